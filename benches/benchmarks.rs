@@ -57,9 +57,88 @@ fn benchmark_ml_dsa(c: &mut Criterion) {
             black_box(valid);
         });
     });
+
+    // Wrong-length encodings must be rejected without touching the key or
+    // message; compare these against "verify" above.
+    let oversized = vec![0u8; 1 << 20];
+    group.bench_function("reject_oversized_signature", |b| {
+        b.iter(|| {
+            let parsed = signature_from_bytes(black_box(&oversized));
+            black_box(parsed.is_err());
+        });
+    });
+
+    let truncated = &sig.as_slice()[..ML_DSA_65_SIG_BYTES - 1];
+    group.bench_function("reject_truncated_signature", |b| {
+        b.iter(|| {
+            let parsed = signature_from_bytes(black_box(truncated));
+            black_box(parsed.is_err());
+        });
+    });
     
     group.finish();
 }
 
-criterion_group!(benches, benchmark_ml_kem, benchmark_ml_dsa);
+/// Worst-case cost of rejecting bad input, for DoS analysis. Each case
+/// should track its happy-path counterpart in the groups above.
+fn benchmark_rejection_paths(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rejection-paths");
+
+    let (pk, sk) = generate_dilithium_keypair();
+    let (other_pk, _other_sk) = generate_dilithium_keypair();
+    let msg = b"benchmark message";
+    let sig = sign_message(&sk, msg);
+
+    let mut tampered = sig.to_array();
+    tampered[ML_DSA_65_SIG_BYTES / 2] ^= 0x01;
+    let tampered = DilithiumSignature::from(tampered);
+    group.bench_function("verify_tampered_signature", |b| {
+        b.iter(|| black_box(verify_signature(&pk, msg, black_box(&tampered))));
+    });
+
+    group.bench_function("verify_wrong_public_key", |b| {
+        b.iter(|| black_box(verify_signature(black_box(&other_pk), msg, &sig)));
+    });
+
+    let keys = KyberKeys::generate_key_pair();
+    let (ct, _ss) = encapsulate_shared_secret(&keys.pk);
+    let mut modified = ct.to_array();
+    modified[0] ^= 0x01;
+    let modified = KyberCiphertext::from(modified);
+    group.bench_function("decapsulate_modified_ciphertext", |b| {
+        b.iter(|| black_box(decapsulate_shared_secret(&keys.sk, black_box(&modified))));
+    });
+
+    group.finish();
+}
+
+/// Marginal cost of the FIPS-mandated pair-wise consistency test: each
+/// `keygen_with_pct` minus its `keygen` is the PCT overhead per key pair.
+fn benchmark_pct_overhead(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pct-overhead");
+
+    group.bench_function("ml-kem/keygen", |b| {
+        b.iter(|| black_box(KyberKeys::generate_key_pair()));
+    });
+    group.bench_function("ml-kem/keygen_with_pct", |b| {
+        b.iter(|| black_box(KyberKeys::generate_key_pair_with_pct().unwrap()));
+    });
+
+    group.bench_function("ml-dsa/keygen", |b| {
+        b.iter(|| black_box(generate_dilithium_keypair()));
+    });
+    group.bench_function("ml-dsa/keygen_with_pct", |b| {
+        b.iter(|| black_box(generate_dilithium_keypair_with_pct().unwrap()));
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_ml_kem,
+    benchmark_ml_dsa,
+    benchmark_rejection_paths,
+    benchmark_pct_overhead
+);
 criterion_main!(benches);
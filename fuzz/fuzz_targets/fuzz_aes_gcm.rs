@@ -7,13 +7,22 @@ use libfuzzer_sys::fuzz_target;
 use pqc_combo::*;
 
 fuzz_target!(|data: &[u8]| {
-    if data.len() < 44 {
-        return; // Need at least key + nonce
-    }
-    
-    let key: [u8; 32] = data[..32].try_into().unwrap();
-    let nonce: [u8; 12] = data[32..44].try_into().unwrap();
-    let plaintext = &data[44..];
+    // Need at least key + nonce
+    let Some((key, rest)) = data.split_first_chunk::<32>() else {
+        return;
+    };
+    let Some((nonce, plaintext)) = rest.split_first_chunk::<12>() else {
+        return;
+    };
+    let (key, nonce) = (*key, *nonce);
+
+    // Anything shorter than a tag is rejected up front
+    let short = &plaintext[..plaintext.len().min(AES_TAG_BYTES - 1)];
+    assert_eq!(
+        decrypt_aes_gcm(&key, &nonce, short),
+        Err(PqcError::AeadOperationFailed),
+        "Input shorter than the tag must be rejected"
+    );
     
     // Test encryption/decryption roundtrip
     if let Ok(ciphertext) = encrypt_aes_gcm(&key, &nonce, plaintext) {
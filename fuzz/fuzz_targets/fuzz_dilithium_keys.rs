@@ -10,8 +10,7 @@ fuzz_target!(|data: &[u8]| {
             let (pk, sk) = generate_dilithium_keypair_with_seed(seed);
             
             let msg = if data.len() > 32 { &data[32..] } else { b"test" };
-            let sig = sign_message(&sk, msg);
-            assert!(verify_signature(&pk, msg, &sig));
+            assert!(invariants::dsa_roundtrip(&pk, &sk, msg, rng::generate_seed_32()));
         }
     }
 });
\ No newline at end of file
@@ -124,14 +124,13 @@ fuzz_target!(|input: DsaFuzzInput| {
             if !sig_bytes.is_empty() {
                 sig_bytes[0] ^= 0xFF;
                 
-                if let Ok(sig_array) = <[u8; ML_DSA_65_SIG_BYTES]>::try_from(sig_bytes.as_slice()) {
-                    let sig_modified = DilithiumSignature::from(sig_array);
-                    
-                    assert!(
-                        !verify_signature(&pk, &input.message, &sig_modified),
-                        "Modified signature verified!"
-                    );
-                }
+                let sig_modified = DilithiumSignature::from_slice(&sig_bytes)
+                    .expect("Same-length signature rejected by from_slice");
+
+                assert!(
+                    !verify_signature(&pk, &input.message, &sig_modified),
+                    "Modified signature verified!"
+                );
             }
         },
         
@@ -9,9 +9,7 @@ fuzz_target!(|data: &[u8]| {
         
         if keygen_seed.iter().any(|&b| b != 0) && encap_seed.iter().any(|&b| b != 0) {
             let keys = KyberKeys::generate_key_pair_with_seed(keygen_seed);
-            let (ct, ss1) = encapsulate_shared_secret_with_randomness(&keys.pk, encap_seed);
-            let ss2 = decapsulate_shared_secret(&keys.sk, &ct);
-            assert_eq!(ss1, ss2);
+            assert!(invariants::kem_roundtrip(&keys.pk, &keys.sk, encap_seed));
         }
     }
 });
\ No newline at end of file
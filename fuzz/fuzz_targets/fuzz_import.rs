@@ -0,0 +1,73 @@
+#![no_main]
+//! Fuzzing for key, ciphertext and signature import
+//!
+//! Feeds arbitrary bytes to every parser that accepts untrusted input.
+//! None may panic; each must return `Ok` or its documented error, and
+//! anything accepted must round-trip to the same bytes.
+
+use libfuzzer_sys::fuzz_target;
+use pqc_combo::*;
+
+/// Key blob header written by `KyberKeys::to_bytes`: magic, version, alg id
+const KEY_BLOB_HEADER: [u8; 6] = [b'P', b'Q', b'C', b'K', 0x01, 0x01];
+
+fuzz_target!(|data: &[u8]| {
+    match KyberPublicKey::from_slice(data) {
+        Ok(pk) => {
+            assert_eq!(pk.as_slice(), data);
+            // Modulus check on an arbitrary key must not panic either
+            let _ = validate_public_key(&pk);
+        }
+        Err(e) => assert_eq!(e, PqcError::InvalidKeyLength),
+    }
+
+    match KyberCiphertext::from_slice(data) {
+        Ok(ct) => assert_eq!(ct.as_slice(), data),
+        Err(e) => assert_eq!(e, PqcError::InvalidCiphertext),
+    }
+
+    match DilithiumPublicKey::from_slice(data) {
+        Ok(pk) => assert_eq!(pk.as_slice(), data),
+        Err(e) => assert_eq!(e, PqcError::InvalidKeyLength),
+    }
+
+    match signature_from_bytes(data) {
+        Ok(sig) => assert_eq!(sig.as_slice(), data),
+        Err(e) => assert_eq!(e, PqcError::InvalidKeyLength),
+    }
+
+    match wire::decode_public_key(data) {
+        Ok(pk) => assert_eq!(wire::encode_public_key(&pk), data),
+        Err(e) => assert!(
+            matches!(e, PqcError::InvalidEncoding | PqcError::InvalidKeyLength | PqcError::InvalidPublicKey),
+            "unexpected error from wire::decode_public_key: {:?}",
+            e
+        ),
+    }
+
+    match wire::decode_encapsulation(data) {
+        Ok(ct) => assert_eq!(wire::encode_encapsulation(&ct), data),
+        Err(e) => assert!(
+            matches!(e, PqcError::InvalidEncoding | PqcError::InvalidCiphertext),
+            "unexpected error from wire::decode_encapsulation: {:?}",
+            e
+        ),
+    }
+
+    // Raw input rarely carries the header, so also try it behind a valid one
+    let framed = [&KEY_BLOB_HEADER[..], data].concat();
+    for blob in [data, &framed[..]] {
+        match KyberKeys::from_bytes(blob) {
+            Ok(keys) => {
+                let body = &blob[KEY_BLOB_HEADER.len()..];
+                assert_eq!(keys.pk.as_slice(), &body[..ML_KEM_1024_PK_BYTES]);
+                assert_eq!(keys.sk.as_slice(), &body[ML_KEM_1024_PK_BYTES..]);
+            }
+            Err(e) => assert!(
+                matches!(e, PqcError::InvalidKeyLength | PqcError::InvalidEncoding),
+                "unexpected error from KyberKeys::from_bytes: {:?}",
+                e
+            ),
+        }
+    }
+});
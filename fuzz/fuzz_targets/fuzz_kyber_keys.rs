@@ -8,9 +8,7 @@ fuzz_target!(|data: &[u8]| {
         
         if seed.iter().any(|&b| b != 0) {
             let keys = KyberKeys::generate_key_pair_with_seed(seed);
-            let (ct, ss1) = encapsulate_shared_secret(&keys.pk);
-            let ss2 = decapsulate_shared_secret(&keys.sk, &ct);
-            assert_eq!(ss1, ss2);
+            assert!(invariants::kem_roundtrip(&keys.pk, &keys.sk, rng::generate_seed_32()));
         }
     }
 });
\ No newline at end of file
@@ -93,9 +93,9 @@ fuzz_target!(|input: KemFuzzInput| {
         },
         
         KemOperation::ModifiedCiphertext => {
-            // Test that modified ciphertext produces different shared secret
+            // A modified ciphertext still decapsulates (implicit rejection)
             let keys = KyberKeys::generate_key_pair_with_seed(input.keygen_seed);
-            let (ct, ss_original) = encapsulate_shared_secret_with_randomness(&keys.pk, input.encap_seed);
+            let (ct, _) = encapsulate_shared_secret_with_randomness(&keys.pk, input.encap_seed);
             
             // Modify ciphertext
             let mut ct_bytes: Vec<u8> = ct.as_slice().to_vec();
@@ -103,19 +103,16 @@ fuzz_target!(|input: KemFuzzInput| {
                 ct_bytes[0] ^= 0xFF;
             }
             
-            // Reconstruct ciphertext
-            if let Ok(ct_array) = <[u8; ML_KEM_1024_CT_BYTES]>::try_from(ct_bytes.as_slice()) {
-                let ct_modified = KyberCiphertext::from(ct_array);
-                let ss_modified = decapsulate_shared_secret(&keys.sk, &ct_modified);
-                
-                // Modified ciphertext should produce different shared secret
-                // (with very high probability)
-                if ss_original == ss_modified {
-                    // This is extremely unlikely but not impossible
-                    // Just log it, don't panic
-                    eprintln!("Warning: Modified ciphertext produced same shared secret (collision)");
-                }
-            }
+            // Reconstruct ciphertext; a same-length buffer must always parse
+            let ct_modified = KyberCiphertext::from_slice(&ct_bytes)
+                .expect("Same-length ciphertext rejected by from_slice");
+            let _ = decapsulate_shared_secret(&keys.sk, &ct_modified);
+
+            // Truncated or extended wire input is an explicit error
+            assert_eq!(
+                KyberCiphertext::from_slice(&ct_bytes[1..]).err(),
+                Some(PqcError::InvalidCiphertext)
+            );
         },
     }
 });
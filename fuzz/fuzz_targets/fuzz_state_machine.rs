@@ -32,18 +32,11 @@ fuzz_target!(|data: &[u8]| {
         },
         
         1 => {
-            // Test operations without POST (should fail in FIPS mode)
-            #[cfg(feature = "fips_140_3")]
-            {
-                if data.len() >= 65 {
-                    let seed: [u8; 64] = data[1..65].try_into().unwrap();
-                    if seed.iter().any(|&b| b != 0) {
-                        // Operations should check operational state
-                        // Note: This might panic or error depending on implementation
-                        // Just ensure it doesn't succeed silently
-                    }
-                }
-            }
+            // Nothing is Operational before POST
+            assert_eq!(
+                pqc_combo::state::check_operational(),
+                Err(PqcError::FipsNotInitialized)
+            );
         },
         
         2 => {
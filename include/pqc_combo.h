@@ -0,0 +1,195 @@
+#ifndef PQC_COMBO_H
+#define PQC_COMBO_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define PQC_ML_KEM_1024_PK_BYTES 1568
+
+#define PQC_ML_KEM_1024_SK_BYTES 3168
+
+#define PQC_ML_KEM_1024_CT_BYTES 1568
+
+#define PQC_ML_KEM_1024_SS_BYTES 32
+
+#define PQC_ML_KEM_KEYGEN_SEED_BYTES 64
+
+#define PQC_ML_KEM_ENCAP_SEED_BYTES 32
+
+#define PQC_ML_DSA_65_PK_BYTES 1952
+
+#define PQC_ML_DSA_65_SK_BYTES 4032
+
+#define PQC_ML_DSA_65_SIG_BYTES 3309
+
+#define PQC_ML_DSA_KEYGEN_SEED_BYTES 32
+
+#define PQC_ML_DSA_SIGN_SEED_BYTES 32
+
+#define PQC_OK 0
+
+#define PQC_ERR_NULL_POINTER -1
+
+#define PQC_ERR_INVALID_LENGTH -2
+
+#define PQC_ERR_VERIFICATION_FAILURE -3
+
+#define PQC_ERR_DECAPSULATION_FAILURE -4
+
+#define PQC_ERR_AES_GCM -5
+
+#define PQC_ERR_INVALID_PUBLIC_KEY -6
+
+#define PQC_ERR_PCT_FAILURE -7
+
+#define PQC_ERR_CAST_FAILURE -8
+
+#define PQC_ERR_NOT_INITIALIZED -9
+
+#define PQC_ERR_POST_IN_PROGRESS -10
+
+#define PQC_ERR_ERROR_STATE -11
+
+#define PQC_ERR_ILLEGAL_STATE_TRANSITION -12
+
+#define PQC_ERR_CSP_EXPORT_BLOCKED -13
+
+#define PQC_ERR_CONTEXT_TOO_LONG -14
+
+#define PQC_ERR_INVALID_ENCODING -15
+
+#define PQC_ERR_MEMORY_LOCK_FAILED -16
+
+#define PQC_ERR_RNG_FAILURE -17
+
+#define PQC_ERR_IO_FAILURE -18
+
+#define PQC_ERR_INVALID_CIPHERTEXT -19
+
+#define PQC_ERR_INVALID_SEED -20
+
+#define PQC_ERR_SIGNING_FAILURE -21
+
+#define PQC_ERR_INVALID_TAG_LENGTH -22
+
+#define PQC_ERR_MESSAGE_TOO_LARGE -23
+
+#define PQC_ERR_WEAK_KDF_PARAMETERS -24
+
+#define PQC_ERR_NONCE_REUSED -25
+
+#define PQC_ERR_EMPTY_CONTEXT -26
+
+#define PQC_ERR_UNSUPPORTED_ALGORITHM -27
+
+#define PQC_ERR_NON_CANONICAL_SIGNATURE -28
+
+#define PQC_ERR_PARAMETER_SET_NOT_ALLOWED -29
+
+#define PQC_ERR_INTERNAL -99
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Generate an ML-KEM-1024 key pair from a 64-byte seed
+ *
+ * # Safety
+ * Each non-null pointer must be valid for its stated length; output
+ * buffers must not overlap the inputs.
+ */
+int pqc_kyber_keygen(const uint8_t *seed,
+                     size_t seed_len,
+                     uint8_t *pk_out,
+                     size_t pk_len,
+                     uint8_t *sk_out,
+                     size_t sk_len);
+
+/**
+ * Encapsulate to an ML-KEM-1024 public key with 32 bytes of randomness
+ *
+ * The public key is validated first (`PQC_ERR_INVALID_PUBLIC_KEY`).
+ *
+ * # Safety
+ * Each non-null pointer must be valid for its stated length; output
+ * buffers must not overlap the inputs.
+ */
+int pqc_kyber_encaps(const uint8_t *pk,
+                     size_t pk_len,
+                     const uint8_t *randomness,
+                     size_t randomness_len,
+                     uint8_t *ct_out,
+                     size_t ct_len,
+                     uint8_t *ss_out,
+                     size_t ss_len);
+
+/**
+ * Decapsulate an ML-KEM-1024 ciphertext
+ *
+ * # Safety
+ * Each non-null pointer must be valid for its stated length; output
+ * buffers must not overlap the inputs.
+ */
+int pqc_kyber_decaps(const uint8_t *sk,
+                     size_t sk_len,
+                     const uint8_t *ct,
+                     size_t ct_len,
+                     uint8_t *ss_out,
+                     size_t ss_len);
+
+/**
+ * Generate an ML-DSA-65 key pair from a 32-byte seed
+ *
+ * # Safety
+ * Each non-null pointer must be valid for its stated length; output
+ * buffers must not overlap the inputs.
+ */
+int pqc_dsa_keygen(const uint8_t *seed,
+                   size_t seed_len,
+                   uint8_t *pk_out,
+                   size_t pk_len,
+                   uint8_t *sk_out,
+                   size_t sk_len);
+
+/**
+ * Sign a message with ML-DSA-65 using 32 bytes of randomness
+ *
+ * `msg` may be null only when `msg_len` is 0.
+ *
+ * # Safety
+ * Each non-null pointer must be valid for its stated length; output
+ * buffers must not overlap the inputs.
+ */
+int pqc_dsa_sign(const uint8_t *sk,
+                 size_t sk_len,
+                 const uint8_t *msg,
+                 size_t msg_len,
+                 const uint8_t *randomness,
+                 size_t randomness_len,
+                 uint8_t *sig_out,
+                 size_t sig_len);
+
+/**
+ * Verify an ML-DSA-65 signature
+ *
+ * Returns `PQC_OK` for a valid signature and `PQC_ERR_VERIFICATION_FAILURE`
+ * otherwise. `msg` may be null only when `msg_len` is 0.
+ *
+ * # Safety
+ * Each non-null pointer must be valid for its stated length.
+ */
+int pqc_dsa_verify(const uint8_t *pk,
+                   size_t pk_len,
+                   const uint8_t *msg,
+                   size_t msg_len,
+                   const uint8_t *sig,
+                   size_t sig_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PQC_COMBO_H */
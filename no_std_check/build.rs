@@ -0,0 +1,5 @@
+// The C runtime supplies `_start`, `memcpy` and friends, and `abort`;
+// without std nothing else links it.
+fn main() {
+    println!("cargo:rustc-link-lib=c");
+}
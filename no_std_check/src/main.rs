@@ -0,0 +1,66 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// no_std Smoke Binary
+// ------------------------------------------------------------------------
+//! Linked without the standard library and entered from the C `main`, so
+//! any std dependency creeping into the no_std surface fails the build.
+//! Exit status 0 means every check passed; each failed check has its own
+//! non-zero status.
+//!
+//! `libcrux-traits` links `alloc` unconditionally, so the binary must name
+//! a global allocator. [`NoAlloc`] fails every request: an allocation on
+//! any checked path aborts the process, which keeps the no_alloc
+//! configuration honest at run time as well.
+#![no_std]
+#![no_main]
+
+use core::alloc::{GlobalAlloc, Layout};
+
+use pqc_combo::cast::run_hash_casts;
+use pqc_combo::{decapsulate_shared_secret, encapsulate_shared_secret_with_randomness, KyberKeys};
+
+struct NoAlloc;
+
+unsafe impl GlobalAlloc for NoAlloc {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: NoAlloc = NoAlloc;
+
+fn run() -> i32 {
+    if run_hash_casts().is_err() {
+        return 1;
+    }
+    let keys = KyberKeys::generate_key_pair_with_seed([0x42; 64]);
+    let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x24; 32]);
+    if decapsulate_shared_secret(&keys.sk, &ct) != ss {
+        return 2;
+    }
+    // Same vector as the hybrid combiner tests
+    if ss.as_bytes()[..4] != [0x25, 0xbe, 0xff, 0x8a] {
+        return 3;
+    }
+    0
+}
+
+#[no_mangle]
+extern "C" fn main(_argc: i32, _argv: *const *const u8) -> i32 {
+    run()
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    extern "C" {
+        fn abort() -> !;
+    }
+    unsafe { abort() }
+}
+
+/// Referenced by the prebuilt `core`/`alloc`, never called with `panic = "abort"`
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
@@ -116,6 +116,8 @@ run_test "ML-KEM only" "cargo test --no-default-features --features 'ml-kem,std'
 run_test "ML-DSA only" "cargo test --no-default-features --features 'ml-dsa,std'"
 run_test "no_std (no_alloc)" "cargo build --no-default-features --features 'ml-kem,ml-dsa'"
 run_test "no_std (with alloc)" "cargo build --no-default-features --features 'alloc,ml-kem,ml-dsa'"
+run_test "no_std smoke binary" "cargo test --test no_std_build"
+run_test "require-context policy" "cargo test --features require-context --test require_context"
 run_test "With AES-GCM" "cargo test --features 'std,ml-kem,ml-dsa,aes-gcm'"
 
 # 6. Benchmarks
@@ -0,0 +1,293 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// ACVP Test-Vector Runner
+// ------------------------------------------------------------------------
+//! Run NIST ACVP JSON vector sets against the crate.
+//!
+//! [`run_acvp_json`] takes a vector set with prompts and expected results
+//! merged (the `internalProjection.json` files from the ACVP server), turns
+//! each test case into a [`KatVector`] and reports the outcome per `tcId`.
+//!
+//! Supported: ML-KEM `keyGen` / `encapDecap` (including the
+//! `encapsulationKeyCheck` function) for ML-KEM-1024 and ML-DSA `keyGen` /
+//! `sigGen` / `sigVer` for ML-DSA-65 with the external, pure interface.
+//! Groups for other parameter sets or interfaces are reported as
+//! [`AcvpOutcome::Skipped`].
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use serde_json::Value;
+
+use crate::ct_codec::from_hex;
+use crate::error::{PqcError, Result};
+use crate::kat::KatVector;
+use crate::{
+    ML_DSA_KEYGEN_SEED_BYTES, ML_DSA_SIGN_SEED_BYTES, ML_KEM_ENCAP_SEED_BYTES,
+    ML_KEM_KEYGEN_SEED_BYTES,
+};
+
+/// Result of one ACVP test case
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcvpOutcome {
+    Passed,
+    /// The case ran (or failed to parse) with this error
+    Failed(PqcError),
+    /// Parameter set or interface not implemented by this crate
+    Skipped,
+}
+
+/// Outcome of one test case, keyed by its ACVP identifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcvpCaseResult {
+    pub tg_id: u64,
+    pub tc_id: u64,
+    pub outcome: AcvpOutcome,
+}
+
+/// Run every test case in an ACVP vector set
+///
+/// Returns `InvalidEncoding` if the document is not an ACVP vector set for
+/// one of the supported algorithm/mode pairs. Per-case problems (bad hex,
+/// wrong lengths, mismatches) are reported in the case's outcome.
+pub fn run_acvp_json(json: &str) -> Result<Vec<AcvpCaseResult>> {
+    let doc: Value = serde_json::from_str(json).map_err(|_| PqcError::InvalidEncoding)?;
+    let algorithm = doc["algorithm"].as_str().ok_or(PqcError::InvalidEncoding)?;
+    let mode = doc["mode"].as_str().ok_or(PqcError::InvalidEncoding)?;
+    let run_case: fn(&Value, &Value) -> Result<()> = match (algorithm, mode) {
+        ("ML-KEM", "keyGen") => ml_kem_keygen,
+        ("ML-KEM", "encapDecap") => ml_kem_encap_decap,
+        ("ML-DSA", "keyGen") => ml_dsa_keygen,
+        ("ML-DSA", "sigGen") => ml_dsa_sig_gen,
+        ("ML-DSA", "sigVer") => ml_dsa_sig_ver,
+        _ => return Err(PqcError::InvalidEncoding),
+    };
+    let expected_set = if algorithm == "ML-KEM" { "ML-KEM-1024" } else { "ML-DSA-65" };
+
+    let groups = doc["testGroups"].as_array().ok_or(PqcError::InvalidEncoding)?;
+    let mut results = Vec::new();
+    for group in groups {
+        let tg_id = group["tgId"].as_u64().ok_or(PqcError::InvalidEncoding)?;
+        let supported = group["parameterSet"] == expected_set && is_pure_external(group);
+        for test in group["tests"].as_array().ok_or(PqcError::InvalidEncoding)? {
+            let tc_id = test["tcId"].as_u64().ok_or(PqcError::InvalidEncoding)?;
+            let outcome = if !supported {
+                AcvpOutcome::Skipped
+            } else {
+                match run_case(group, test) {
+                    Ok(()) => AcvpOutcome::Passed,
+                    Err(e) => AcvpOutcome::Failed(e),
+                }
+            };
+            results.push(AcvpCaseResult { tg_id, tc_id, outcome });
+        }
+    }
+    Ok(results)
+}
+
+/// True when every case in `results` passed or was skipped
+pub fn all_passed(results: &[AcvpCaseResult]) -> bool {
+    results.iter().all(|r| !matches!(r.outcome, AcvpOutcome::Failed(_)))
+}
+
+/// ML-DSA groups may select the internal interface, pre-hashing or external mu
+fn is_pure_external(group: &Value) -> bool {
+    let interface_ok = matches!(group["signatureInterface"].as_str(), None | Some("external"));
+    let prehash_ok = matches!(group["preHash"].as_str(), None | Some("pure"));
+    let mu_ok = !group["externalMu"].as_bool().unwrap_or(false);
+    interface_ok && prehash_ok && mu_ok
+}
+
+fn ml_kem_keygen(group: &Value, test: &Value) -> Result<()> {
+    let d = hex_field(group, test, "d")?;
+    let z = hex_field(group, test, "z")?;
+    let seed: Vec<u8> = d.iter().chain(z.iter()).copied().collect();
+    let pk = hex_field(group, test, "ek")?;
+    let sk = hex_field(group, test, "dk")?;
+    KatVector::MlKemKeyGen { seed: fixed::<ML_KEM_KEYGEN_SEED_BYTES>(&seed)?, pk: &pk, sk: &sk }.check()
+}
+
+fn ml_kem_encap_decap(group: &Value, test: &Value) -> Result<()> {
+    match group["function"].as_str() {
+        Some("encapsulation") => {
+            let ct = hex_field(group, test, "c")?;
+            let ss = hex_field(group, test, "k")?;
+            let pk = hex_field(group, test, "ek")?;
+            let m = hex_field(group, test, "m")?;
+            KatVector::MlKemEncap { pk: &pk, randomness: fixed::<ML_KEM_ENCAP_SEED_BYTES>(&m)?, ct: &ct, ss: &ss }.check()
+        }
+        Some("encapsulationKeyCheck") => {
+            let pk = hex_field(group, test, "ek")?;
+            let valid = test["testPassed"].as_bool().ok_or(PqcError::InvalidEncoding)?;
+            KatVector::MlKemPublicKeyCheck { pk: &pk, valid }.check()
+        }
+        Some("decapsulation") => {
+            let ct = hex_field(group, test, "c")?;
+            let ss = hex_field(group, test, "k")?;
+            let sk = hex_field(group, test, "dk")?;
+            KatVector::MlKemDecap { sk: &sk, ct: &ct, ss: &ss }.check()
+        }
+        _ => Err(PqcError::InvalidEncoding),
+    }
+}
+
+fn ml_dsa_keygen(group: &Value, test: &Value) -> Result<()> {
+    let seed = hex_field(group, test, "seed")?;
+    let pk = hex_field(group, test, "pk")?;
+    let sk = hex_field(group, test, "sk")?;
+    KatVector::MlDsaKeyGen { seed: fixed::<ML_DSA_KEYGEN_SEED_BYTES>(&seed)?, pk: &pk, sk: &sk }.check()
+}
+
+fn ml_dsa_sig_gen(group: &Value, test: &Value) -> Result<()> {
+    let sk = hex_field(group, test, "sk")?;
+    let message = hex_field(group, test, "message")?;
+    let context = optional_hex_field(group, test, "context")?;
+    let sig = hex_field(group, test, "signature")?;
+    // Deterministic groups carry no `rnd`; the all-zero value selects that mode
+    let randomness = if group["deterministic"].as_bool().unwrap_or(false) {
+        [0u8; ML_DSA_SIGN_SEED_BYTES]
+    } else {
+        fixed::<ML_DSA_SIGN_SEED_BYTES>(&hex_field(group, test, "rnd")?)?
+    };
+    KatVector::MlDsaSign { sk: &sk, message: &message, context: &context, randomness, sig: &sig }.check()
+}
+
+fn ml_dsa_sig_ver(group: &Value, test: &Value) -> Result<()> {
+    let pk = hex_field(group, test, "pk")?;
+    let message = hex_field(group, test, "message")?;
+    let context = optional_hex_field(group, test, "context")?;
+    let sig = hex_field(group, test, "signature")?;
+    let valid = test["testPassed"].as_bool().ok_or(PqcError::InvalidEncoding)?;
+    KatVector::MlDsaVerify { pk: &pk, message: &message, context: &context, sig: &sig, valid }.check()
+}
+
+/// Look a hex field up on the test case, falling back to the group
+///
+/// Some vector-set revisions hoist shared keys (e.g. `dk`, `pk`) to the group.
+fn hex_field(group: &Value, test: &Value, name: &str) -> Result<Vec<u8>> {
+    let encoded = test[name]
+        .as_str()
+        .or_else(|| group[name].as_str())
+        .ok_or(PqcError::InvalidEncoding)?;
+    from_hex(encoded)
+}
+
+/// Like [`hex_field`], but a missing field is empty (e.g. ML-DSA `context`)
+fn optional_hex_field(group: &Value, test: &Value, name: &str) -> Result<Vec<u8>> {
+    match test[name].as_str().or_else(|| group[name].as_str()) {
+        Some(encoded) => from_hex(encoded),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn fixed<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
+    bytes.try_into().map_err(|_| PqcError::InvalidEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02X}", b)).collect()
+    }
+
+    #[test]
+    fn test_ml_kem_vector_sets() {
+        let d = [0x11u8; 32];
+        let z = [0x22u8; 32];
+        let mut seed = [0u8; 64];
+        seed[..32].copy_from_slice(&d);
+        seed[32..].copy_from_slice(&z);
+        let keys = crate::KyberKeys::generate_key_pair_with_seed(seed);
+        let (ct, ss) = crate::encapsulate_shared_secret_with_randomness(&keys.pk, [0x33; 32]);
+        let (pk, sk) = (hex(keys.pk.as_slice()), hex(keys.sk.as_slice()));
+
+        let keygen = format!(
+            r#"{{"algorithm":"ML-KEM","mode":"keyGen","testGroups":[
+                {{"tgId":1,"parameterSet":"ML-KEM-1024","tests":[
+                    {{"tcId":1,"d":"{}","z":"{}","ek":"{pk}","dk":"{sk}"}},
+                    {{"tcId":2,"d":"{}","z":"{}","ek":"{pk}","dk":"{sk}"}}]}},
+                {{"tgId":2,"parameterSet":"ML-KEM-512","tests":[{{"tcId":3}}]}}]}}"#,
+            hex(&d), hex(&z), hex(&z), hex(&d),
+        );
+        let results = run_acvp_json(&keygen).unwrap();
+        assert_eq!(results[0], AcvpCaseResult { tg_id: 1, tc_id: 1, outcome: AcvpOutcome::Passed });
+        assert_eq!(results[1].outcome, AcvpOutcome::Failed(PqcError::CastFailure));
+        assert_eq!(results[2].outcome, AcvpOutcome::Skipped);
+        assert!(!all_passed(&results));
+
+        let encap_decap = format!(
+            r#"{{"algorithm":"ML-KEM","mode":"encapDecap","testGroups":[
+                {{"tgId":1,"parameterSet":"ML-KEM-1024","function":"encapsulation","tests":[
+                    {{"tcId":1,"ek":"{pk}","m":"{}","c":"{ct}","k":"{ss}"}}]}},
+                {{"tgId":2,"parameterSet":"ML-KEM-1024","function":"decapsulation","dk":"{sk}","tests":[
+                    {{"tcId":2,"c":"{ct}","k":"{ss}"}}]}},
+                {{"tgId":3,"parameterSet":"ML-KEM-1024","function":"encapsulationKeyCheck","tests":[
+                    {{"tcId":3,"ek":"{pk}","testPassed":true}},
+                    {{"tcId":4,"ek":"FFFF{}","testPassed":false}}]}}]}}"#,
+            hex(&[0x33; 32]), &pk[4..], ct = hex(ct.as_slice()), ss = hex(&ss),
+        );
+        let results = run_acvp_json(&encap_decap).unwrap();
+        assert_eq!(results.len(), 4);
+        assert!(all_passed(&results));
+    }
+
+    #[test]
+    fn test_ml_dsa_vector_sets() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x44; 32]);
+        let hedged = crate::sign_message_with_context_and_randomness(&sk, b"msg", b"ctx", [0x55; 32]).unwrap();
+        // Context-free vector, which the harness accepts even under `require-context`
+        let deterministic = crate::sign_with_any_context(&sk, b"msg", &[], [0; 32]).unwrap();
+        let (pk, sk) = (hex(pk.as_slice()), hex(sk.as_slice()));
+        let (msg, ctx) = (hex(b"msg"), hex(b"ctx"));
+
+        let keygen = format!(
+            r#"{{"algorithm":"ML-DSA","mode":"keyGen","testGroups":[{{"tgId":1,"parameterSet":"ML-DSA-65",
+                "tests":[{{"tcId":1,"seed":"{}","pk":"{pk}","sk":"{sk}"}}]}}]}}"#,
+            hex(&[0x44; 32]),
+        );
+        assert!(all_passed(&run_acvp_json(&keygen).unwrap()));
+
+        let sig_gen = format!(
+            r#"{{"algorithm":"ML-DSA","mode":"sigGen","testGroups":[
+                {{"tgId":1,"parameterSet":"ML-DSA-65","signatureInterface":"external","preHash":"pure","deterministic":false,
+                  "tests":[{{"tcId":1,"sk":"{sk}","message":"{msg}","context":"{ctx}","rnd":"{}","signature":"{}"}}]}},
+                {{"tgId":2,"parameterSet":"ML-DSA-65","deterministic":true,
+                  "tests":[{{"tcId":2,"sk":"{sk}","message":"{msg}","signature":"{}"}}]}},
+                {{"tgId":3,"parameterSet":"ML-DSA-65","signatureInterface":"internal","tests":[{{"tcId":3}}]}}]}}"#,
+            hex(&[0x55; 32]), hex(hedged.as_slice()), hex(deterministic.as_slice()),
+        );
+        let results = run_acvp_json(&sig_gen).unwrap();
+        let outcomes: Vec<_> = results.iter().map(|r| r.outcome).collect();
+        assert_eq!(outcomes, [AcvpOutcome::Passed, AcvpOutcome::Passed, AcvpOutcome::Skipped]);
+
+        let sig_ver = format!(
+            r#"{{"algorithm":"ML-DSA","mode":"sigVer","testGroups":[{{"tgId":1,"parameterSet":"ML-DSA-65","pk":"{pk}",
+                "tests":[{{"tcId":1,"message":"{msg}","context":"{ctx}","signature":"{sig}","testPassed":true}},
+                         {{"tcId":2,"message":"{msg}","signature":"{sig}","testPassed":false}},
+                         {{"tcId":3,"message":"{msg}","signature":"{sig}","testPassed":true}}]}}]}}"#,
+            sig = hex(hedged.as_slice()),
+        );
+        let results = run_acvp_json(&sig_ver).unwrap();
+        let outcomes: Vec<_> = results.iter().map(|r| r.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [AcvpOutcome::Passed, AcvpOutcome::Passed, AcvpOutcome::Failed(PqcError::CastFailure)]
+        );
+    }
+
+    #[test]
+    fn test_rejects_malformed_documents() {
+        assert_eq!(run_acvp_json("not json"), Err(PqcError::InvalidEncoding));
+        assert_eq!(
+            run_acvp_json(r#"{"algorithm":"SLH-DSA","mode":"keyGen","testGroups":[]}"#),
+            Err(PqcError::InvalidEncoding)
+        );
+        let bad_hex = r#"{"algorithm":"ML-DSA","mode":"keyGen","testGroups":[{"tgId":1,"parameterSet":"ML-DSA-65",
+            "tests":[{"tcId":1,"seed":"zz","pk":"","sk":""}]}]}"#;
+        assert_eq!(run_acvp_json(bad_hex).unwrap()[0].outcome, AcvpOutcome::Failed(PqcError::InvalidEncoding));
+    }
+}
@@ -0,0 +1,241 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Symmetric Encryption Re-exports
+// ------------------------------------------------------------------------
+//! AES-256-GCM surface, available with the `aes-gcm` feature.
+//!
+//! `use pqc_combo::aead::prelude::*;` brings in exactly the AEAD functions
+//! and constants. The `Vec`-returning forms also need `alloc`; the `*_into`
+//! forms work without an allocator.
+//!
+//! [`AeadAlgorithm`] names the AEAD behind the high-level encryption
+//! formats ([`crate::stream`]). Each format writes [`AeadAlgorithm::id`]
+//! into its header and dispatches on it when opening, so a blob always
+//! says which cipher sealed it. AES-256-GCM is the default; the
+//! `chacha20poly1305` feature adds ChaCha20-Poly1305 (RFC 8439), which is
+//! not FIPS-approved. The enum is `#[non_exhaustive]` so others can be
+//! added without changing the formats.
+
+use crate::error::{PqcError, Result};
+use crate::{decrypt_aes_gcm_into_with_aad, encrypt_aes_gcm_into_with_aad, AES_KEY_BYTES, AES_NONCE_BYTES};
+
+/// Header id of AES-256-GCM
+pub const AEAD_ID_AES_256_GCM: u8 = 0x01;
+
+/// Header id of ChaCha20-Poly1305
+pub const AEAD_ID_CHACHA20_POLY1305: u8 = 0x02;
+
+/// AEAD used by a sealed format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum AeadAlgorithm {
+    /// AES-256-GCM with a 16-byte tag
+    #[default]
+    Aes256Gcm,
+    /// ChaCha20-Poly1305 (RFC 8439) with a 16-byte tag; not FIPS-approved
+    #[cfg(feature = "chacha20poly1305")]
+    ChaCha20Poly1305,
+}
+
+impl AeadAlgorithm {
+    /// Byte recorded in format headers
+    pub const fn id(self) -> u8 {
+        match self {
+            Self::Aes256Gcm => AEAD_ID_AES_256_GCM,
+            #[cfg(feature = "chacha20poly1305")]
+            Self::ChaCha20Poly1305 => AEAD_ID_CHACHA20_POLY1305,
+        }
+    }
+
+    /// Algorithm for a header id; `UnsupportedAlgorithm` if unknown
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            AEAD_ID_AES_256_GCM => Ok(Self::Aes256Gcm),
+            #[cfg(feature = "chacha20poly1305")]
+            AEAD_ID_CHACHA20_POLY1305 => Ok(Self::ChaCha20Poly1305),
+            _ => Err(PqcError::UnsupportedAlgorithm),
+        }
+    }
+
+    /// Authentication tag length
+    pub const fn tag_len(self) -> usize {
+        match self {
+            Self::Aes256Gcm => crate::AES_TAG_BYTES,
+            #[cfg(feature = "chacha20poly1305")]
+            Self::ChaCha20Poly1305 => chacha::TAG_BYTES,
+        }
+    }
+
+    /// Encrypt `plaintext` into `out` as `ciphertext || tag`
+    pub fn seal_into(
+        self,
+        key: &[u8; AES_KEY_BYTES],
+        nonce: &[u8; AES_NONCE_BYTES],
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        match self {
+            Self::Aes256Gcm => encrypt_aes_gcm_into_with_aad(key, nonce, aad, plaintext, out),
+            #[cfg(feature = "chacha20poly1305")]
+            Self::ChaCha20Poly1305 => chacha::seal_into(key, nonce, aad, plaintext, out),
+        }
+    }
+
+    /// Inverse of [`AeadAlgorithm::seal_into`]
+    pub fn open_into(
+        self,
+        key: &[u8; AES_KEY_BYTES],
+        nonce: &[u8; AES_NONCE_BYTES],
+        aad: &[u8],
+        ciphertext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        match self {
+            Self::Aes256Gcm => decrypt_aes_gcm_into_with_aad(key, nonce, aad, ciphertext, out),
+            #[cfg(feature = "chacha20poly1305")]
+            Self::ChaCha20Poly1305 => chacha::open_into(key, nonce, aad, ciphertext, out),
+        }
+    }
+}
+
+/// ChaCha20-Poly1305 in the same `ciphertext || tag` layout as AES-GCM
+#[cfg(feature = "chacha20poly1305")]
+mod chacha {
+    use chacha20poly1305::aead::{AeadInPlace, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce, Tag};
+
+    use crate::error::{PqcError, Result};
+    use crate::{AES_KEY_BYTES, AES_NONCE_BYTES};
+
+    pub(super) const TAG_BYTES: usize = 16;
+
+    /// RFC 8439 §2.8: 2^32 - 1 blocks of keystream after the Poly1305 key
+    const MAX_PLAINTEXT_BYTES: u64 = ((1 << 32) - 1) * 64;
+
+    fn check_len(len: usize) -> Result<()> {
+        match u64::try_from(len) {
+            Ok(len) if len <= MAX_PLAINTEXT_BYTES => Ok(()),
+            _ => Err(PqcError::MessageTooLarge),
+        }
+    }
+
+    pub(super) fn seal_into(
+        key: &[u8; AES_KEY_BYTES],
+        nonce: &[u8; AES_NONCE_BYTES],
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        check_len(plaintext.len())?;
+        let total = plaintext.len().checked_add(TAG_BYTES).ok_or(PqcError::BufferTooSmall)?;
+        if out.len() < total {
+            return Err(PqcError::BufferTooSmall);
+        }
+        let (body, rest) = out.split_at_mut(plaintext.len());
+        body.copy_from_slice(plaintext);
+
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+        let tag = cipher
+            .encrypt_in_place_detached(Nonce::from_slice(nonce), aad, body)
+            .map_err(|_| PqcError::AeadOperationFailed)?;
+        rest[..TAG_BYTES].copy_from_slice(&tag);
+        Ok(total)
+    }
+
+    /// Inverse of [`seal_into`]; wipes the written bytes on failure
+    pub(super) fn open_into(
+        key: &[u8; AES_KEY_BYTES],
+        nonce: &[u8; AES_NONCE_BYTES],
+        aad: &[u8],
+        ciphertext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        let body_len = ciphertext.len().checked_sub(TAG_BYTES).ok_or(PqcError::AeadOperationFailed)?;
+        check_len(body_len)?;
+        if out.len() < body_len {
+            return Err(PqcError::BufferTooSmall);
+        }
+        let (body, tag) = ciphertext.split_at(body_len);
+        let plaintext = &mut out[..body_len];
+        plaintext.copy_from_slice(body);
+
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+        match cipher.decrypt_in_place_detached(Nonce::from_slice(nonce), aad, plaintext, Tag::from_slice(tag)) {
+            Ok(()) => Ok(body_len),
+            Err(_) => {
+                zeroize::Zeroize::zeroize(plaintext);
+                Err(PqcError::AeadOperationFailed)
+            }
+        }
+    }
+}
+
+/// Everything needed to encrypt and decrypt with AES-256-GCM
+pub mod prelude {
+    pub use super::{AeadAlgorithm, AEAD_ID_AES_256_GCM};
+    #[cfg(feature = "chacha20poly1305")]
+    pub use super::AEAD_ID_CHACHA20_POLY1305;
+    pub use crate::error::{PqcError, Result};
+    pub use crate::gcm_context::AesGcmContext;
+    pub use crate::{
+        decrypt_aes_gcm_into, decrypt_aes_gcm_into_with_aad, encrypt_aes_gcm_into,
+        encrypt_aes_gcm_into_with_aad, AES_KEY_BYTES, AES_NONCE_BYTES, AES_TAG_BYTES,
+    };
+    #[cfg(feature = "alloc")]
+    pub use crate::{decrypt_aes_gcm, decrypt_aes_gcm_with_tag_len, encrypt_aes_gcm};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    fn test_prelude_into_round_trip() {
+        let (key, nonce) = ([7u8; AES_KEY_BYTES], [8u8; AES_NONCE_BYTES]);
+        let mut sealed = [0u8; 5 + AES_TAG_BYTES];
+        let n = encrypt_aes_gcm_into(&key, &nonce, b"hello", &mut sealed).unwrap();
+        let mut opened = [0u8; 5];
+        assert_eq!(decrypt_aes_gcm_into(&key, &nonce, &sealed[..n], &mut opened), Ok(5));
+        assert_eq!(&opened, b"hello");
+    }
+
+    #[test]
+    fn test_aead_ids() {
+        assert_eq!(AeadAlgorithm::default(), AeadAlgorithm::Aes256Gcm);
+        assert_eq!(AeadAlgorithm::from_id(AEAD_ID_AES_256_GCM), Ok(AeadAlgorithm::Aes256Gcm));
+        assert_eq!(AeadAlgorithm::Aes256Gcm.id(), 0x01);
+        assert_eq!(AeadAlgorithm::from_id(0x00), Err(PqcError::UnsupportedAlgorithm));
+        assert_eq!(AeadAlgorithm::from_id(0x03), Err(PqcError::UnsupportedAlgorithm));
+        #[cfg(not(feature = "chacha20poly1305"))]
+        assert_eq!(AeadAlgorithm::from_id(0x02), Err(PqcError::UnsupportedAlgorithm));
+    }
+
+    #[test]
+    #[cfg(feature = "chacha20poly1305")]
+    fn test_chacha20_poly1305_rfc8439_vector() {
+        // RFC 8439 §2.8.2
+        let key: [u8; 32] = core::array::from_fn(|i| 0x80 + i as u8);
+        let nonce = [0x07, 0, 0, 0, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+        let aad = [0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7];
+        let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+        let tag = [
+            0x1a, 0xe1, 0x0b, 0x59, 0x4f, 0x09, 0xe2, 0x6a, 0x7e, 0x90, 0x2e, 0xcb, 0xd0, 0x60, 0x06, 0x91,
+        ];
+
+        let chacha = AeadAlgorithm::from_id(AEAD_ID_CHACHA20_POLY1305).unwrap();
+        assert_eq!(chacha, AeadAlgorithm::ChaCha20Poly1305);
+        let mut sealed = [0u8; 114 + 16];
+        assert_eq!(chacha.seal_into(&key, &nonce, &aad, plaintext, &mut sealed), Ok(130));
+        assert_eq!(&sealed[..4], &[0xd3, 0x1a, 0x8d, 0x34]);
+        assert_eq!(sealed[114..], tag);
+
+        let mut opened = [0u8; 114];
+        assert_eq!(chacha.open_into(&key, &nonce, &aad, &sealed, &mut opened), Ok(114));
+        assert_eq!(&opened[..], &plaintext[..]);
+        assert!(AeadAlgorithm::Aes256Gcm.open_into(&key, &nonce, &aad, &sealed, &mut opened).is_err());
+        sealed[0] ^= 1;
+        assert_eq!(chacha.open_into(&key, &nonce, &aad, &sealed, &mut opened), Err(PqcError::AeadOperationFailed));
+        assert_eq!(opened, [0u8; 114]);
+    }
+}
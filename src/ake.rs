@@ -0,0 +1,152 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// One-Shot Authenticated Key Exchange (ML-KEM-1024 + ML-DSA-65)
+// ------------------------------------------------------------------------
+//! A single-message authenticated key exchange:
+//!
+//! ```text
+//! initiator:  (ct, ss) = ML-KEM.Encaps(responder_kem_pk)
+//!             sig      = ML-DSA.Sign(initiator_dsa_sk, ct, ctx = AKE_CONTEXT)
+//!             send ct || sig
+//! responder:  ML-DSA.Verify(initiator_dsa_pk, ct, AKE_CONTEXT, sig) else reject
+//!             ss = ML-KEM.Decaps(responder_kem_sk, ct)
+//! ```
+//!
+//! The responder learns that `ss` is shared with the holder of the
+//! initiator's signing key; the initiator learns that only the holder of
+//! the responder's KEM secret key can compute it. The signature is checked
+//! before decapsulation, so a ciphertext from anyone else is never fed to
+//! the decapsulation key. Signing under [`AKE_CONTEXT`] keeps these
+//! signatures from being accepted as ordinary signatures over the same
+//! bytes, and vice versa.
+//!
+//! There is no replay protection: a recorded `(ct, sig)` yields the same
+//! `ss` again. Protocols that need freshness must bind a nonce or
+//! transcript into their key derivation.
+
+use zeroize::Zeroizing;
+
+use crate::error::{PqcError, Result};
+use crate::rng::{self, SeedSource};
+use crate::{
+    decapsulate_shared_secret, sign_message_with_context_and_randomness,
+    try_encapsulate_shared_secret_with_randomness, verify_signature_with_context,
+    DilithiumPublicKey, DilithiumSecretKey, DilithiumSignature, KyberCiphertext, KyberPublicKey,
+    KyberSecretKey, KyberSharedSecret, ML_DSA_SIGN_SEED_BYTES, ML_KEM_ENCAP_SEED_BYTES,
+};
+
+/// FIPS 204 context string the initiator signs the ciphertext under
+pub const AKE_CONTEXT: &[u8] = b"pqc-combo ake v1";
+
+/// Encapsulate to `their_kem_pk` and sign the ciphertext, with OS randomness
+///
+/// Returns `InvalidPublicKey` if `their_kem_pk` fails the FIPS 203 §7.2
+/// modulus check, or `SigningFailure`.
+#[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+pub fn ake_initiate(
+    their_kem_pk: &KyberPublicKey,
+    my_dsa_sk: &DilithiumSecretKey,
+) -> Result<(KyberCiphertext, DilithiumSignature, KyberSharedSecret)> {
+    ake_initiate_with_randomness(their_kem_pk, my_dsa_sk, rng::generate_seed_32(), rng::generate_seed_32())
+}
+
+/// [`ake_initiate`] with encapsulation and signing randomness from `rng`
+pub fn ake_initiate_with_rng<R: SeedSource + ?Sized>(
+    their_kem_pk: &KyberPublicKey,
+    my_dsa_sk: &DilithiumSecretKey,
+    rng: &mut R,
+) -> Result<(KyberCiphertext, DilithiumSignature, KyberSharedSecret)> {
+    let encap = Zeroizing::new(rng::seed_32_from(rng)?);
+    let sign = Zeroizing::new(rng::seed_32_from(rng)?);
+    ake_initiate_with_randomness(their_kem_pk, my_dsa_sk, *encap, *sign)
+}
+
+/// [`ake_initiate`] with caller-supplied randomness (KATs, reproducible tests)
+///
+/// An all-zero seed is `InvalidSeed`.
+pub fn ake_initiate_with_randomness(
+    their_kem_pk: &KyberPublicKey,
+    my_dsa_sk: &DilithiumSecretKey,
+    encap_randomness: [u8; ML_KEM_ENCAP_SEED_BYTES],
+    sign_randomness: [u8; ML_DSA_SIGN_SEED_BYTES],
+) -> Result<(KyberCiphertext, DilithiumSignature, KyberSharedSecret)> {
+    let encap_randomness = Zeroizing::new(encap_randomness);
+    let sign_randomness = Zeroizing::new(sign_randomness);
+    rng::check_seed_32(&encap_randomness)?;
+    let (ct, ss) = try_encapsulate_shared_secret_with_randomness(their_kem_pk, *encap_randomness)?;
+    let sig = sign_message_with_context_and_randomness(my_dsa_sk, ct.as_slice(), AKE_CONTEXT, *sign_randomness)?;
+    Ok((ct, sig, ss))
+}
+
+/// Check the initiator's signature over `ct`, then decapsulate
+///
+/// Returns `VerificationFailure`, without touching `my_kem_sk`, unless
+/// `sig` is `their_dsa_pk`'s signature over exactly `ct`.
+pub fn ake_respond(
+    my_kem_sk: &KyberSecretKey,
+    their_dsa_pk: &DilithiumPublicKey,
+    ct: &KyberCiphertext,
+    sig: &DilithiumSignature,
+) -> Result<KyberSharedSecret> {
+    if !verify_signature_with_context(their_dsa_pk, ct.as_slice(), AKE_CONTEXT, sig) {
+        return Err(PqcError::VerificationFailure);
+    }
+    Ok(decapsulate_shared_secret(my_kem_sk, ct))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DilithiumKeypair, KyberKeys, ML_KEM_1024_PK_BYTES};
+
+    fn parties() -> (KyberKeys, DilithiumKeypair) {
+        (KyberKeys::generate_key_pair_with_seed([0xa1; 64]), DilithiumKeypair::generate_with_seed([0xa2; 32]))
+    }
+
+    #[test]
+    fn test_ake_roundtrip() {
+        let (responder, initiator) = parties();
+        let (ct, sig, ss) = ake_initiate_with_randomness(&responder.pk, &initiator.sk, [0xa3; 32], [0xa4; 32]).unwrap();
+        assert_eq!(ake_respond(&responder.sk, &initiator.pk, &ct, &sig).unwrap(), ss);
+
+        // Same randomness, same transcript
+        let (ct2, sig2, _) = ake_initiate_with_randomness(&responder.pk, &initiator.sk, [0xa3; 32], [0xa4; 32]).unwrap();
+        assert!(ct2 == ct && sig2 == sig);
+    }
+
+    #[test]
+    fn test_ake_rejects_unauthenticated_ciphertexts() {
+        let (responder, initiator) = parties();
+        let (ct, sig, _) = ake_initiate_with_randomness(&responder.pk, &initiator.sk, [0xa5; 32], [0xa6; 32]).unwrap();
+
+        // Another signer, or another ciphertext under the same signature
+        let impostor = DilithiumKeypair::generate_with_seed([0xa7; 32]);
+        assert_eq!(ake_respond(&responder.sk, &impostor.pk, &ct, &sig).map(|_| ()), Err(PqcError::VerificationFailure));
+        let mut bytes = ct.to_array();
+        bytes[0] ^= 1;
+        let tampered = KyberCiphertext::from(bytes);
+        assert_eq!(
+            ake_respond(&responder.sk, &initiator.pk, &tampered, &sig).map(|_| ()),
+            Err(PqcError::VerificationFailure)
+        );
+
+        // A signature over the ciphertext under another context is not an AKE signature
+        let plain = crate::sign_message_with_context_and_randomness(&initiator.sk, ct.as_slice(), b"other", [0xa8; 32]).unwrap();
+        assert_eq!(ake_respond(&responder.sk, &initiator.pk, &ct, &plain).map(|_| ()), Err(PqcError::VerificationFailure));
+    }
+
+    #[test]
+    fn test_ake_rejects_bad_inputs() {
+        let (_, initiator) = parties();
+        let unreduced = KyberPublicKey::from([0xff; ML_KEM_1024_PK_BYTES]);
+        assert_eq!(
+            ake_initiate_with_randomness(&unreduced, &initiator.sk, [0xa9; 32], [0xaa; 32]).map(|_| ()),
+            Err(PqcError::InvalidPublicKey)
+        );
+        let (responder, _) = parties();
+        assert_eq!(
+            ake_initiate_with_randomness(&responder.pk, &initiator.sk, [0; 32], [0xaa; 32]).map(|_| ()),
+            Err(PqcError::InvalidSeed)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Background POST with an Awaitable Readiness Future
+// ------------------------------------------------------------------------
+//! Run POST off the async runtime, behind the `async-post` feature.
+//!
+//! [`spawn_post`] moves the module into the POST state on the calling
+//! thread, so every crypto call made from then on returns the retriable
+//! `FipsPostInProgress` until the self-tests finish. It then runs them on a
+//! dedicated OS thread. [`PostHandle::ready`] is a plain
+//! [`core::future::Future`] that resolves with the POST result once the
+//! module is Operational or in Error, so it works under any executor
+//! (tokio, async-std, smol, ...) without this crate depending on one.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Arc, Mutex};
+use std::vec::Vec;
+
+use crate::error::Result;
+use crate::preop::run_entered_post;
+use crate::state::enter_post_state;
+
+/// POST outcome plus the tasks waiting for it
+#[derive(Default)]
+struct Slot {
+    result: Option<Result<()>>,
+    wakers: Vec<Waker>,
+}
+
+/// Handle to a POST started by [`spawn_post`]
+///
+/// Clones share the same run; any number of tasks may await it.
+#[derive(Clone)]
+pub struct PostHandle {
+    slot: Arc<Mutex<Slot>>,
+}
+
+impl PostHandle {
+    /// Future resolving to the POST result
+    pub fn ready(&self) -> Ready<'_> {
+        Ready { handle: self }
+    }
+
+    /// POST result if it has finished, without waiting
+    pub fn try_result(&self) -> Option<Result<()>> {
+        self.lock().result
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Slot> {
+        self.slot.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn complete(&self, result: Result<()>) {
+        let wakers = {
+            let mut slot = self.lock();
+            slot.result = Some(result);
+            core::mem::take(&mut slot.wakers)
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
+/// Future returned by [`PostHandle::ready`]
+pub struct Ready<'a> {
+    handle: &'a PostHandle,
+}
+
+impl Future for Ready<'_> {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let mut slot = self.handle.lock();
+        match slot.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                if !slot.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                    slot.wakers.push(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Start POST on a background thread and return at once
+///
+/// Valid from the same states as [`crate::run_post`]; otherwise the handle
+/// resolves immediately to `IllegalStateTransition` and the state is left
+/// unchanged.
+pub fn spawn_post() -> PostHandle {
+    let handle = PostHandle { slot: Arc::new(Mutex::new(Slot::default())) };
+    if let Err(e) = enter_post_state() {
+        handle.complete(Err(e));
+        return handle;
+    }
+    let worker = handle.clone();
+    std::thread::spawn(move || worker.complete(run_entered_post()));
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PqcError;
+    use crate::state::{check_operational, lock_state_for_test, reset_fips_state};
+    use std::task::Wake;
+    use std::thread::Thread;
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Minimal executor: poll on this thread, park between wake-ups
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = core::pin::pin!(future);
+        let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            std::thread::park();
+        }
+    }
+
+    #[test]
+    fn test_spawned_post_becomes_ready() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+
+        let handle = spawn_post();
+        // Crypto waits until the background run has finished
+        assert!(matches!(check_operational(), Err(PqcError::FipsPostInProgress) | Ok(())));
+        assert_eq!(block_on(handle.ready()), Ok(()));
+        assert_eq!(block_on(handle.clone().ready()), Ok(()));
+        assert_eq!(handle.try_result(), Some(Ok(())));
+        assert_eq!(check_operational(), Ok(()));
+        reset_fips_state();
+    }
+
+    #[test]
+    fn test_spawn_from_error_state_refused() {
+        let _guard = lock_state_for_test();
+        crate::state::enter_error_state();
+
+        let handle = spawn_post();
+        assert_eq!(handle.try_result(), Some(Err(PqcError::IllegalStateTransition)));
+        assert_eq!(block_on(handle.ready()), Err(PqcError::IllegalStateTransition));
+        assert_eq!(check_operational(), Err(PqcError::FipsErrorState));
+        reset_fips_state();
+    }
+}
@@ -22,6 +22,7 @@
 //! Uses NIST CAVP test vectors for validation.
 
 use crate::error::{PqcError, Result};
+use crate::events::{cast_result, SelfTestId};
 use sha3::{Digest, Sha3_256, Sha3_512, Shake128, Shake256};
 use sha3::digest::{ExtendableOutput, Update, XofReader};
 
@@ -135,12 +136,13 @@ pub fn shake256_cast() -> Result<()> {
 /// 
 /// This must be called before any cryptographic operations in FIPS mode.
 /// All four hash functions (SHA3-256, SHA3-512, SHAKE-128, SHAKE-256)
-/// must pass their CASTs.
+/// must pass their CASTs. Each outcome is reported as a
+/// [`crate::events::FipsEvent`].
 pub fn run_hash_casts() -> Result<()> {
-    sha3_256_cast()?;
-    sha3_512_cast()?;
-    shake128_cast()?;
-    shake256_cast()?;
+    cast_result(SelfTestId::Sha3_256, sha3_256_cast())?;
+    cast_result(SelfTestId::Sha3_512, sha3_512_cast())?;
+    cast_result(SelfTestId::Shake128, shake128_cast())?;
+    cast_result(SelfTestId::Shake256, shake256_cast())?;
     Ok(())
 }
 
@@ -170,6 +172,8 @@ mod tests {
     
     #[test]
     fn test_all_hash_casts_pass() {
+        // Records into the shared self-test status
+        let _guard = crate::state::lock_state_for_test();
         assert!(run_hash_casts().is_ok(), "All hash CASTs should pass");
     }
     
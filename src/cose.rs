@@ -0,0 +1,247 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// COSE (RFC 9052) Support for ML-DSA-65
+// ------------------------------------------------------------------------
+//! COSE_Key and COSE_Sign1 encoding for ML-DSA-65 keys and signatures.
+//!
+//! Key type and algorithm identifiers follow draft-ietf-cose-dilithium:
+//! `kty` = AKP (7), `alg` = ML-DSA-65 (-49). Signatures are computed over the
+//! RFC 9052 `Sig_structure` with an empty ML-DSA context string.
+
+extern crate alloc;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub use ciborium::Value as CborValue;
+
+use crate::error::{PqcError, Result};
+use crate::DilithiumPublicKey;
+#[cfg(not(feature = "require-context"))]
+use crate::{verify_signature_with_context, DilithiumSignature};
+#[cfg(not(any(feature = "explicit-rng", feature = "require-context")))]
+use crate::{sign_message_with_context, DilithiumSecretKey};
+
+/// COSE key type: Algorithm Key Pair (AKP)
+pub const COSE_KTY_AKP: i64 = 7;
+/// COSE algorithm identifier for ML-DSA-65
+pub const COSE_ALG_ML_DSA_65: i64 = -49;
+/// CBOR tag for a COSE_Sign1 structure
+pub const COSE_SIGN1_TAG: u64 = 18;
+
+const LABEL_KTY: i64 = 1;
+const LABEL_ALG: i64 = 3;
+const LABEL_AKP_PUB: i64 = -1;
+#[cfg(not(feature = "require-context"))]
+const HEADER_ALG: i64 = 1;
+
+/// Encode a public key as a CBOR COSE_Key map
+///
+/// Map entries are emitted in deterministic (RFC 8949 §4.2.1) key order:
+/// `kty`, `alg`, `pub`.
+pub fn to_cose_key(pk: &DilithiumPublicKey) -> Result<Vec<u8>> {
+    let key = CborValue::Map(vec![
+        (CborValue::from(LABEL_KTY), CborValue::from(COSE_KTY_AKP)),
+        (CborValue::from(LABEL_ALG), CborValue::from(COSE_ALG_ML_DSA_65)),
+        (CborValue::from(LABEL_AKP_PUB), CborValue::Bytes(pk.as_slice().to_vec())),
+    ]);
+    encode(&key)
+}
+
+/// Sign `payload` and return a tagged COSE_Sign1 structure
+///
+/// The protected header always carries `alg` = ML-DSA-65; `protected_headers`
+/// supplies additional entries and must not redefine `alg`. The unprotected
+/// header is empty and the payload is attached.
+#[cfg(not(any(feature = "explicit-rng", feature = "require-context")))]
+pub fn cose_sign1(
+    sk: &DilithiumSecretKey,
+    payload: &[u8],
+    protected_headers: &[(CborValue, CborValue)],
+) -> Result<Vec<u8>> {
+    let mut headers = Vec::with_capacity(protected_headers.len() + 1);
+    headers.push((CborValue::from(HEADER_ALG), CborValue::from(COSE_ALG_ML_DSA_65)));
+    for (label, value) in protected_headers {
+        if *label == CborValue::from(HEADER_ALG) {
+            return Err(PqcError::InvalidEncoding);
+        }
+        headers.push((label.clone(), value.clone()));
+    }
+    let protected = encode(&CborValue::Map(headers))?;
+
+    let to_be_signed = sig_structure(&protected, payload)?;
+    let signature = sign_message_with_context(sk, &to_be_signed, &[])?;
+
+    let sign1 = CborValue::Tag(
+        COSE_SIGN1_TAG,
+        alloc::boxed::Box::new(CborValue::Array(vec![
+            CborValue::Bytes(protected),
+            CborValue::Map(Vec::new()),
+            CborValue::Bytes(payload.to_vec()),
+            CborValue::Bytes(signature.as_slice().to_vec()),
+        ])),
+    );
+    encode(&sign1)
+}
+
+/// Verify a COSE_Sign1 structure and return its payload
+///
+/// Accepts tagged or untagged COSE_Sign1. The protected header must declare
+/// `alg` = ML-DSA-65 and the payload must be attached.
+#[cfg(not(feature = "require-context"))]
+pub fn cose_sign1_verify(pk: &DilithiumPublicKey, cose_sign1: &[u8]) -> Result<Vec<u8>> {
+    let value: CborValue =
+        ciborium::from_reader(cose_sign1).map_err(|_| PqcError::InvalidEncoding)?;
+    let value = match value {
+        CborValue::Tag(COSE_SIGN1_TAG, inner) => *inner,
+        CborValue::Tag(_, _) => return Err(PqcError::InvalidEncoding),
+        other => other,
+    };
+
+    let (protected, payload, signature) = match value {
+        CborValue::Array(items) => match <[CborValue; 4]>::try_from(items) {
+            Ok([
+                CborValue::Bytes(protected),
+                CborValue::Map(_),
+                CborValue::Bytes(payload),
+                CborValue::Bytes(signature),
+            ]) => (protected, payload, signature),
+            _ => return Err(PqcError::InvalidEncoding),
+        },
+        _ => return Err(PqcError::InvalidEncoding),
+    };
+
+    let headers: CborValue =
+        ciborium::from_reader(protected.as_slice()).map_err(|_| PqcError::InvalidEncoding)?;
+    let alg = match headers {
+        CborValue::Map(entries) => entries
+            .into_iter()
+            .find(|(label, _)| *label == CborValue::from(HEADER_ALG))
+            .map(|(_, alg)| alg),
+        _ => None,
+    };
+    if alg != Some(CborValue::from(COSE_ALG_ML_DSA_65)) {
+        return Err(PqcError::InvalidEncoding);
+    }
+
+    let signature = DilithiumSignature::try_from(signature.as_slice())?;
+
+    let to_be_signed = sig_structure(&protected, &payload)?;
+    if verify_signature_with_context(pk, &to_be_signed, &[], &signature) {
+        Ok(payload)
+    } else {
+        Err(PqcError::VerificationFailure)
+    }
+}
+
+/// Build the RFC 9052 §4.4 `Sig_structure` for COSE_Sign1
+///
+/// `["Signature1", body_protected, external_aad, payload]` with an empty
+/// external AAD.
+#[cfg(not(feature = "require-context"))]
+fn sig_structure(protected: &[u8], payload: &[u8]) -> Result<Vec<u8>> {
+    encode(&CborValue::Array(vec![
+        CborValue::Text("Signature1".into()),
+        CborValue::Bytes(protected.to_vec()),
+        CborValue::Bytes(Vec::new()),
+        CborValue::Bytes(payload.to_vec()),
+    ]))
+}
+
+fn encode(value: &CborValue) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    ciborium::into_writer(value, &mut out).map_err(|_| PqcError::InvalidEncoding)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_sig_structure_layout() {
+        let protected = encode(&CborValue::Map(vec![(
+            CborValue::from(HEADER_ALG),
+            CborValue::from(COSE_ALG_ML_DSA_65),
+        )]))
+        .unwrap();
+        assert_eq!(protected, [0xa1, 0x01, 0x38, 0x30]);
+
+        let tbs = sig_structure(&protected, b"hi").unwrap();
+        let mut expected = vec![0x84, 0x6a];
+        expected.extend_from_slice(b"Signature1");
+        expected.extend_from_slice(&[0x44, 0xa1, 0x01, 0x38, 0x30, 0x40, 0x42]);
+        expected.extend_from_slice(b"hi");
+        assert_eq!(tbs, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_cose_key_layout() {
+        let (pk, _sk) = crate::generate_dilithium_keypair_with_seed([0x51; 32]);
+        let key = to_cose_key(&pk).unwrap();
+        // map(3), kty: 7, alg: -49, pub: bstr(1952)
+        assert_eq!(&key[..9], &[0xa3, 0x01, 0x07, 0x03, 0x38, 0x30, 0x20, 0x59, 0x07]);
+        assert_eq!(&key[9..10], &[0xa0]);
+        assert_eq!(&key[10..], pk.as_slice());
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "std",
+        not(any(feature = "explicit-rng", feature = "require-context"))
+    ))]
+    fn test_cose_sign1_roundtrip() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x52; 32]);
+        let headers = [(CborValue::from(4), CborValue::Bytes(b"kid-1".to_vec()))];
+        let sign1 = cose_sign1(&sk, b"attestation", &headers).unwrap();
+        // tag(18), array(4)
+        assert_eq!(&sign1[..2], &[0xd2, 0x84]);
+
+        assert_eq!(cose_sign1_verify(&pk, &sign1).unwrap(), b"attestation");
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "std",
+        not(any(feature = "explicit-rng", feature = "require-context"))
+    ))]
+    fn test_cose_sign1_rejects_tampering() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x53; 32]);
+        let (other_pk, _) = crate::generate_dilithium_keypair_with_seed([0x54; 32]);
+        let sign1 = cose_sign1(&sk, b"attestation", &[]).unwrap();
+
+        assert_eq!(
+            cose_sign1_verify(&other_pk, &sign1).unwrap_err(),
+            PqcError::VerificationFailure
+        );
+
+        // The payload bytes sit right before the 3309-byte signature bstr
+        let mut tampered = sign1.clone();
+        let payload_end = tampered.len() - crate::ML_DSA_65_SIG_BYTES - 3;
+        tampered[payload_end - 1] ^= 0x01;
+        assert_eq!(
+            cose_sign1_verify(&pk, &tampered).unwrap_err(),
+            PqcError::VerificationFailure
+        );
+
+        assert_eq!(
+            cose_sign1_verify(&pk, &sign1[..sign1.len() - 1]).unwrap_err(),
+            PqcError::InvalidEncoding
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "std",
+        not(any(feature = "explicit-rng", feature = "require-context"))
+    ))]
+    fn test_cose_sign1_rejects_alg_override() {
+        let (_pk, sk) = crate::generate_dilithium_keypair_with_seed([0x55; 32]);
+        let headers = [(CborValue::from(HEADER_ALG), CborValue::from(-7))];
+        assert_eq!(
+            cose_sign1(&sk, b"payload", &headers).unwrap_err(),
+            PqcError::InvalidEncoding
+        );
+    }
+}
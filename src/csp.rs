@@ -7,14 +7,25 @@
 extern crate alloc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use zeroize::Zeroizing;
+
+use core::ops::Deref;
+#[cfg(debug_assertions)]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use zeroize::Zeroize;
 
 use crate::error::{PqcError, Result};
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", feature = "alloc")))]
 use crate::state::check_operational;
 
 #[cfg(feature = "ml-kem")]
-use crate::{KyberSecretKey, KyberSharedSecret};
+use crate::KyberSharedSecret;
+
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+use crate::KyberSecretKey;
 
-#[cfg(feature = "ml-dsa")]
+#[cfg(all(feature = "ml-dsa", feature = "alloc"))]
 use crate::DilithiumSecretKey;
 
 /// CSP Export Policy
@@ -47,19 +58,23 @@ pub fn check_csp_export_allowed() -> Result<()> {
 }
 
 /// Guard function for Kyber secret key export
+///
+/// The exported bytes are wiped when the returned buffer is dropped.
 #[cfg(all(feature = "ml-kem", feature = "alloc"))]
-pub fn guard_kyber_sk_export(sk: &KyberSecretKey) -> Result<Vec<u8>> {
+pub fn guard_kyber_sk_export(sk: &KyberSecretKey) -> Result<Zeroizing<Vec<u8>>> {
     check_operational()?;
     check_csp_export_allowed()?;
-    Ok(sk.as_slice().to_vec())
+    Ok(Zeroizing::new(sk.as_slice().to_vec()))
 }
 
 /// Guard function for Dilithium secret key export
+///
+/// The exported bytes are wiped when the returned buffer is dropped.
 #[cfg(all(feature = "ml-dsa", feature = "alloc"))]
-pub fn guard_dilithium_sk_export(sk: &DilithiumSecretKey) -> Result<Vec<u8>> {
+pub fn guard_dilithium_sk_export(sk: &DilithiumSecretKey) -> Result<Zeroizing<Vec<u8>>> {
     check_operational()?;
     check_csp_export_allowed()?;
-    Ok(sk.as_slice().to_vec())
+    Ok(Zeroizing::new(sk.as_slice().to_vec()))
 }
 
 /// Guard function for shared secret export
@@ -70,6 +85,150 @@ pub fn guard_shared_secret_export(ss: &KyberSharedSecret) -> Result<&[u8]> {
     Ok(ss)
 }
 
+/// Guards dropped while still holding their key (debug builds only)
+#[cfg(debug_assertions)]
+static LEAKED_SECRETS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of [`SecretKeyGuard`]s dropped without `zeroize_now`/`into_inner`
+///
+/// Counted in debug builds only; release builds always return 0.
+pub fn leaked_secret_count() -> usize {
+    #[cfg(debug_assertions)]
+    {
+        LEAKED_SECRETS.load(Ordering::Relaxed)
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        0
+    }
+}
+
+/// Development tripwire for secret keys that are never explicitly retired
+///
+/// Wrap a key whose end of life should be an explicit step, then finish
+/// with [`SecretKeyGuard::zeroize_now`] or hand it on with
+/// [`SecretKeyGuard::into_inner`]. A guard dropped while it still holds the
+/// key wipes it anyway and, in debug builds, bumps [`leaked_secret_count`],
+/// so tests can assert that every CSP path retires its keys. Release builds
+/// keep only the wipe.
+pub struct SecretKeyGuard<K: Zeroize> {
+    key: Option<K>,
+}
+
+impl<K: Zeroize> SecretKeyGuard<K> {
+    pub fn new(key: K) -> Self {
+        Self { key: Some(key) }
+    }
+
+    /// Wipe the key now: the intended end of its lifetime
+    pub fn zeroize_now(mut self) {
+        if let Some(mut key) = self.key.take() {
+            key.zeroize();
+        }
+    }
+
+    /// Release the key to a caller that takes over responsibility for it
+    pub fn into_inner(mut self) -> K {
+        self.key.take().expect("SecretKeyGuard holds its key until consumed")
+    }
+}
+
+impl<K: Zeroize> Deref for SecretKeyGuard<K> {
+    type Target = K;
+
+    fn deref(&self) -> &K {
+        self.key.as_ref().expect("SecretKeyGuard holds its key until consumed")
+    }
+}
+
+impl<K: Zeroize> Drop for SecretKeyGuard<K> {
+    fn drop(&mut self) {
+        if let Some(mut key) = self.key.take() {
+            key.zeroize();
+            #[cfg(debug_assertions)]
+            LEAKED_SECRETS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Owner of a CSP that wipes it on drop and keeps it out of logs
+///
+/// `Debug` prints `[REDACTED]`, and there is no `Clone`: a copy of the
+/// secret has to be asked for by name with [`SecretBox::unsafe_clone`].
+/// Reads go through `Deref` (or [`SecretBox::expose_secret`]), so a
+/// `&SecretBox<KyberSecretKey>` can be passed wherever a `&KyberSecretKey`
+/// is expected. The value is held inline, not on the heap, so the wrapper
+/// works without `alloc`.
+pub struct SecretBox<T: Zeroize> {
+    inner: Option<T>,
+}
+
+impl<T: Zeroize> SecretBox<T> {
+    pub fn new(secret: T) -> Self {
+        Self { inner: Some(secret) }
+    }
+
+    /// Borrow the secret; an explicit spelling of the `Deref` impl
+    pub fn expose_secret(&self) -> &T {
+        self.inner.as_ref().expect("SecretBox holds its secret until dropped")
+    }
+
+    /// Mutable access to the secret, e.g. to overwrite it in place
+    pub fn expose_secret_mut(&mut self) -> &mut T {
+        self.inner.as_mut().expect("SecretBox holds its secret until dropped")
+    }
+
+    /// Take the secret out; the caller is responsible for wiping it
+    pub fn into_inner(mut self) -> T {
+        self.inner.take().expect("SecretBox holds its secret until dropped")
+    }
+}
+
+impl<T: Zeroize + Clone> SecretBox<T> {
+    /// Second, independently wiped copy of the secret
+    ///
+    /// Named so that every duplication of a CSP stands out in review.
+    pub fn unsafe_clone(&self) -> Self {
+        Self::new(self.expose_secret().clone())
+    }
+}
+
+impl<T: Zeroize> From<T> for SecretBox<T> {
+    fn from(secret: T) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl<T: Zeroize> Deref for SecretBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.expose_secret()
+    }
+}
+
+impl<T: Zeroize> core::fmt::Debug for SecretBox<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SecretBox([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> Zeroize for SecretBox<T> {
+    fn zeroize(&mut self) {
+        if let Some(secret) = self.inner.as_mut() {
+            secret.zeroize();
+        }
+    }
+}
+
+impl<T: Zeroize> Drop for SecretBox<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Zeroize> zeroize::ZeroizeOnDrop for SecretBox<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,18 +253,18 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std", feature = "alloc"))]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std", feature = "alloc", not(feature = "explicit-rng")))]
     fn test_guard_functions_check_operational() {
         use crate::{generate_dilithium_keypair, KyberKeys};
-        use crate::state::enter_operational_state;
+        use crate::state::{enter_operational_state, enter_post_state, lock_state_for_test, reset_fips_state};
         
+        let _guard = lock_state_for_test();
         let _keys = KyberKeys::generate_key_pair();
         let (_pk, _sk_dil) = generate_dilithium_keypair();
         
         // Should fail when not operational
         #[cfg(not(feature = "fips_140_3"))]
         {
-            use crate::state::reset_fips_state;
             reset_fips_state();
             let result = guard_kyber_sk_export(&_keys.sk);
             assert!(result.is_err(), "Should fail when not operational");
@@ -116,7 +275,9 @@ mod tests {
         }
         
         // Should work when operational (non-FIPS)
-        enter_operational_state();
+        reset_fips_state();
+        enter_post_state().unwrap();
+        enter_operational_state().unwrap();
         
         #[cfg(not(feature = "fips_140_3"))]
         {
@@ -128,16 +289,17 @@ mod tests {
     #[test]
     #[cfg(all(feature = "fips_140_3", feature = "ml-kem", feature = "ml-dsa", feature = "std", feature = "alloc"))]
     fn test_fips_blocks_csp_export() {
-        use crate::{generate_dilithium_keypair, KyberKeys};
-        use crate::state::reset_fips_state;
+        use crate::{generate_dilithium_keypair_with_seed, KyberKeys};
+        use crate::state::{lock_state_for_test, reset_fips_state};
         use crate::preop::run_post;
         
+        let _guard = lock_state_for_test();
         // Reset state and run POST to become operational in FIPS mode
         reset_fips_state();
         run_post().expect("POST should succeed");
         
-        let keys = KyberKeys::generate_key_pair();
-        let (_, sk_dil) = generate_dilithium_keypair();
+        let keys = KyberKeys::generate_key_pair_with_seed([0x45; 64]);
+        let (_, sk_dil) = generate_dilithium_keypair_with_seed([0x46; 32]);
         
         // When operational, FIPS mode blocks export
         assert!(guard_kyber_sk_export(&keys.sk).is_err());
@@ -148,15 +310,17 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std"))]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
     fn test_keys_use_approved_api() {
         use crate::{encapsulate_shared_secret, decapsulate_shared_secret};
         use crate::{sign_message, verify_signature};
         use crate::{generate_dilithium_keypair, KyberKeys};
-        use crate::state::{reset_fips_state, enter_operational_state};
+        use crate::state::{reset_fips_state, enter_operational_state, enter_post_state, lock_state_for_test};
         
+        let _guard = lock_state_for_test();
         reset_fips_state();
-        enter_operational_state();
+        enter_post_state().unwrap();
+        enter_operational_state().unwrap();
         
         // Keys should work through approved API regardless of export policy
         let keys = KyberKeys::generate_key_pair();
@@ -169,4 +333,51 @@ mod tests {
         let sig = sign_message(&sk, msg);
         assert!(verify_signature(&pk, msg, &sig));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_secret_key_guard_counts_unretired_keys() {
+        let before = leaked_secret_count();
+
+        SecretKeyGuard::new([0x11u8; 32]).zeroize_now();
+        let key = SecretKeyGuard::new([0x22u8; 32]);
+        assert_eq!(key[0], 0x22);
+        assert_eq!(key.into_inner(), [0x22; 32]);
+        assert_eq!(leaked_secret_count(), before);
+
+        #[cfg(feature = "ml-kem")]
+        {
+            let keys = crate::KyberKeys::generate_key_pair_with_seed([0x44; 64]);
+            let guard = SecretKeyGuard::new(keys.sk);
+            assert_eq!(guard.as_slice().len(), crate::ML_KEM_1024_SK_BYTES);
+            guard.zeroize_now();
+            assert_eq!(leaked_secret_count(), before);
+        }
+
+        drop(SecretKeyGuard::new([0x33u8; 32]));
+        let expected = if cfg!(debug_assertions) { before + 1 } else { 0 };
+        assert_eq!(leaked_secret_count(), expected);
+    }
+
+    #[test]
+    fn test_secret_box_hygiene() {
+        let mut secret = SecretBox::new([0x55u8; 32]);
+        #[cfg(feature = "alloc")]
+        assert_eq!(alloc::format!("{:?}", secret), "SecretBox([REDACTED])");
+        assert_eq!(secret[0], 0x55);
+
+        // An explicit clone is an independent copy
+        let copy = secret.unsafe_clone();
+        secret.zeroize();
+        assert_eq!(*secret.expose_secret(), [0u8; 32]);
+        assert_eq!(copy.into_inner(), [0x55; 32]);
+
+        #[cfg(feature = "ml-kem")]
+        {
+            let keys = crate::KyberKeys::generate_key_pair_with_seed([0x66; 64]);
+            let (ct, ss) = crate::encapsulate_shared_secret_with_randomness(&keys.pk, [0x67; 32]);
+            assert_eq!(crate::decapsulate_shared_secret(&keys.sk, &ct), ss);
+            let sk: &crate::KyberSecretKey = keys.sk.expose_secret();
+            assert!(crate::ct::secret_key_eq(&keys.sk.unsafe_clone(), &SecretBox::from(sk.clone())));
+        }
+    }
+}
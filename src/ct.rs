@@ -0,0 +1,99 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Constant-Time Comparisons for Secret Material
+// ------------------------------------------------------------------------
+//! Which comparisons are constant-time:
+//!
+//! * Secret keys (`KyberSecretKey`, `DilithiumSecretKey`) implement
+//!   [`ConstantTimeEq`] and are compared with [`secret_key_eq`]. They have no
+//!   `PartialEq`, so an accidental `==` does not compile. The same holds for
+//!   a [`crate::SecretBox`] around them.
+//! * Raw secret bytes (shared secrets, exported keys, derived session keys)
+//!   are compared with [`constant_time_eq_secret`].
+//! * Public keys, ciphertexts and signatures implement `PartialEq`/`Eq` as an
+//!   ordinary, short-circuiting byte compare. Their contents are public, so
+//!   the fast compare is fine.
+
+pub use subtle::ConstantTimeEq;
+
+#[cfg(feature = "ml-kem")]
+use crate::KyberSecretKey;
+
+#[cfg(feature = "ml-dsa")]
+use crate::DilithiumSecretKey;
+
+#[cfg(feature = "ml-kem")]
+impl ConstantTimeEq for KyberSecretKey {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+#[cfg(feature = "ml-dsa")]
+impl ConstantTimeEq for DilithiumSecretKey {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+impl<T: zeroize::Zeroize + ConstantTimeEq> ConstantTimeEq for crate::SecretBox<T> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.expose_secret().ct_eq(other.expose_secret())
+    }
+}
+
+/// Compare two secret keys in constant time
+///
+/// Runtime depends only on the key length, never on where the keys differ.
+pub fn secret_key_eq<K: ConstantTimeEq>(a: &K, b: &K) -> bool {
+    a.ct_eq(b).into()
+}
+
+/// Compare two secret byte strings in constant time
+///
+/// Runtime depends only on the lengths, which are treated as public:
+/// inputs of different length compare unequal immediately.
+pub fn constant_time_eq_secret(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq_secret() {
+        assert!(constant_time_eq_secret(b"secret", b"secret"));
+        assert!(!constant_time_eq_secret(b"secret", b"secreT"));
+        assert!(!constant_time_eq_secret(b"secret", b"secret!"));
+        assert!(constant_time_eq_secret(b"", b""));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_kyber_secret_key_eq() {
+        use crate::KyberKeys;
+        let a = KyberKeys::generate_key_pair_with_seed([0x01; 64]);
+        let b = KyberKeys::generate_key_pair_with_seed([0x01; 64]);
+        let c = KyberKeys::generate_key_pair_with_seed([0x02; 64]);
+
+        assert!(secret_key_eq(&a.sk, &b.sk));
+        assert!(!secret_key_eq(&a.sk, &c.sk));
+        assert!(a.pk == b.pk);
+        assert!(a.pk != c.pk);
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_dilithium_secret_key_eq() {
+        use crate::generate_dilithium_keypair_with_seed;
+        let (pk_a, sk_a) = generate_dilithium_keypair_with_seed([0x01; 32]);
+        let (pk_b, sk_b) = generate_dilithium_keypair_with_seed([0x01; 32]);
+        let (pk_c, sk_c) = generate_dilithium_keypair_with_seed([0x02; 32]);
+
+        assert!(secret_key_eq(&sk_a, &sk_b));
+        assert!(!secret_key_eq(&sk_a, &sk_c));
+        assert!(pk_a == pk_b);
+        assert!(pk_a != pk_c);
+    }
+}
@@ -0,0 +1,145 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Hex Encoding: Constant-Time Path for Secrets, Fast Path for Public Data
+// ------------------------------------------------------------------------
+//! Two hex codecs that produce and accept the same text:
+//!
+//! - [`secret_to_hex`] / [`secret_from_hex`] run in time that depends only
+//!   on the input length. Each nibble is mapped with arithmetic and masks,
+//!   with no table lookups indexed by secret data and no branches on it. A
+//!   bad character is only reported after the whole input has been scanned.
+//!   Outputs are [`Zeroizing`]. Use these for secret keys, seeds and shared
+//!   secrets.
+//! - [`to_hex`] / [`from_hex`] use a lookup table and stop at the first bad
+//!   character. They are faster but leak through cache and timing side
+//!   channels, so they are for public keys, ciphertexts, signatures and
+//!   digests only.
+//!
+//! Encoding is lowercase; decoding accepts either case. Odd-length input or
+//! a non-hex character yields `PqcError::InvalidEncoding`. The input length
+//! is treated as public.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+use crate::error::{PqcError, Result};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Lowercase hex for public data (variable-time)
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(2 * bytes.len());
+    for &b in bytes {
+        out.push(char::from(HEX_DIGITS[usize::from(b >> 4)]));
+        out.push(char::from(HEX_DIGITS[usize::from(b & 0x0f)]));
+    }
+    out
+}
+
+/// Decode hex holding public data (variable-time)
+pub fn from_hex(s: &str) -> Result<Vec<u8>> {
+    let s = s.as_bytes();
+    if !s.len().is_multiple_of(2) {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let nibble = |c: u8| match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(PqcError::InvalidEncoding),
+    };
+    s.chunks_exact(2).map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?)).collect()
+}
+
+/// Lowercase hex for secret data, in constant time
+pub fn secret_to_hex(bytes: &[u8]) -> Zeroizing<String> {
+    // Allocated once up front so no partial copy is left behind by a realloc
+    let mut out = Zeroizing::new(String::with_capacity(2 * bytes.len()));
+    for &b in bytes {
+        out.push(char::from(encode_nibble(b >> 4)));
+        out.push(char::from(encode_nibble(b & 0x0f)));
+    }
+    out
+}
+
+/// Decode hex holding secret data, in constant time
+///
+/// Scans the whole input before returning `InvalidEncoding`, so the
+/// position of a bad character is not revealed either.
+pub fn secret_from_hex(s: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let s = s.as_bytes();
+    if !s.len().is_multiple_of(2) {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let mut out = Zeroizing::new(Vec::with_capacity(s.len() / 2));
+    let mut invalid = 0u8;
+    for pair in s.chunks_exact(2) {
+        let (hi, hi_bad) = decode_nibble(pair[0]);
+        let (lo, lo_bad) = decode_nibble(pair[1]);
+        invalid |= hi_bad | lo_bad;
+        out.push((hi << 4) | lo);
+    }
+    if invalid != 0 {
+        return Err(PqcError::InvalidEncoding);
+    }
+    Ok(out)
+}
+
+/// `0..=15` to `'0'..='9' | 'a'..='f'` without a table or branch
+fn encode_nibble(n: u8) -> u8 {
+    let n = i16::from(n);
+    // 0xffff when n > 9, else 0: shifts the result past ':'..'`'
+    let alpha = (9 - n) >> 8;
+    (n + i16::from(b'0') + (alpha & i16::from(b'a' - b'0' - 10))) as u8
+}
+
+/// Hex character to its value and a mask that is `0xff` if it is not hex
+fn decode_nibble(c: u8) -> (u8, u8) {
+    let c = i16::from(c);
+    // Each `in_*` is -1 (all ones) when `c` is inside the range, else 0
+    let in_digit = ((i16::from(b'0') - 1 - c) & (c - i16::from(b'9') - 1)) >> 8;
+    let in_lower = ((i16::from(b'a') - 1 - c) & (c - i16::from(b'f') - 1)) >> 8;
+    let in_upper = ((i16::from(b'A') - 1 - c) & (c - i16::from(b'F') - 1)) >> 8;
+    let value = (in_digit & (c - i16::from(b'0')))
+        | (in_lower & (c - i16::from(b'a') + 10))
+        | (in_upper & (c - i16::from(b'A') + 10));
+    let valid = in_digit | in_lower | in_upper;
+    (value as u8, !valid as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codecs_agree_on_every_byte() {
+        let all: Vec<u8> = (0..=255).collect();
+        let public = to_hex(&all);
+        assert_eq!(public, hex::encode(&all));
+        assert_eq!(*secret_to_hex(&all), public);
+
+        assert_eq!(from_hex(&public).unwrap(), all);
+        assert_eq!(*secret_from_hex(&public).unwrap(), all);
+        let upper = public.to_ascii_uppercase();
+        assert_eq!(from_hex(&upper).unwrap(), all);
+        assert_eq!(*secret_from_hex(&upper).unwrap(), all);
+        assert!(secret_from_hex("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_every_non_hex_character_rejected() {
+        for c in 0..=255u8 {
+            let is_hex = c.is_ascii_hexdigit();
+            assert_eq!(decode_nibble(c).1 == 0, is_hex, "character {:#04x}", c);
+            if !is_hex && c.is_ascii() {
+                let s = alloc::format!("a{}", char::from(c));
+                assert_eq!(from_hex(&s), Err(PqcError::InvalidEncoding));
+                assert_eq!(secret_from_hex(&s).map(|_| ()), Err(PqcError::InvalidEncoding));
+            }
+        }
+        assert_eq!(secret_from_hex("abc").map(|_| ()), Err(PqcError::InvalidEncoding));
+        assert_eq!(secret_from_hex("0g00").map(|_| ()), Err(PqcError::InvalidEncoding));
+        assert_eq!(secret_from_hex("é0").map(|_| ()), Err(PqcError::InvalidEncoding));
+    }
+}
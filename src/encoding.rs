@@ -0,0 +1,151 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Base64 Encoding for Keys, Ciphertexts and Signatures
+// ------------------------------------------------------------------------
+//! Standard (RFC 4648 §4, padded) and URL-safe (§5, unpadded) Base64.
+//!
+//! Decoding is strict: malformed input or a decoded length other than the
+//! one expected yields `PqcError::InvalidEncoding`. Only public material has
+//! typed helpers; secret keys are not exported through this module.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+
+use crate::error::{PqcError, Result};
+
+#[cfg(feature = "ml-kem")]
+use crate::{KyberCiphertext, KyberPublicKey, ML_KEM_1024_CT_BYTES, ML_KEM_1024_PK_BYTES};
+
+#[cfg(feature = "ml-dsa")]
+use crate::{DilithiumPublicKey, DilithiumSignature, ML_DSA_65_PK_BYTES, ML_DSA_65_SIG_BYTES};
+
+/// Encode with the standard alphabet, padded
+pub fn to_base64(bytes: &[u8]) -> String {
+    STANDARD.encode(bytes)
+}
+
+/// Decode standard, padded Base64 that must be exactly `expected_len` bytes
+pub fn from_base64(s: &str, expected_len: usize) -> Result<Vec<u8>> {
+    check_len(STANDARD.decode(s).map_err(|_| PqcError::InvalidEncoding)?, expected_len)
+}
+
+/// Encode with the URL-safe alphabet, unpadded
+pub fn to_base64url(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Decode URL-safe, unpadded Base64 that must be exactly `expected_len` bytes
+pub fn from_base64url(s: &str, expected_len: usize) -> Result<Vec<u8>> {
+    check_len(URL_SAFE_NO_PAD.decode(s).map_err(|_| PqcError::InvalidEncoding)?, expected_len)
+}
+
+fn check_len(decoded: Vec<u8>, expected_len: usize) -> Result<Vec<u8>> {
+    if decoded.len() == expected_len {
+        Ok(decoded)
+    } else {
+        Err(PqcError::InvalidEncoding)
+    }
+}
+
+/// Standard and URL-safe encode/decode pairs for one wrapper type
+macro_rules! typed_base64 {
+    ($ty:ty, $len:expr, $to:ident, $from:ident, $to_url:ident, $from_url:ident) => {
+        pub fn $to(value: &$ty) -> String {
+            to_base64(value.as_slice())
+        }
+
+        pub fn $from(s: &str) -> Result<$ty> {
+            <$ty>::try_from(from_base64(s, $len)?.as_slice())
+        }
+
+        pub fn $to_url(value: &$ty) -> String {
+            to_base64url(value.as_slice())
+        }
+
+        pub fn $from_url(s: &str) -> Result<$ty> {
+            <$ty>::try_from(from_base64url(s, $len)?.as_slice())
+        }
+    };
+}
+
+#[cfg(feature = "ml-kem")]
+typed_base64!(
+    KyberPublicKey, ML_KEM_1024_PK_BYTES,
+    public_key_to_base64, public_key_from_base64,
+    public_key_to_base64url, public_key_from_base64url
+);
+
+#[cfg(feature = "ml-kem")]
+typed_base64!(
+    KyberCiphertext, ML_KEM_1024_CT_BYTES,
+    ciphertext_to_base64, ciphertext_from_base64,
+    ciphertext_to_base64url, ciphertext_from_base64url
+);
+
+#[cfg(feature = "ml-dsa")]
+typed_base64!(
+    DilithiumPublicKey, ML_DSA_65_PK_BYTES,
+    verification_key_to_base64, verification_key_from_base64,
+    verification_key_to_base64url, verification_key_from_base64url
+);
+
+#[cfg(feature = "ml-dsa")]
+typed_base64!(
+    DilithiumSignature, ML_DSA_65_SIG_BYTES,
+    signature_to_base64, signature_from_base64,
+    signature_to_base64url, signature_from_base64url
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_roundtrip_both_alphabets() {
+        let bytes = [0xfbu8, 0xff, 0xfe, 0x00, 0x01];
+        assert_eq!(to_base64(&bytes), "+//+AAE=");
+        assert_eq!(to_base64url(&bytes), "-__-AAE");
+        assert_eq!(from_base64("+//+AAE=", 5).unwrap(), bytes);
+        assert_eq!(from_base64url("-__-AAE", 5).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_rejects_malformed_and_wrong_length() {
+        assert_eq!(from_base64("+//+AAE=", 4), Err(PqcError::InvalidEncoding));
+        assert_eq!(from_base64("-__-AAE", 5), Err(PqcError::InvalidEncoding));
+        assert_eq!(from_base64url("+//+AAE=", 5), Err(PqcError::InvalidEncoding));
+        assert_eq!(from_base64("not base64!", 8), Err(PqcError::InvalidEncoding));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_kyber_roundtrip() {
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x01; 64]);
+        let (ct, _ss) = crate::encapsulate_shared_secret_with_randomness(&keys.pk, [0x02; 32]);
+
+        assert!(public_key_from_base64(&public_key_to_base64(&keys.pk)).unwrap() == keys.pk);
+        assert!(public_key_from_base64url(&public_key_to_base64url(&keys.pk)).unwrap() == keys.pk);
+        assert!(ciphertext_from_base64(&ciphertext_to_base64(&ct)).unwrap() == ct);
+        assert!(ciphertext_from_base64url(&ciphertext_to_base64url(&ct)).unwrap() == ct);
+
+        assert!(matches!(public_key_from_base64("AAAA"), Err(PqcError::InvalidEncoding)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_dilithium_roundtrip() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x03; 32]);
+        let sig = crate::sign_message_with_randomness(&sk, b"m", [0x04; 32]);
+
+        assert!(verification_key_from_base64(&verification_key_to_base64(&pk)).unwrap() == pk);
+        assert!(verification_key_from_base64url(&verification_key_to_base64url(&pk)).unwrap() == pk);
+        assert!(signature_from_base64(&signature_to_base64(&sig)).unwrap() == sig);
+        assert!(signature_from_base64url(&signature_to_base64url(&sig)).unwrap() == sig);
+
+        let truncated = to_base64(&sig.as_slice()[1..]);
+        assert!(matches!(signature_from_base64(&truncated), Err(PqcError::InvalidEncoding)));
+    }
+}
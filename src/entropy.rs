@@ -0,0 +1,145 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Entropy Source Report
+// ------------------------------------------------------------------------
+//! Runtime summary of where the module's randomness comes from, for audit
+//! evidence.
+//!
+//! [`entropy_report`] names the source behind the implicit-RNG functions
+//! on the calling thread, a conservative min-entropy claim for it, and the
+//! results of the health test applied to every seed: the stuck-output
+//! check in [`crate::rng::seed_32_from`] / [`crate::rng::seed_64_from`],
+//! which rejects an all-zero draw with `RngFailure`. The module runs no
+//! other continuous health tests on OS output; the operating system's
+//! entropy source is responsible for its own.
+
+use crate::rng::stuck_output_counts;
+
+/// Where implicit seeds come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum EntropySource {
+    /// `OsRng` (getrandom), conditioned and health-tested by the OS
+    OsProvided,
+    /// A `testing::TestRng` installed on this thread; fully predictable
+    TestOverride,
+    /// `explicit-rng` build: every seed comes from a caller's `SeedSource`
+    CallerSupplied,
+}
+
+impl EntropySource {
+    /// One-line description for evidence records
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::OsProvided => "OS-provided, assumed full entropy",
+            Self::TestOverride => "deterministic test RNG, no entropy",
+            Self::CallerSupplied => "caller-supplied SeedSource, entropy not assessed by the module",
+        }
+    }
+
+    /// Conservative min-entropy per output byte, in bits
+    ///
+    /// `None` when the module cannot make a claim about the source.
+    pub const fn min_entropy_bits_per_byte(self) -> Option<u8> {
+        match self {
+            Self::OsProvided => Some(8),
+            Self::TestOverride => Some(0),
+            Self::CallerSupplied => None,
+        }
+    }
+}
+
+/// Stuck-output health test counters since process start
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HealthTestCounts {
+    /// Seeds drawn through `seed_*_from`, from any source
+    pub seeds_checked: usize,
+    /// Seeds rejected as all-zero
+    pub stuck_outputs: usize,
+}
+
+/// Snapshot returned by [`entropy_report`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EntropyReport {
+    pub source: EntropySource,
+    pub description: &'static str,
+    /// See [`EntropySource::min_entropy_bits_per_byte`]
+    pub min_entropy_bits_per_byte: Option<u8>,
+    pub health_tests: HealthTestCounts,
+}
+
+impl EntropyReport {
+    /// No stuck output has been seen
+    pub fn healthy(&self) -> bool {
+        self.health_tests.stuck_outputs == 0
+    }
+}
+
+/// Entropy-source posture as seen from the calling thread
+pub fn entropy_report() -> EntropyReport {
+    let source = current_source();
+    let (seeds_checked, stuck_outputs) = stuck_output_counts();
+    EntropyReport {
+        source,
+        description: source.description(),
+        min_entropy_bits_per_byte: source.min_entropy_bits_per_byte(),
+        health_tests: HealthTestCounts { seeds_checked, stuck_outputs },
+    }
+}
+
+#[cfg(not(feature = "explicit-rng"))]
+fn current_source() -> EntropySource {
+    #[cfg(feature = "testing")]
+    if crate::testing::is_installed() {
+        return EntropySource::TestOverride;
+    }
+    EntropySource::OsProvided
+}
+
+#[cfg(feature = "explicit-rng")]
+fn current_source() -> EntropySource {
+    EntropySource::CallerSupplied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{PqcError, Result};
+    use crate::rng::{seed_32_from, SeedSource};
+
+    struct Stuck;
+
+    impl SeedSource for Stuck {
+        fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+            dest.fill(0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "explicit-rng"))]
+    fn test_os_source_reported() {
+        let report = entropy_report();
+        assert_eq!(report.source, EntropySource::OsProvided);
+        assert_eq!(report.description, "OS-provided, assumed full entropy");
+        assert_eq!(report.min_entropy_bits_per_byte, Some(8));
+
+        #[cfg(feature = "testing")]
+        crate::testing::with_test_rng(crate::testing::TestRng::new(3), || {
+            assert_eq!(entropy_report().source, EntropySource::TestOverride);
+            assert_eq!(entropy_report().min_entropy_bits_per_byte, Some(0));
+        });
+    }
+
+    #[test]
+    fn test_stuck_output_counted() {
+        let before = entropy_report().health_tests;
+        assert_eq!(seed_32_from(&mut Stuck), Err(PqcError::RngFailure));
+        let after = entropy_report().health_tests;
+        assert!(after.seeds_checked > before.seeds_checked);
+        assert!(after.stuck_outputs > before.stuck_outputs);
+        assert!(!entropy_report().healthy());
+    }
+}
@@ -0,0 +1,287 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Attached Signature Envelope for ML-DSA-65
+// ------------------------------------------------------------------------
+//! Standard framing for a message travelling with its signature:
+//!
+//! ```text
+//! len(msg): u64 big-endian || msg || signature (3309 bytes)
+//! ```
+//!
+//! [`SignedMessage`] adds a version header and the signer's key
+//! fingerprint, for services that exchange signed payloads:
+//!
+//! ```text
+//! "PQSM" || version (1) || alg_id (0x02, ML-DSA-65) || SHA3-256(pk)
+//!        || len(payload): u64 big-endian || payload || signature
+//! ```
+//!
+//! Its signature uses the FIPS 204 context [`SIGNED_MESSAGE_CONTEXT`], so it
+//! never verifies as a plain signature over the payload, or vice versa.
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use sha3::{Digest, Sha3_256};
+
+use crate::error::{PqcError, Result};
+use crate::rng::SeedSource;
+use crate::sig::SigningContext;
+use crate::{
+    verify_signature_with_context, DilithiumPublicKey, DilithiumSecretKey, DilithiumSignature,
+    ML_DSA_65_SIG_BYTES,
+};
+
+/// Size of the big-endian message length prefix
+pub const ENVELOPE_LEN_PREFIX_BYTES: usize = 8;
+
+/// FIPS 204 context string of [`SignedMessage`] signatures
+pub const SIGNED_MESSAGE_CONTEXT: &[u8] = b"pqc-combo SignedMessage v1";
+
+const SIGNED_MESSAGE_MAGIC: [u8; 4] = *b"PQSM";
+const SIGNED_MESSAGE_VERSION: u8 = 1;
+const SIGNED_MESSAGE_ALG_ML_DSA_65: u8 = 0x02;
+const SIGNED_MESSAGE_HEADER_BYTES: usize = 6 + 32 + ENVELOPE_LEN_PREFIX_BYTES;
+
+/// Attach a signature to its message
+pub fn attach(msg: &[u8], sig: &DilithiumSignature) -> Vec<u8> {
+    let mut out = Vec::with_capacity(ENVELOPE_LEN_PREFIX_BYTES + msg.len() + ML_DSA_65_SIG_BYTES);
+    out.extend_from_slice(&(msg.len() as u64).to_be_bytes());
+    out.extend_from_slice(msg);
+    out.extend_from_slice(sig.as_slice());
+    out
+}
+
+/// Split an attached envelope into message and signature
+///
+/// Rejects envelopes whose length prefix does not account for exactly
+/// `ML_DSA_65_SIG_BYTES` of trailing signature.
+pub fn detach(bytes: &[u8]) -> Result<(Vec<u8>, DilithiumSignature)> {
+    if bytes.len() < ENVELOPE_LEN_PREFIX_BYTES {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let (prefix, rest) = bytes.split_at(ENVELOPE_LEN_PREFIX_BYTES);
+    let msg_len = u64::from_be_bytes(prefix.try_into().map_err(|_| PqcError::InvalidEncoding)?);
+
+    let expected_sig_len = usize::try_from(msg_len)
+        .ok()
+        .and_then(|msg_len| rest.len().checked_sub(msg_len));
+    match expected_sig_len {
+        Some(ML_DSA_65_SIG_BYTES) => {}
+        _ => return Err(PqcError::InvalidEncoding),
+    }
+
+    let (msg, sig) = rest.split_at(rest.len() - ML_DSA_65_SIG_BYTES);
+    let sig: [u8; ML_DSA_65_SIG_BYTES] = sig.try_into().map_err(|_| PqcError::InvalidEncoding)?;
+    Ok((msg.to_vec(), DilithiumSignature::from(sig)))
+}
+
+/// Detach and verify an envelope, returning the payload only if it is authentic
+///
+/// Returns `InvalidEncoding` for malformed framing and `VerificationFailure`
+/// if the signature does not verify under `pk`. The payload is never
+/// returned unverified.
+#[cfg(not(feature = "require-context"))]
+pub fn verify_and_extract(pk: &DilithiumPublicKey, signed_bytes: &[u8]) -> Result<Vec<u8>> {
+    let (msg, sig) = detach(signed_bytes)?;
+    if crate::verify_signature(pk, &msg, &sig) {
+        Ok(msg)
+    } else {
+        Err(PqcError::VerificationFailure)
+    }
+}
+
+/// SHA3-256 of the encoded public key
+pub fn signer_fingerprint(pk: &DilithiumPublicKey) -> [u8; 32] {
+    Sha3_256::digest(pk.as_slice()).into()
+}
+
+/// Payload, signature and the fingerprint of the key that made it
+#[derive(Clone)]
+pub struct SignedMessage {
+    pub payload: Vec<u8>,
+    pub signature: DilithiumSignature,
+    pub signer_fingerprint: [u8; 32],
+}
+
+impl SignedMessage {
+    /// Sign `payload` with OS randomness; `pk` must be `sk`'s public key
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    pub fn create(sk: &DilithiumSecretKey, pk: &DilithiumPublicKey, payload: &[u8]) -> Result<Self> {
+        Self::create_with_rng(sk, pk, payload, &mut rand::rngs::OsRng)
+    }
+
+    /// Sign `payload` with hedging randomness from `rng`
+    pub fn create_with_rng<R: SeedSource + ?Sized>(
+        sk: &DilithiumSecretKey,
+        pk: &DilithiumPublicKey,
+        payload: &[u8],
+        rng: &mut R,
+    ) -> Result<Self> {
+        let signature = SigningContext::new()
+            .with_context(SIGNED_MESSAGE_CONTEXT)?
+            .sign_with_rng(sk, payload, rng)?;
+        Ok(Self {
+            payload: payload.to_vec(),
+            signature,
+            signer_fingerprint: signer_fingerprint(pk),
+        })
+    }
+
+    /// Check the message came from `pk` and return the authentic payload
+    ///
+    /// Returns `VerificationFailure` if the fingerprint names another key or
+    /// the signature does not verify.
+    pub fn verify(&self, pk: &DilithiumPublicKey) -> Result<&[u8]> {
+        let authentic = self.signer_fingerprint == signer_fingerprint(pk)
+            && verify_signature_with_context(pk, &self.payload, SIGNED_MESSAGE_CONTEXT, &self.signature);
+        if authentic {
+            Ok(&self.payload)
+        } else {
+            Err(PqcError::VerificationFailure)
+        }
+    }
+
+    /// Canonical encoding (see the module docs)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(
+            SIGNED_MESSAGE_HEADER_BYTES + self.payload.len() + ML_DSA_65_SIG_BYTES,
+        );
+        out.extend_from_slice(&SIGNED_MESSAGE_MAGIC);
+        out.push(SIGNED_MESSAGE_VERSION);
+        out.push(SIGNED_MESSAGE_ALG_ML_DSA_65);
+        out.extend_from_slice(&self.signer_fingerprint);
+        out.extend_from_slice(&attach(&self.payload, &self.signature));
+        out
+    }
+
+    /// Parse the encoding written by [`SignedMessage::to_bytes`]
+    ///
+    /// Returns `InvalidEncoding` for a bad magic, unknown version or
+    /// algorithm, or framing that does not account for every byte. Parsing
+    /// does not verify; call [`SignedMessage::verify`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (header, rest) = bytes.split_first_chunk::<6>().ok_or(PqcError::InvalidEncoding)?;
+        if header[..4] != SIGNED_MESSAGE_MAGIC
+            || header[4] != SIGNED_MESSAGE_VERSION
+            || header[5] != SIGNED_MESSAGE_ALG_ML_DSA_65
+        {
+            return Err(PqcError::InvalidEncoding);
+        }
+        let (signer_fingerprint, envelope) =
+            rest.split_first_chunk::<32>().ok_or(PqcError::InvalidEncoding)?;
+        let (payload, signature) = detach(envelope)?;
+        Ok(Self { payload, signature, signer_fingerprint: *signer_fingerprint })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_dilithium_keypair_with_seed;
+    #[cfg(not(feature = "require-context"))]
+    use crate::{sign_message_with_randomness, verify_signature};
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_attach_detach_roundtrip() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x11; 32]);
+        let msg = b"firmware manifest";
+        let sig = sign_message_with_randomness(&sk, msg, [0x22; 32]);
+
+        let envelope = attach(msg, &sig);
+        assert_eq!(envelope.len(), ENVELOPE_LEN_PREFIX_BYTES + msg.len() + ML_DSA_65_SIG_BYTES);
+        assert_eq!(&envelope[..ENVELOPE_LEN_PREFIX_BYTES], &(msg.len() as u64).to_be_bytes());
+
+        let (detached_msg, detached_sig) = detach(&envelope).unwrap();
+        assert_eq!(detached_msg, msg);
+        assert!(verify_signature(&pk, &detached_msg, &detached_sig));
+    }
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_verify_and_extract() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x11; 32]);
+        let envelope = attach(b"payload", &sign_message_with_randomness(&sk, b"payload", [0x22; 32]));
+        assert_eq!(verify_and_extract(&pk, &envelope).unwrap(), b"payload");
+
+        let mut tampered = envelope.clone();
+        tampered[ENVELOPE_LEN_PREFIX_BYTES] ^= 1;
+        assert_eq!(verify_and_extract(&pk, &tampered), Err(PqcError::VerificationFailure));
+
+        let (other_pk, _) = generate_dilithium_keypair_with_seed([0x12; 32]);
+        assert_eq!(verify_and_extract(&other_pk, &envelope), Err(PqcError::VerificationFailure));
+        assert_eq!(verify_and_extract(&pk, &envelope[..10]), Err(PqcError::InvalidEncoding));
+    }
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_detach_rejects_malformed_lengths() {
+        let (_pk, sk) = generate_dilithium_keypair_with_seed([0x11; 32]);
+        let envelope = attach(b"payload", &sign_message_with_randomness(&sk, b"payload", [0x22; 32]));
+
+        // Truncated signature tail
+        assert!(matches!(detach(&envelope[..envelope.len() - 1]), Err(PqcError::InvalidEncoding)));
+
+        // Extra trailing byte
+        let mut extended = envelope.clone();
+        extended.push(0);
+        assert!(matches!(detach(&extended), Err(PqcError::InvalidEncoding)));
+
+        // Length prefix larger than the envelope
+        let mut oversized = envelope.clone();
+        oversized[..ENVELOPE_LEN_PREFIX_BYTES].copy_from_slice(&u64::MAX.to_be_bytes());
+        assert!(matches!(detach(&oversized), Err(PqcError::InvalidEncoding)));
+
+        // Shorter than the prefix itself
+        assert!(matches!(detach(&[0u8; 3]), Err(PqcError::InvalidEncoding)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(feature = "require-context")))]
+    fn test_signed_message_roundtrip() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x13; 32]);
+        let mut rng = rand::rngs::OsRng;
+        let signed = SignedMessage::create_with_rng(&sk, &pk, b"order #42", &mut rng).unwrap();
+        assert_eq!(signed.verify(&pk).unwrap(), b"order #42");
+
+        let bytes = signed.to_bytes();
+        assert_eq!(&bytes[..6], b"PQSM\x01\x02");
+        assert_eq!(&bytes[6..38], &signer_fingerprint(&pk));
+        assert_eq!(bytes.len(), SIGNED_MESSAGE_HEADER_BYTES + 9 + ML_DSA_65_SIG_BYTES);
+        let parsed = SignedMessage::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.verify(&pk).unwrap(), b"order #42");
+        assert!(parsed.signature == signed.signature);
+
+        // Context separation from plain signatures over the same payload
+        assert!(!verify_signature(&pk, b"order #42", &signed.signature));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_signed_message_rejects_wrong_signer_and_framing() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x13; 32]);
+        let (other_pk, other_sk) = generate_dilithium_keypair_with_seed([0x14; 32]);
+        let mut rng = rand::rngs::OsRng;
+        let signed = SignedMessage::create_with_rng(&sk, &pk, b"order #42", &mut rng).unwrap();
+        assert_eq!(signed.verify(&other_pk), Err(PqcError::VerificationFailure));
+
+        // Right fingerprint, signature from another key
+        let mut forged = SignedMessage::create_with_rng(&other_sk, &other_pk, b"order #42", &mut rng).unwrap();
+        forged.signer_fingerprint = signer_fingerprint(&pk);
+        assert_eq!(forged.verify(&pk), Err(PqcError::VerificationFailure));
+
+        let mut tampered = signed.clone();
+        tampered.payload[0] ^= 1;
+        assert_eq!(tampered.verify(&pk), Err(PqcError::VerificationFailure));
+
+        let bytes = signed.to_bytes();
+        for (index, value) in [(0, b'X'), (4, 2), (5, 0x01)] {
+            let mut bad = bytes.clone();
+            bad[index] = value;
+            assert!(matches!(SignedMessage::from_bytes(&bad), Err(PqcError::InvalidEncoding)));
+        }
+        assert!(matches!(SignedMessage::from_bytes(&bytes[..bytes.len() - 1]), Err(PqcError::InvalidEncoding)));
+        assert!(matches!(SignedMessage::from_bytes(&bytes[..20]), Err(PqcError::InvalidEncoding)));
+    }
+}
@@ -14,12 +14,26 @@
 //
 // Contact: aaronschnacky@gmail.com
 // ------------------------------------------------------------------------// src/error.rs
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PqcError {
     InvalidKeyLength,
     VerificationFailure,
     DecapsulationFailure,
-    AesGcmOperationFailed,
+    /// AEAD (AES-256-GCM) encryption failed or the tag did not authenticate
+    AeadOperationFailed,
+    /// Caller-provided output buffer cannot hold the result
+    BufferTooSmall,
+    /// ML-KEM public key failed the FIPS 203 §7.2 modulus check
+    InvalidPublicKey,
+    /// ML-KEM ciphertext has the wrong length
+    InvalidCiphertext,
+    /// Caller-supplied seed or randomness is unusable (e.g. all zero)
+    InvalidSeed,
+    /// ML-DSA signing did not produce a signature
+    SigningFailure,
+    /// AES-GCM tag length is not supported (or not approved in FIPS mode)
+    InvalidTagLength,
     /// FIPS 140-3 Pair-wise Consistency Test (PCT) failure
     PairwiseConsistencyTestFailure,
     /// FIPS 140-3 Conditional Algorithm Self-Test (CAST) failure
@@ -30,8 +44,34 @@ pub enum PqcError {
     FipsPostInProgress,
     /// FIPS 140-3 State: Module in error state (POST failed)
     FipsErrorState,
+    /// FIPS 140-3 State: Requested transition is not legal from the current state
+    IllegalStateTransition,
     /// FIPS 140-3 CSP: Plaintext export blocked in FIPS mode
     CspExportBlocked,
+    /// ML-DSA context string exceeds 255 bytes (FIPS 204)
+    ContextTooLong,
+    /// Serialized input (CBOR, JSON, framing) is malformed
+    InvalidEncoding,
+    /// Entropy source failed or returned an all-zero seed
+    RngFailure,
+    /// mlock() refused to pin a secret buffer (e.g. RLIMIT_MEMLOCK)
+    MemoryLockFailed,
+    /// Reading from or writing to a caller-supplied stream failed
+    IoFailure,
+    /// Message exceeds the signing context's `max_message_len`
+    MessageTooLarge,
+    /// Passphrase KDF parameters below the SP 800-132 minimum
+    WeakKdfParameters,
+    /// AES-GCM (key, nonce) pair already used for encryption in this process
+    NonceReused,
+    /// ML-DSA context is empty but the `require-context` policy demands one
+    EmptyContext,
+    /// Algorithm id in a sealed format is not one this build supports
+    UnsupportedAlgorithm,
+    /// ML-DSA signature bytes are not the canonical encoding of what they parse to
+    NonCanonicalSignature,
+    /// Parameter set is not on the runtime whitelist (`set_allowed_parameter_sets`)
+    ParameterSetNotAllowed,
 }
 
 pub type Result<T> = core::result::Result<T, PqcError>;
\ No newline at end of file
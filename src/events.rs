@@ -0,0 +1,178 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Self-Test and State Event Hooks
+// ------------------------------------------------------------------------
+//! Structured events for audit logging (SIEM, syslog, metrics).
+//!
+//! Install one process-wide handler with [`set_event_handler`]; the module
+//! then reports POST progress, every self-test outcome and every FIPS state
+//! change as a [`FipsEvent`]. Nothing is written to stdout. Without the `std`
+//! feature no handler can be installed and events are discarded.
+
+use crate::error::{PqcError, Result};
+use crate::state::FipsState;
+
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+/// Identifies one self-test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SelfTestId {
+    Sha3_256,
+    Sha3_512,
+    Shake128,
+    Shake256,
+    MlKemKat,
+    MlDsaKat,
+    MlKemPct,
+    MlDsaPct,
+}
+
+/// Category of a self-test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SelfTestKind {
+    /// Cryptographic algorithm self-test (hash functions)
+    Cast,
+    /// Known answer test
+    Kat,
+    /// Pair-wise consistency test
+    Pct,
+}
+
+impl SelfTestId {
+    /// Which category this self-test belongs to
+    pub const fn kind(self) -> SelfTestKind {
+        match self {
+            SelfTestId::Sha3_256 | SelfTestId::Sha3_512 | SelfTestId::Shake128 | SelfTestId::Shake256 => {
+                SelfTestKind::Cast
+            }
+            SelfTestId::MlKemKat | SelfTestId::MlDsaKat => SelfTestKind::Kat,
+            SelfTestId::MlKemPct | SelfTestId::MlDsaPct => SelfTestKind::Pct,
+        }
+    }
+}
+
+/// Event reported to the installed handler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FipsEvent {
+    PostStarted,
+    PostPassed,
+    PostFailed(PqcError),
+    CastPassed(SelfTestId),
+    CastFailed(SelfTestId),
+    KatPassed(SelfTestId),
+    KatFailed(SelfTestId),
+    PctPassed(SelfTestId),
+    PctFailed(SelfTestId),
+    StateChanged(FipsState),
+}
+
+/// Callback receiving [`FipsEvent`]s
+#[cfg(feature = "std")]
+pub type EventHandler = Box<dyn Fn(FipsEvent) + Send + Sync>;
+
+#[cfg(feature = "std")]
+static HANDLER: RwLock<Option<EventHandler>> = RwLock::new(None);
+
+/// Install the process-wide event handler, replacing any previous one
+///
+/// The handler runs synchronously on the thread that raised the event, so
+/// keep it short. It must not call [`set_event_handler`] or
+/// [`clear_event_handler`] itself.
+#[cfg(feature = "std")]
+pub fn set_event_handler(handler: EventHandler) {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(handler);
+}
+
+/// Remove the installed event handler
+#[cfg(feature = "std")]
+pub fn clear_event_handler() {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Deliver `event` to the installed handler, if any
+pub(crate) fn emit(event: FipsEvent) {
+    #[cfg(feature = "std")]
+    if let Some(handler) = HANDLER.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        handler(event);
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = event;
+}
+
+/// Report a CAST outcome and pass the result through
+pub(crate) fn cast_result(id: SelfTestId, result: Result<()>) -> Result<()> {
+    self_test_result(id, result)
+}
+
+/// Report any self-test outcome, picking the event from [`SelfTestId::kind`]
+pub(crate) fn self_test_result(id: SelfTestId, result: Result<()>) -> Result<()> {
+    #[cfg(feature = "std")]
+    crate::status::record_test(id, result);
+    emit(match (id.kind(), result.is_ok()) {
+        (SelfTestKind::Cast, true) => FipsEvent::CastPassed(id),
+        (SelfTestKind::Cast, false) => FipsEvent::CastFailed(id),
+        (SelfTestKind::Kat, true) => FipsEvent::KatPassed(id),
+        (SelfTestKind::Kat, false) => FipsEvent::KatFailed(id),
+        (SelfTestKind::Pct, true) => FipsEvent::PctPassed(id),
+        (SelfTestKind::Pct, false) => FipsEvent::PctFailed(id),
+    });
+    result
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::state::{lock_state_for_test, reset_fips_state};
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
+
+    fn capture() -> Arc<Mutex<Vec<FipsEvent>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        set_event_handler(Box::new(move |event| sink.lock().unwrap().push(event)));
+        events
+    }
+
+    #[test]
+    fn test_post_emits_events() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        let events = capture();
+
+        crate::run_post().unwrap();
+        clear_event_handler();
+
+        let events = events.lock().unwrap();
+        for expected in [
+            FipsEvent::PostStarted,
+            FipsEvent::StateChanged(FipsState::POST),
+            FipsEvent::CastPassed(SelfTestId::Sha3_256),
+            FipsEvent::CastPassed(SelfTestId::Sha3_512),
+            FipsEvent::CastPassed(SelfTestId::Shake128),
+            FipsEvent::CastPassed(SelfTestId::Shake256),
+            FipsEvent::StateChanged(FipsState::Operational),
+            FipsEvent::PostPassed,
+        ] {
+            assert!(events.contains(&expected), "missing {:?}", expected);
+        }
+        #[cfg(all(feature = "ml-kem", not(feature = "explicit-rng")))]
+        assert!(events.contains(&FipsEvent::PctPassed(SelfTestId::MlKemPct)));
+        #[cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
+        assert!(events.contains(&FipsEvent::KatPassed(SelfTestId::MlDsaKat)));
+    }
+
+    #[test]
+    fn test_cast_failure_event() {
+        let _guard = lock_state_for_test();
+        let events = capture();
+
+        let result = cast_result(SelfTestId::Shake256, Err(PqcError::CastFailure));
+        clear_event_handler();
+
+        assert_eq!(result, Err(PqcError::CastFailure));
+        assert!(events.lock().unwrap().contains(&FipsEvent::CastFailed(SelfTestId::Shake256)));
+    }
+}
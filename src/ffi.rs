@@ -0,0 +1,586 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// C ABI for the Core Operations
+// ------------------------------------------------------------------------
+//! `extern "C"` entry points for linking the module into C applications.
+//!
+//! Every function takes raw pointer + length pairs, checks each pointer for
+//! null and each length against the exact expected size, and returns an
+//! `int` status: `PQC_OK` (0) or a negative `PQC_ERR_*` code mapped from
+//! [`PqcError`]. Randomness is always supplied by the caller (e.g. from an
+//! approved DRBG); an all-zero seed or randomness buffer is refused with
+//! `PQC_ERR_INVALID_SEED`. Each call first runs
+//! [`ensure_initialized`](crate::ensure_initialized), so POST runs on the
+//! first call and nothing is computed outside the Operational state.
+//! Panics are caught and reported as `PQC_ERR_INTERNAL`. Seeds, randomness
+//! and secret keys copied in from the caller are wiped before returning.
+//!
+//! Build a C library with
+//! `cargo rustc --release --features ffi --crate-type staticlib` (or
+//! `cdylib`); the matching header is `include/pqc_combo.h`, generated from
+//! this file alone, so only what is declared here reaches C.
+
+use core::ffi::c_int;
+use core::slice;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use zeroize::Zeroizing;
+
+use crate::error::PqcError;
+use crate::rng::{check_seed_32, check_seed_64};
+use crate::{
+    decapsulate_shared_secret, encapsulate_shared_secret_with_randomness,
+    generate_dilithium_keypair_with_seed, KyberCiphertext, KyberKeys, KyberPublicKey,
+    KyberSecretKey,
+};
+#[cfg(not(feature = "require-context"))]
+use crate::{
+    sign_message_with_randomness, verify_signature, DilithiumPublicKey, DilithiumSecretKey,
+    DilithiumSignature,
+};
+
+// Buffer sizes for C callers (the same values as the Rust constants)
+pub const PQC_ML_KEM_1024_PK_BYTES: usize = 1568;
+pub const PQC_ML_KEM_1024_SK_BYTES: usize = 3168;
+pub const PQC_ML_KEM_1024_CT_BYTES: usize = 1568;
+pub const PQC_ML_KEM_1024_SS_BYTES: usize = 32;
+pub const PQC_ML_KEM_KEYGEN_SEED_BYTES: usize = 64;
+pub const PQC_ML_KEM_ENCAP_SEED_BYTES: usize = 32;
+pub const PQC_ML_DSA_65_PK_BYTES: usize = 1952;
+pub const PQC_ML_DSA_65_SK_BYTES: usize = 4032;
+pub const PQC_ML_DSA_65_SIG_BYTES: usize = 3309;
+pub const PQC_ML_DSA_KEYGEN_SEED_BYTES: usize = 32;
+pub const PQC_ML_DSA_SIGN_SEED_BYTES: usize = 32;
+
+const _: () = assert!(
+    PQC_ML_KEM_1024_PK_BYTES == crate::ML_KEM_1024_PK_BYTES
+        && PQC_ML_KEM_1024_SK_BYTES == crate::ML_KEM_1024_SK_BYTES
+        && PQC_ML_KEM_1024_CT_BYTES == crate::ML_KEM_1024_CT_BYTES
+        && PQC_ML_KEM_1024_SS_BYTES == crate::ML_KEM_1024_SS_BYTES
+        && PQC_ML_KEM_KEYGEN_SEED_BYTES == crate::ML_KEM_KEYGEN_SEED_BYTES
+        && PQC_ML_KEM_ENCAP_SEED_BYTES == crate::ML_KEM_ENCAP_SEED_BYTES
+        && PQC_ML_DSA_65_PK_BYTES == crate::ML_DSA_65_PK_BYTES
+        && PQC_ML_DSA_65_SK_BYTES == crate::ML_DSA_65_SK_BYTES
+        && PQC_ML_DSA_65_SIG_BYTES == crate::ML_DSA_65_SIG_BYTES
+        && PQC_ML_DSA_KEYGEN_SEED_BYTES == crate::ML_DSA_KEYGEN_SEED_BYTES
+        && PQC_ML_DSA_SIGN_SEED_BYTES == crate::ML_DSA_SIGN_SEED_BYTES
+);
+
+pub const PQC_OK: c_int = 0;
+pub const PQC_ERR_NULL_POINTER: c_int = -1;
+pub const PQC_ERR_INVALID_LENGTH: c_int = -2;
+pub const PQC_ERR_VERIFICATION_FAILURE: c_int = -3;
+pub const PQC_ERR_DECAPSULATION_FAILURE: c_int = -4;
+pub const PQC_ERR_AES_GCM: c_int = -5;
+pub const PQC_ERR_INVALID_PUBLIC_KEY: c_int = -6;
+pub const PQC_ERR_PCT_FAILURE: c_int = -7;
+pub const PQC_ERR_CAST_FAILURE: c_int = -8;
+pub const PQC_ERR_NOT_INITIALIZED: c_int = -9;
+pub const PQC_ERR_POST_IN_PROGRESS: c_int = -10;
+pub const PQC_ERR_ERROR_STATE: c_int = -11;
+pub const PQC_ERR_ILLEGAL_STATE_TRANSITION: c_int = -12;
+pub const PQC_ERR_CSP_EXPORT_BLOCKED: c_int = -13;
+pub const PQC_ERR_CONTEXT_TOO_LONG: c_int = -14;
+pub const PQC_ERR_INVALID_ENCODING: c_int = -15;
+pub const PQC_ERR_MEMORY_LOCK_FAILED: c_int = -16;
+pub const PQC_ERR_RNG_FAILURE: c_int = -17;
+pub const PQC_ERR_IO_FAILURE: c_int = -18;
+pub const PQC_ERR_INVALID_CIPHERTEXT: c_int = -19;
+pub const PQC_ERR_INVALID_SEED: c_int = -20;
+pub const PQC_ERR_SIGNING_FAILURE: c_int = -21;
+pub const PQC_ERR_INVALID_TAG_LENGTH: c_int = -22;
+pub const PQC_ERR_MESSAGE_TOO_LARGE: c_int = -23;
+pub const PQC_ERR_WEAK_KDF_PARAMETERS: c_int = -24;
+pub const PQC_ERR_NONCE_REUSED: c_int = -25;
+pub const PQC_ERR_EMPTY_CONTEXT: c_int = -26;
+pub const PQC_ERR_UNSUPPORTED_ALGORITHM: c_int = -27;
+pub const PQC_ERR_NON_CANONICAL_SIGNATURE: c_int = -28;
+pub const PQC_ERR_PARAMETER_SET_NOT_ALLOWED: c_int = -29;
+pub const PQC_ERR_INTERNAL: c_int = -99;
+
+/// Map a [`PqcError`] to its C status code
+pub fn status_code(err: &PqcError) -> c_int {
+    match err {
+        PqcError::InvalidKeyLength | PqcError::BufferTooSmall => PQC_ERR_INVALID_LENGTH,
+        PqcError::VerificationFailure => PQC_ERR_VERIFICATION_FAILURE,
+        PqcError::DecapsulationFailure => PQC_ERR_DECAPSULATION_FAILURE,
+        PqcError::AeadOperationFailed => PQC_ERR_AES_GCM,
+        PqcError::InvalidPublicKey => PQC_ERR_INVALID_PUBLIC_KEY,
+        PqcError::PairwiseConsistencyTestFailure => PQC_ERR_PCT_FAILURE,
+        PqcError::CastFailure => PQC_ERR_CAST_FAILURE,
+        PqcError::FipsNotInitialized => PQC_ERR_NOT_INITIALIZED,
+        PqcError::FipsPostInProgress => PQC_ERR_POST_IN_PROGRESS,
+        PqcError::FipsErrorState => PQC_ERR_ERROR_STATE,
+        PqcError::IllegalStateTransition => PQC_ERR_ILLEGAL_STATE_TRANSITION,
+        PqcError::CspExportBlocked => PQC_ERR_CSP_EXPORT_BLOCKED,
+        PqcError::ContextTooLong => PQC_ERR_CONTEXT_TOO_LONG,
+        PqcError::InvalidEncoding => PQC_ERR_INVALID_ENCODING,
+        PqcError::MemoryLockFailed => PQC_ERR_MEMORY_LOCK_FAILED,
+        PqcError::RngFailure => PQC_ERR_RNG_FAILURE,
+        PqcError::IoFailure => PQC_ERR_IO_FAILURE,
+        PqcError::InvalidCiphertext => PQC_ERR_INVALID_CIPHERTEXT,
+        PqcError::InvalidSeed => PQC_ERR_INVALID_SEED,
+        PqcError::SigningFailure => PQC_ERR_SIGNING_FAILURE,
+        PqcError::InvalidTagLength => PQC_ERR_INVALID_TAG_LENGTH,
+        PqcError::MessageTooLarge => PQC_ERR_MESSAGE_TOO_LARGE,
+        PqcError::WeakKdfParameters => PQC_ERR_WEAK_KDF_PARAMETERS,
+        PqcError::NonceReused => PQC_ERR_NONCE_REUSED,
+        PqcError::EmptyContext => PQC_ERR_EMPTY_CONTEXT,
+        PqcError::UnsupportedAlgorithm => PQC_ERR_UNSUPPORTED_ALGORITHM,
+        PqcError::NonCanonicalSignature => PQC_ERR_NON_CANONICAL_SIGNATURE,
+        PqcError::ParameterSetNotAllowed => PQC_ERR_PARAMETER_SET_NOT_ALLOWED,
+    }
+}
+
+/// Generate an ML-KEM-1024 key pair from a 64-byte seed
+///
+/// # Safety
+/// Each non-null pointer must be valid for its stated length; output
+/// buffers must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn pqc_kyber_keygen(
+    seed: *const u8,
+    seed_len: usize,
+    pk_out: *mut u8,
+    pk_len: usize,
+    sk_out: *mut u8,
+    sk_len: usize,
+) -> c_int {
+    guarded(|| {
+        let seed = secret_input::<PQC_ML_KEM_KEYGEN_SEED_BYTES>(seed, seed_len)?;
+        let pk_out = output(pk_out, pk_len, PQC_ML_KEM_1024_PK_BYTES)?;
+        let sk_out = output(sk_out, sk_len, PQC_ML_KEM_1024_SK_BYTES)?;
+        check_seed_64(&seed)?;
+
+        let keys = KyberKeys::generate_key_pair_with_seed(*seed);
+        pk_out.copy_from_slice(keys.pk.as_slice());
+        sk_out.copy_from_slice(keys.sk.as_slice());
+        Ok(())
+    })
+}
+
+/// Encapsulate to an ML-KEM-1024 public key with 32 bytes of randomness
+///
+/// The public key is validated first (`PQC_ERR_INVALID_PUBLIC_KEY`).
+///
+/// # Safety
+/// Each non-null pointer must be valid for its stated length; output
+/// buffers must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn pqc_kyber_encaps(
+    pk: *const u8,
+    pk_len: usize,
+    randomness: *const u8,
+    randomness_len: usize,
+    ct_out: *mut u8,
+    ct_len: usize,
+    ss_out: *mut u8,
+    ss_len: usize,
+) -> c_int {
+    guarded(|| {
+        let pk = KyberPublicKey::from(input::<PQC_ML_KEM_1024_PK_BYTES>(pk, pk_len)?);
+        let randomness = secret_input::<PQC_ML_KEM_ENCAP_SEED_BYTES>(randomness, randomness_len)?;
+        let ct_out = output(ct_out, ct_len, PQC_ML_KEM_1024_CT_BYTES)?;
+        let ss_out = output(ss_out, ss_len, PQC_ML_KEM_1024_SS_BYTES)?;
+
+        if !crate::validate_public_key(&pk) {
+            return Err(PqcError::InvalidPublicKey.into());
+        }
+        check_seed_32(&randomness)?;
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&pk, *randomness);
+        ct_out.copy_from_slice(ct.as_slice());
+        ss_out.copy_from_slice(&ss);
+        Ok(())
+    })
+}
+
+/// Decapsulate an ML-KEM-1024 ciphertext
+///
+/// # Safety
+/// Each non-null pointer must be valid for its stated length; output
+/// buffers must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn pqc_kyber_decaps(
+    sk: *const u8,
+    sk_len: usize,
+    ct: *const u8,
+    ct_len: usize,
+    ss_out: *mut u8,
+    ss_len: usize,
+) -> c_int {
+    guarded(|| {
+        let sk = KyberSecretKey::try_from(input_slice(sk, sk_len)?)?;
+        let ct = KyberCiphertext::from(input::<PQC_ML_KEM_1024_CT_BYTES>(ct, ct_len)?);
+        let ss_out = output(ss_out, ss_len, PQC_ML_KEM_1024_SS_BYTES)?;
+
+        ss_out.copy_from_slice(&decapsulate_shared_secret(&sk, &ct));
+        Ok(())
+    })
+}
+
+/// Generate an ML-DSA-65 key pair from a 32-byte seed
+///
+/// # Safety
+/// Each non-null pointer must be valid for its stated length; output
+/// buffers must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn pqc_dsa_keygen(
+    seed: *const u8,
+    seed_len: usize,
+    pk_out: *mut u8,
+    pk_len: usize,
+    sk_out: *mut u8,
+    sk_len: usize,
+) -> c_int {
+    guarded(|| {
+        let seed = secret_input::<PQC_ML_DSA_KEYGEN_SEED_BYTES>(seed, seed_len)?;
+        let pk_out = output(pk_out, pk_len, PQC_ML_DSA_65_PK_BYTES)?;
+        let sk_out = output(sk_out, sk_len, PQC_ML_DSA_65_SK_BYTES)?;
+        check_seed_32(&seed)?;
+
+        let (pk, sk) = generate_dilithium_keypair_with_seed(*seed);
+        pk_out.copy_from_slice(pk.as_slice());
+        sk_out.copy_from_slice(sk.as_slice());
+        Ok(())
+    })
+}
+
+/// Sign a message with ML-DSA-65 using 32 bytes of randomness
+///
+/// `msg` may be null only when `msg_len` is 0.
+///
+/// # Safety
+/// Each non-null pointer must be valid for its stated length; output
+/// buffers must not overlap the inputs.
+#[cfg(not(feature = "require-context"))]
+#[no_mangle]
+pub unsafe extern "C" fn pqc_dsa_sign(
+    sk: *const u8,
+    sk_len: usize,
+    msg: *const u8,
+    msg_len: usize,
+    randomness: *const u8,
+    randomness_len: usize,
+    sig_out: *mut u8,
+    sig_len: usize,
+) -> c_int {
+    guarded(|| {
+        let sk = DilithiumSecretKey::try_from(input_slice(sk, sk_len)?)?;
+        let msg = message(msg, msg_len)?;
+        let randomness = secret_input::<PQC_ML_DSA_SIGN_SEED_BYTES>(randomness, randomness_len)?;
+        let sig_out = output(sig_out, sig_len, PQC_ML_DSA_65_SIG_BYTES)?;
+        check_seed_32(&randomness)?;
+
+        sig_out.copy_from_slice(sign_message_with_randomness(&sk, msg, *randomness).as_slice());
+        Ok(())
+    })
+}
+
+/// Verify an ML-DSA-65 signature
+///
+/// Returns `PQC_OK` for a valid signature and `PQC_ERR_VERIFICATION_FAILURE`
+/// otherwise. `msg` may be null only when `msg_len` is 0.
+///
+/// # Safety
+/// Each non-null pointer must be valid for its stated length.
+#[cfg(not(feature = "require-context"))]
+#[no_mangle]
+pub unsafe extern "C" fn pqc_dsa_verify(
+    pk: *const u8,
+    pk_len: usize,
+    msg: *const u8,
+    msg_len: usize,
+    sig: *const u8,
+    sig_len: usize,
+) -> c_int {
+    guarded(|| {
+        let pk = DilithiumPublicKey::from(input::<PQC_ML_DSA_65_PK_BYTES>(pk, pk_len)?);
+        let msg = message(msg, msg_len)?;
+        let sig = DilithiumSignature::from(input::<PQC_ML_DSA_65_SIG_BYTES>(sig, sig_len)?);
+
+        if verify_signature(&pk, msg, &sig) {
+            Ok(())
+        } else {
+            Err(PqcError::VerificationFailure.into())
+        }
+    })
+}
+
+/// Failure inside an FFI call: a null pointer or a library error
+enum FfiError {
+    NullPointer,
+    Pqc(PqcError),
+}
+
+impl From<PqcError> for FfiError {
+    fn from(err: PqcError) -> Self {
+        FfiError::Pqc(err)
+    }
+}
+
+type FfiResult<T> = core::result::Result<T, FfiError>;
+
+/// Run `f` once the module is Operational, translating its result (or a
+/// panic) into a status code
+fn guarded<F: FnOnce() -> FfiResult<()>>(f: F) -> c_int {
+    let call = || {
+        crate::ensure_initialized()?;
+        f()
+    };
+    match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(())) => PQC_OK,
+        Ok(Err(FfiError::NullPointer)) => PQC_ERR_NULL_POINTER,
+        Ok(Err(FfiError::Pqc(e))) => status_code(&e),
+        Err(_) => PQC_ERR_INTERNAL,
+    }
+}
+
+/// Borrow a non-null input buffer
+unsafe fn input_slice<'a>(ptr: *const u8, len: usize) -> FfiResult<&'a [u8]> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    Ok(slice::from_raw_parts(ptr, len))
+}
+
+/// Copy a non-null input buffer of exactly `N` bytes
+unsafe fn input<const N: usize>(ptr: *const u8, len: usize) -> FfiResult<[u8; N]> {
+    input_slice(ptr, len)?
+        .try_into()
+        .map_err(|_| PqcError::InvalidKeyLength.into())
+}
+
+/// [`input`] for seeds and randomness: the copy is wiped on drop
+unsafe fn secret_input<const N: usize>(ptr: *const u8, len: usize) -> FfiResult<Zeroizing<[u8; N]>> {
+    let bytes = input_slice(ptr, len)?;
+    if bytes.len() != N {
+        return Err(PqcError::InvalidKeyLength.into());
+    }
+    let mut out = Zeroizing::new([0u8; N]);
+    out.copy_from_slice(bytes);
+    Ok(out)
+}
+
+/// Borrow a message, allowing null for the empty message
+#[cfg(not(feature = "require-context"))]
+unsafe fn message<'a>(ptr: *const u8, len: usize) -> FfiResult<&'a [u8]> {
+    if ptr.is_null() && len == 0 {
+        return Ok(&[]);
+    }
+    input_slice(ptr, len)
+}
+
+/// Borrow a non-null output buffer of exactly `expected` bytes
+unsafe fn output<'a>(ptr: *mut u8, len: usize, expected: usize) -> FfiResult<&'a mut [u8]> {
+    if ptr.is_null() {
+        return Err(FfiError::NullPointer);
+    }
+    if len != expected {
+        return Err(PqcError::InvalidKeyLength.into());
+    }
+    Ok(slice::from_raw_parts_mut(ptr, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{enter_error_state, lock_state_for_test, reset_fips_state};
+    use core::ptr;
+
+    /// Hold the state lock with the module Operational
+    fn operational() -> std::sync::MutexGuard<'static, ()> {
+        let guard = lock_state_for_test();
+        reset_fips_state();
+        // Settle the one-time POST so later calls only check the state
+        let _ = crate::ensure_initialized();
+        crate::run_post().unwrap();
+        guard
+    }
+
+    #[test]
+    fn test_ffi_kem_roundtrip() {
+        let _state = operational();
+        let mut pk = [0u8; PQC_ML_KEM_1024_PK_BYTES];
+        let mut sk = [0u8; PQC_ML_KEM_1024_SK_BYTES];
+        let mut ct = [0u8; PQC_ML_KEM_1024_CT_BYTES];
+        let mut ss_a = [0u8; PQC_ML_KEM_1024_SS_BYTES];
+        let mut ss_b = [0u8; PQC_ML_KEM_1024_SS_BYTES];
+        let seed = [0x21u8; PQC_ML_KEM_KEYGEN_SEED_BYTES];
+        let randomness = [0x22u8; PQC_ML_KEM_ENCAP_SEED_BYTES];
+
+        unsafe {
+            assert_eq!(
+                pqc_kyber_keygen(seed.as_ptr(), seed.len(), pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_OK
+            );
+            assert_eq!(
+                pqc_kyber_encaps(
+                    pk.as_ptr(), pk.len(),
+                    randomness.as_ptr(), randomness.len(),
+                    ct.as_mut_ptr(), ct.len(),
+                    ss_a.as_mut_ptr(), ss_a.len(),
+                ),
+                PQC_OK
+            );
+            assert_eq!(
+                pqc_kyber_decaps(sk.as_ptr(), sk.len(), ct.as_ptr(), ct.len(), ss_b.as_mut_ptr(), ss_b.len()),
+                PQC_OK
+            );
+        }
+        assert_eq!(ss_a, ss_b);
+
+        let keys = KyberKeys::generate_key_pair_with_seed(seed);
+        assert_eq!(&pk[..], keys.pk.as_slice());
+    }
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_ffi_dsa_roundtrip() {
+        let _state = operational();
+        let mut pk = [0u8; PQC_ML_DSA_65_PK_BYTES];
+        let mut sk = [0u8; PQC_ML_DSA_65_SK_BYTES];
+        let mut sig = [0u8; PQC_ML_DSA_65_SIG_BYTES];
+        let seed = [0x31u8; PQC_ML_DSA_KEYGEN_SEED_BYTES];
+        let randomness = [0x32u8; PQC_ML_DSA_SIGN_SEED_BYTES];
+        let msg = b"ffi message";
+
+        unsafe {
+            assert_eq!(
+                pqc_dsa_keygen(seed.as_ptr(), seed.len(), pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_OK
+            );
+            assert_eq!(
+                pqc_dsa_sign(
+                    sk.as_ptr(), sk.len(),
+                    msg.as_ptr(), msg.len(),
+                    randomness.as_ptr(), randomness.len(),
+                    sig.as_mut_ptr(), sig.len(),
+                ),
+                PQC_OK
+            );
+            assert_eq!(
+                pqc_dsa_verify(pk.as_ptr(), pk.len(), msg.as_ptr(), msg.len(), sig.as_ptr(), sig.len()),
+                PQC_OK
+            );
+
+            sig[0] ^= 0x01;
+            assert_eq!(
+                pqc_dsa_verify(pk.as_ptr(), pk.len(), msg.as_ptr(), msg.len(), sig.as_ptr(), sig.len()),
+                PQC_ERR_VERIFICATION_FAILURE
+            );
+
+            // Empty message may be passed as NULL
+            assert_eq!(
+                pqc_dsa_sign(
+                    sk.as_ptr(), sk.len(),
+                    ptr::null(), 0,
+                    randomness.as_ptr(), randomness.len(),
+                    sig.as_mut_ptr(), sig.len(),
+                ),
+                PQC_OK
+            );
+            assert_eq!(
+                pqc_dsa_verify(pk.as_ptr(), pk.len(), ptr::null(), 0, sig.as_ptr(), sig.len()),
+                PQC_OK
+            );
+        }
+    }
+
+    #[test]
+    fn test_ffi_rejects_null_and_wrong_lengths() {
+        let _state = operational();
+        let mut pk = [0u8; PQC_ML_KEM_1024_PK_BYTES];
+        let mut sk = [0u8; PQC_ML_KEM_1024_SK_BYTES];
+        let seed = [0x41u8; PQC_ML_KEM_KEYGEN_SEED_BYTES];
+
+        unsafe {
+            assert_eq!(
+                pqc_kyber_keygen(ptr::null(), seed.len(), pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_ERR_NULL_POINTER
+            );
+            assert_eq!(
+                pqc_kyber_keygen(seed.as_ptr(), seed.len(), ptr::null_mut(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_ERR_NULL_POINTER
+            );
+            assert_eq!(
+                pqc_kyber_keygen(seed.as_ptr(), seed.len() - 1, pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_ERR_INVALID_LENGTH
+            );
+            assert_eq!(
+                pqc_kyber_keygen(seed.as_ptr(), seed.len(), pk.as_mut_ptr(), pk.len() - 1, sk.as_mut_ptr(), sk.len()),
+                PQC_ERR_INVALID_LENGTH
+            );
+            #[cfg(not(feature = "require-context"))]
+            assert_eq!(
+                pqc_dsa_verify(ptr::null(), 0, ptr::null(), 1, ptr::null(), 0),
+                PQC_ERR_NULL_POINTER
+            );
+
+            // All-zero seeds are refused up front, not by a caught panic
+            let zero_seed = [0u8; PQC_ML_KEM_KEYGEN_SEED_BYTES];
+            assert_eq!(
+                pqc_kyber_keygen(zero_seed.as_ptr(), zero_seed.len(), pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_ERR_INVALID_SEED
+            );
+            let zero_32 = [0u8; 32];
+            let mut dsa_pk = [0u8; PQC_ML_DSA_65_PK_BYTES];
+            let mut dsa_sk = [0u8; PQC_ML_DSA_65_SK_BYTES];
+            assert_eq!(
+                pqc_dsa_keygen(zero_32.as_ptr(), zero_32.len(), dsa_pk.as_mut_ptr(), dsa_pk.len(), dsa_sk.as_mut_ptr(), dsa_sk.len()),
+                PQC_ERR_INVALID_SEED
+            );
+
+            // Malformed public key is rejected before encapsulation
+            let bad_pk = [0xFFu8; PQC_ML_KEM_1024_PK_BYTES];
+            let randomness = [0x42u8; PQC_ML_KEM_ENCAP_SEED_BYTES];
+            let mut ct = [0u8; PQC_ML_KEM_1024_CT_BYTES];
+            let mut ss = [0u8; PQC_ML_KEM_1024_SS_BYTES];
+            assert_eq!(
+                pqc_kyber_encaps(
+                    bad_pk.as_ptr(), bad_pk.len(),
+                    randomness.as_ptr(), randomness.len(),
+                    ct.as_mut_ptr(), ct.len(),
+                    ss.as_mut_ptr(), ss.len(),
+                ),
+                PQC_ERR_INVALID_PUBLIC_KEY
+            );
+        }
+    }
+
+    #[test]
+    fn test_ffi_refuses_zero_randomness_and_error_state() {
+        let _state = operational();
+        let seed = [0x51u8; PQC_ML_KEM_KEYGEN_SEED_BYTES];
+        let mut pk = [0u8; PQC_ML_KEM_1024_PK_BYTES];
+        let mut sk = [0u8; PQC_ML_KEM_1024_SK_BYTES];
+        let mut ct = [0u8; PQC_ML_KEM_1024_CT_BYTES];
+        let mut ss = [0u8; PQC_ML_KEM_1024_SS_BYTES];
+        let zero = [0u8; PQC_ML_KEM_ENCAP_SEED_BYTES];
+
+        unsafe {
+            assert_eq!(
+                pqc_kyber_keygen(seed.as_ptr(), seed.len(), pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_OK
+            );
+            assert_eq!(
+                pqc_kyber_encaps(
+                    pk.as_ptr(), pk.len(),
+                    zero.as_ptr(), zero.len(),
+                    ct.as_mut_ptr(), ct.len(),
+                    ss.as_mut_ptr(), ss.len(),
+                ),
+                PQC_ERR_INVALID_SEED
+            );
+
+            // Nothing is computed outside the Operational state
+            enter_error_state();
+            sk.fill(0);
+            assert_eq!(
+                pqc_kyber_keygen(seed.as_ptr(), seed.len(), pk.as_mut_ptr(), pk.len(), sk.as_mut_ptr(), sk.len()),
+                PQC_ERR_ERROR_STATE
+            );
+            assert!(sk.iter().all(|&b| b == 0));
+        }
+        reset_fips_state();
+    }
+}
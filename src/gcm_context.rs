@@ -0,0 +1,230 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Keyed AES-256-GCM Context with Nonce-Reuse Detection
+// ------------------------------------------------------------------------
+//! [`AesGcmContext`] holds one AES-256-GCM key and takes a nonce per call,
+//! like the free `*_aes_gcm*` functions.
+//!
+//! Repeating a (key, nonce) pair under GCM reveals the XOR of the two
+//! plaintexts and lets an attacker forge tags, and the caller-supplied
+//! nonce makes that easy to get wrong. A context built with
+//! [`AesGcmContext::with_nonce_reuse_detection`] records every (key, nonce)
+//! pair it encrypts under in a process-wide [`NonceReuseDetector`] and
+//! refuses a repeat with `NonceReused`. Detection is a development aid: it
+//! only runs in debug builds with `std`, and the set of seen pairs grows
+//! for the life of the process. Elsewhere the detector is still exported,
+//! so callers need no `cfg` of their own, but records nothing.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+use crate::error::{PqcError, Result};
+use crate::{
+    decrypt_aes_gcm_into_with_aad, encrypt_aes_gcm_into_with_aad, AES_KEY_BYTES, AES_NONCE_BYTES,
+    AES_TAG_BYTES,
+};
+
+/// Process-wide record of (key, nonce) pairs used for encryption
+///
+/// Only fingerprints are stored, never keys. A no-op unless
+/// [`NonceReuseDetector::ACTIVE`].
+pub struct NonceReuseDetector;
+
+impl NonceReuseDetector {
+    /// Whether pairs are recorded: debug builds with `std`
+    pub const ACTIVE: bool = cfg!(all(debug_assertions, feature = "std"));
+
+    /// Record the pair; `NonceReused` if it was recorded before
+    ///
+    /// Always `Ok(())` when the detector is not active.
+    pub fn record(key: &[u8; AES_KEY_BYTES], nonce: &[u8; AES_NONCE_BYTES]) -> Result<()> {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        {
+            detector::record(key, nonce)
+        }
+        #[cfg(not(all(debug_assertions, feature = "std")))]
+        {
+            let _ = (key, nonce);
+            Ok(())
+        }
+    }
+}
+
+/// AES-256-GCM bound to one key
+pub struct AesGcmContext {
+    key: Zeroizing<[u8; AES_KEY_BYTES]>,
+    detect_nonce_reuse: bool,
+}
+
+impl AesGcmContext {
+    /// Context without nonce-reuse detection
+    pub fn new(key: &[u8; AES_KEY_BYTES]) -> Self {
+        Self { key: Zeroizing::new(*key), detect_nonce_reuse: false }
+    }
+
+    /// Context whose encryptions go through the [`NonceReuseDetector`]
+    ///
+    /// When the detector is not active this is the same as
+    /// [`AesGcmContext::new`].
+    pub fn with_nonce_reuse_detection(key: &[u8; AES_KEY_BYTES]) -> Self {
+        Self { key: Zeroizing::new(*key), detect_nonce_reuse: true }
+    }
+
+    /// Encrypt into `out` as [`crate::encrypt_aes_gcm_into_with_aad`] does
+    ///
+    /// Returns `NonceReused`, before anything is written, if detection is on
+    /// and this (key, nonce) pair was already used for encryption. The
+    /// lengths are checked first, so a `MessageTooLarge` or `BufferTooSmall`
+    /// call does not use up the nonce.
+    pub fn encrypt_into(
+        &self,
+        nonce: &[u8; AES_NONCE_BYTES],
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        crate::check_gcm_len(plaintext.len())?;
+        if out.len() < plaintext.len().saturating_add(AES_TAG_BYTES) {
+            return Err(PqcError::BufferTooSmall);
+        }
+        if self.detect_nonce_reuse {
+            NonceReuseDetector::record(&self.key, nonce)?;
+        }
+        encrypt_aes_gcm_into_with_aad(&self.key, nonce, aad, plaintext, out)
+    }
+
+    /// Decrypt into `out` as [`crate::decrypt_aes_gcm_into_with_aad`] does
+    ///
+    /// Decryption never touches the detector; opening the same message
+    /// twice is not a reuse.
+    pub fn decrypt_into(
+        &self,
+        nonce: &[u8; AES_NONCE_BYTES],
+        aad: &[u8],
+        ciphertext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        decrypt_aes_gcm_into_with_aad(&self.key, nonce, aad, ciphertext, out)
+    }
+
+    /// [`AesGcmContext::encrypt_into`] returning `ciphertext || tag`
+    #[cfg(feature = "alloc")]
+    pub fn encrypt(&self, nonce: &[u8; AES_NONCE_BYTES], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut out = alloc::vec![0u8; plaintext.len() + AES_TAG_BYTES];
+        self.encrypt_into(nonce, aad, plaintext, &mut out)?;
+        Ok(out)
+    }
+
+    /// [`AesGcmContext::decrypt_into`] returning the plaintext
+    #[cfg(feature = "alloc")]
+    pub fn decrypt(&self, nonce: &[u8; AES_NONCE_BYTES], aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut out = alloc::vec![0u8; ciphertext.len().saturating_sub(AES_TAG_BYTES)];
+        let len = self.decrypt_into(nonce, aad, ciphertext, &mut out)?;
+        out.truncate(len);
+        Ok(out)
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "std"))]
+mod detector {
+    use std::collections::BTreeSet;
+    use std::sync::Mutex;
+
+    use sha3::{Digest, Sha3_256};
+
+    use crate::error::{PqcError, Result};
+    use crate::{AES_KEY_BYTES, AES_NONCE_BYTES};
+
+    /// Domain separator, so the fingerprint is not a bare hash of the key
+    const FINGERPRINT_LABEL: &[u8] = b"pqc-combo gcm nonce-reuse v1";
+
+    /// SHA3-256 fingerprints of every (key, nonce) pair encrypted under
+    static SEEN: Mutex<BTreeSet<[u8; 32]>> = Mutex::new(BTreeSet::new());
+
+    pub(super) fn record(key: &[u8; AES_KEY_BYTES], nonce: &[u8; AES_NONCE_BYTES]) -> Result<()> {
+        let fingerprint: [u8; 32] = Sha3_256::new()
+            .chain_update(FINGERPRINT_LABEL)
+            .chain_update(key)
+            .chain_update(nonce)
+            .finalize()
+            .into();
+        if SEEN.lock().unwrap_or_else(|e| e.into_inner()).insert(fingerprint) {
+            Ok(())
+        } else {
+            Err(PqcError::NonceReused)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn test_nonce_reuse_detected() {
+        let key = [0x81; AES_KEY_BYTES];
+        let ctx = AesGcmContext::with_nonce_reuse_detection(&key);
+        let mut out = [0u8; 4 + AES_TAG_BYTES];
+        ctx.encrypt_into(&[1; 12], b"", b"ping", &mut out).unwrap();
+        assert!(ctx.encrypt_into(&[2; 12], b"", b"ping", &mut out).is_ok());
+
+        // Same pair again, from this or any other detecting context
+        let mut untouched = [0u8; 4 + AES_TAG_BYTES];
+        assert_eq!(ctx.encrypt_into(&[1; 12], b"aad", b"pong", &mut untouched), Err(PqcError::NonceReused));
+        assert_eq!(untouched, [0u8; 4 + AES_TAG_BYTES]);
+        let other = AesGcmContext::with_nonce_reuse_detection(&key);
+        assert_eq!(other.encrypt_into(&[2; 12], b"", b"ping", &mut untouched), Err(PqcError::NonceReused));
+
+        // Same nonce under another key is fine
+        let rekeyed = AesGcmContext::with_nonce_reuse_detection(&[0x82; AES_KEY_BYTES]);
+        assert!(rekeyed.encrypt_into(&[1; 12], b"", b"ping", &mut untouched).is_ok());
+
+        // Decryption is never a reuse, and opt-out contexts are not checked
+        let mut opened = [0u8; 4];
+        let len = ctx.encrypt_into(&[3; 12], b"", b"once", &mut out).unwrap();
+        assert_eq!(ctx.decrypt_into(&[3; 12], b"", &out[..len], &mut opened), Ok(4));
+        assert_eq!(ctx.decrypt_into(&[3; 12], b"", &out[..len], &mut opened), Ok(4));
+        assert_eq!(&opened, b"once");
+        let plain = AesGcmContext::new(&key);
+        assert!(plain.encrypt_into(&[1; 12], b"", b"ping", &mut out).is_ok());
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn test_failed_encrypt_keeps_nonce() {
+        let ctx = AesGcmContext::with_nonce_reuse_detection(&[0x85; AES_KEY_BYTES]);
+        let mut short = [0u8; 4];
+        assert_eq!(ctx.encrypt_into(&[4; 12], b"", b"ping", &mut short), Err(PqcError::BufferTooSmall));
+
+        // The nonce was not recorded, so the retry with a proper buffer works
+        let mut out = [0u8; 4 + AES_TAG_BYTES];
+        assert_eq!(ctx.encrypt_into(&[4; 12], b"", b"ping", &mut out), Ok(4 + AES_TAG_BYTES));
+        assert_eq!(ctx.encrypt_into(&[4; 12], b"", b"ping", &mut out), Err(PqcError::NonceReused));
+    }
+
+    #[test]
+    fn test_detector_always_exported() {
+        let ctx = AesGcmContext::with_nonce_reuse_detection(&[0x86; AES_KEY_BYTES]);
+        let mut out = [0u8; 4 + AES_TAG_BYTES];
+        assert!(ctx.encrypt_into(&[5; 12], b"", b"ping", &mut out).is_ok());
+        let again = NonceReuseDetector::record(&[0x86; AES_KEY_BYTES], &[5; 12]);
+        if NonceReuseDetector::ACTIVE {
+            assert_eq!(again, Err(PqcError::NonceReused));
+        } else {
+            assert_eq!(again, Ok(()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_context_matches_free_functions() {
+        let (key, nonce) = ([0x83; AES_KEY_BYTES], [0x84; AES_NONCE_BYTES]);
+        let ctx = AesGcmContext::new(&key);
+        let sealed = ctx.encrypt(&nonce, b"", b"context").unwrap();
+        assert_eq!(sealed, crate::encrypt_aes_gcm(&key, &nonce, b"context").unwrap());
+        assert_eq!(ctx.decrypt(&nonce, b"", &sealed).unwrap(), b"context");
+        assert!(ctx.decrypt(&nonce, b"aad", &sealed).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Hybrid KEM Secret Combiners
+// ------------------------------------------------------------------------
+//! Combines an ML-KEM-1024 shared secret with a classical (ECDH) shared
+//! secret computed elsewhere, for hybrid key exchange.
+//!
+//! ```text
+//! Tls13:    out = classical_ss || ml_kem_ss
+//! KdfBound: out = HKDF-SHA3-256(salt = "", ikm = ml_kem_ss || classical_ss, info = label)[..32]
+//! ```
+//!
+//! [`HybridCombiner::Tls13`] follows the TLS 1.3 hybrid design
+//! (draft-ietf-tls-hybrid-design, draft-ietf-tls-ecdhe-mlkem): the
+//! concatenated secret is the `(EC)DHE` input to the TLS key schedule, which
+//! does its own extraction. The ECDHE secret comes first, as specified for
+//! `SecP384r1MLKEM1024`, the ML-KEM-1024 group. [`HybridCombiner::KdfBound`]
+//! is for protocols without their own key schedule.
+
+use alloc::vec::Vec;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::kdf::hmac_sha3_256;
+use crate::KyberSharedSecret;
+
+/// Output length of [`HybridCombiner::KdfBound`]
+pub const HYBRID_KDF_BYTES: usize = 32;
+
+/// How the two shared secrets become one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HybridCombiner<'a> {
+    /// `classical_ss || ml_kem_ss`, as TLS 1.3 feeds to HKDF-Extract
+    Tls13,
+    /// HKDF-SHA3-256 over both secrets, bound to a protocol `label`
+    KdfBound { label: &'a [u8] },
+}
+
+impl HybridCombiner<'_> {
+    /// Combine `ml_kem_ss` with the classical shared secret
+    pub fn combine(&self, ml_kem_ss: &KyberSharedSecret, classical_ss: &[u8]) -> Zeroizing<Vec<u8>> {
+        match *self {
+            HybridCombiner::Tls13 => {
+                let mut out = Zeroizing::new(Vec::with_capacity(classical_ss.len() + ml_kem_ss.len()));
+                out.extend_from_slice(classical_ss);
+                out.extend_from_slice(ml_kem_ss);
+                out
+            }
+            HybridCombiner::KdfBound { label } => {
+                let mut prk = hmac_sha3_256(&[], &[ml_kem_ss, classical_ss]);
+                let okm = hmac_sha3_256(&prk, &[label, &[1]]);
+                prk.zeroize();
+                Zeroizing::new(okm.to_vec())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decapsulate_shared_secret, encapsulate_shared_secret_with_randomness, KyberKeys};
+
+    const CLASSICAL_SS: [u8; 48] = [0x11; 48];
+
+    fn ml_kem_ss() -> KyberSharedSecret {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x42; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x24; 32]);
+        assert_eq!(decapsulate_shared_secret(&keys.sk, &ct), ss);
+        ss
+    }
+
+    #[test]
+    fn test_tls13_vector() {
+        let combined = HybridCombiner::Tls13.combine(&ml_kem_ss(), &CLASSICAL_SS);
+        assert_eq!(
+            hex::encode(&*combined),
+            "111111111111111111111111111111111111111111111111\
+             111111111111111111111111111111111111111111111111\
+             25beff8a4c047cd1532e6a6136114f2d6bf149fed4132990060af7a1eea780c7"
+        );
+    }
+
+    #[test]
+    fn test_kdf_bound_vector() {
+        // Cross-checked against Python's hmac + hashlib.sha3_256 (RFC 5869 HKDF)
+        let combiner = HybridCombiner::KdfBound { label: b"example-protocol v1" };
+        let key = combiner.combine(&ml_kem_ss(), &CLASSICAL_SS);
+        assert_eq!(key.len(), HYBRID_KDF_BYTES);
+        assert_eq!(
+            hex::encode(&*key),
+            "1e81ba89f2ca9185707964e31d42d4ec7ba1377a8c6a0190b7c11a8ca2044375"
+        );
+
+        let relabeled = HybridCombiner::KdfBound { label: b"example-protocol v2" };
+        assert_ne!(*relabeled.combine(&ml_kem_ss(), &CLASSICAL_SS), *key);
+        assert_ne!(*combiner.combine(&ml_kem_ss(), &[0x12; 48]), *key);
+    }
+}
@@ -0,0 +1,213 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// FIPS Service Indicator (SP 800-140 / ISO 19790 7.4.3)
+// ------------------------------------------------------------------------
+//! Per-result approved-service indicator.
+//!
+//! The `*_indicated` functions run the same service as their plain
+//! counterparts and return the output in an [`ApprovedResult`] whose
+//! [`FipsIndicator`] says whether an approved service produced it. The
+//! indicator is [`FipsIndicator::Approved`] only when all of these hold:
+//!
+//! - the module is built with `fips_140_3`,
+//! - the module is Operational when the service starts, and
+//! - the parameters are approved (ML-KEM-1024 and ML-DSA-65 always are;
+//!   AES-GCM only with the full 16-byte tag).
+//!
+//! These functions do not refuse to run outside the Operational state;
+//! callers that need a hard gate use [`crate::OperationalHandle`] or
+//! [`crate::MonitoredCrypto`].
+
+use crate::state::is_operational;
+
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+use crate::error::Result;
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+use crate::rng::SeedSource;
+
+#[cfg(feature = "ml-kem")]
+use crate::{KyberCiphertext, KyberPublicKey, KyberSecretKey, KyberSharedSecret};
+
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+use crate::{DilithiumSecretKey, DilithiumSignature};
+
+/// Whether a service result came from an approved service
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FipsIndicator {
+    /// FIPS mode, Operational state and approved parameters
+    Approved,
+    /// Any of the three conditions did not hold
+    NotApproved,
+}
+
+impl FipsIndicator {
+    pub fn is_approved(self) -> bool {
+        self == FipsIndicator::Approved
+    }
+}
+
+/// Indicator for a service about to run with the given parameter approval
+pub fn service_indicator(approved_parameters: bool) -> FipsIndicator {
+    if cfg!(feature = "fips_140_3") && is_operational() && approved_parameters {
+        FipsIndicator::Approved
+    } else {
+        FipsIndicator::NotApproved
+    }
+}
+
+/// A service output tagged with its [`FipsIndicator`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovedResult<T> {
+    pub value: T,
+    pub indicator: FipsIndicator,
+}
+
+impl<T> ApprovedResult<T> {
+    /// `true` if an approved service produced `value`
+    pub fn approved(&self) -> bool {
+        self.indicator.is_approved()
+    }
+
+    /// Drop the indicator
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Transform the value, keeping the indicator
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> ApprovedResult<U> {
+        ApprovedResult { value: f(self.value), indicator: self.indicator }
+    }
+}
+
+/// ML-KEM-1024 encapsulation with OS randomness
+#[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+pub fn encapsulate_indicated(
+    pk: &KyberPublicKey,
+) -> Result<ApprovedResult<(KyberCiphertext, KyberSharedSecret)>> {
+    encapsulate_indicated_with_rng(pk, &mut rand::rngs::OsRng)
+}
+
+/// ML-KEM-1024 encapsulation with randomness drawn from `rng`
+#[cfg(feature = "ml-kem")]
+pub fn encapsulate_indicated_with_rng<R: SeedSource + ?Sized>(
+    pk: &KyberPublicKey,
+    rng: &mut R,
+) -> Result<ApprovedResult<(KyberCiphertext, KyberSharedSecret)>> {
+    let indicator = service_indicator(true);
+    let value = crate::encapsulate_shared_secret_with_rng(pk, rng)?;
+    Ok(ApprovedResult { value, indicator })
+}
+
+/// ML-KEM-1024 decapsulation
+#[cfg(feature = "ml-kem")]
+pub fn decapsulate_indicated(
+    sk: &KyberSecretKey,
+    ct: &KyberCiphertext,
+) -> ApprovedResult<KyberSharedSecret> {
+    let indicator = service_indicator(true);
+    ApprovedResult { value: crate::decapsulate_shared_secret(sk, ct), indicator }
+}
+
+/// Hedged ML-DSA-65 signature with OS randomness
+#[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+pub fn sign_indicated(sk: &DilithiumSecretKey, msg: &[u8]) -> Result<ApprovedResult<DilithiumSignature>> {
+    sign_indicated_with_rng(sk, msg, &mut rand::rngs::OsRng)
+}
+
+/// Hedged ML-DSA-65 signature with randomness drawn from `rng`
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+pub fn sign_indicated_with_rng<R: SeedSource + ?Sized>(
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    rng: &mut R,
+) -> Result<ApprovedResult<DilithiumSignature>> {
+    let indicator = service_indicator(true);
+    let value = crate::sign_message_with_rng(sk, msg, rng)?;
+    Ok(ApprovedResult { value, indicator })
+}
+
+/// AES-256-GCM decryption with a `tag_len`-byte tag
+///
+/// Only 16-byte tags are approved; the truncated tags accepted outside
+/// `fips_140_3` always report [`FipsIndicator::NotApproved`].
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
+pub fn decrypt_aes_gcm_with_tag_len_indicated(
+    key_bytes: &[u8; crate::AES_KEY_BYTES],
+    nonce_bytes: &[u8; crate::AES_NONCE_BYTES],
+    ciphertext: &[u8],
+    tag_len: usize,
+) -> crate::Result<ApprovedResult<alloc::vec::Vec<u8>>> {
+    let indicator = service_indicator(tag_len == crate::AES_TAG_BYTES);
+    let value = crate::decrypt_aes_gcm_with_tag_len(key_bytes, nonce_bytes, ciphertext, tag_len)?;
+    Ok(ApprovedResult { value, indicator })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::state::{enter_error_state, lock_state_for_test, reset_fips_state};
+
+    #[test]
+    fn test_indicator_follows_mode_and_state() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        assert_eq!(service_indicator(true), FipsIndicator::NotApproved);
+
+        crate::run_post().unwrap();
+        assert_eq!(service_indicator(true).is_approved(), crate::is_fips_mode());
+        assert_eq!(service_indicator(false), FipsIndicator::NotApproved);
+
+        enter_error_state();
+        assert_eq!(service_indicator(true), FipsIndicator::NotApproved);
+        reset_fips_state();
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_indicated_services() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        crate::run_post().unwrap();
+
+        let mut rng = rand::rngs::OsRng;
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x72; 64]);
+        let enc = encapsulate_indicated_with_rng(&keys.pk, &mut rng).unwrap();
+        assert_eq!(enc.approved(), crate::is_fips_mode());
+        let (ct, ss) = enc.into_inner();
+        let dec = decapsulate_indicated(&keys.sk, &ct);
+        assert_eq!(dec.indicator, service_indicator(true));
+        assert_eq!(dec.value, ss);
+
+        let dsa = crate::DilithiumKeypair::generate_with_seed([0x73; 32]);
+        let signed = sign_indicated_with_rng(&dsa.sk, b"indicated", &mut rng).unwrap();
+        assert_eq!(signed.approved(), crate::is_fips_mode());
+        assert!(dsa.verify(b"indicated", &signed.value));
+
+        // Same service, module not initialized: not approved
+        reset_fips_state();
+        let signed = sign_indicated_with_rng(&dsa.sk, b"indicated", &mut rng).unwrap();
+        assert_eq!(signed.indicator, FipsIndicator::NotApproved);
+        assert_eq!(signed.map(|sig| sig.as_slice().len()).value, crate::ML_DSA_65_SIG_BYTES);
+    }
+
+    #[test]
+    #[cfg(all(feature = "aes-gcm", not(feature = "fips_140_3")))]
+    fn test_truncated_tag_not_approved() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        crate::run_post().unwrap();
+
+        let (key, nonce) = ([0x74; 32], [0x75; 12]);
+        let sealed = crate::encrypt_aes_gcm(&key, &nonce, b"legacy peer").unwrap();
+        let full = decrypt_aes_gcm_with_tag_len_indicated(&key, &nonce, &sealed, 16).unwrap();
+        assert_eq!(full.indicator, service_indicator(true));
+        assert_eq!(full.value, b"legacy peer");
+
+        let short = &sealed[..sealed.len() - 4];
+        let truncated = decrypt_aes_gcm_with_tag_len_indicated(&key, &nonce, short, 12).unwrap();
+        assert_eq!(truncated.indicator, FipsIndicator::NotApproved);
+        assert_eq!(truncated.value, b"legacy peer");
+        reset_fips_state();
+    }
+}
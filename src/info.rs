@@ -0,0 +1,299 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Algorithm and Module Metadata
+// ------------------------------------------------------------------------
+//! Runtime description of each supported parameter set and of the module.
+//!
+//! Lets generic code (serializers, protocol negotiation) size buffers from
+//! an [`AlgorithmInfo`] instead of the `ML_KEM_1024_*` / `ML_DSA_65_*`
+//! constants. Available regardless of which algorithm features are enabled.
+//! [`module_info`] reports the build and FIPS state for audit logs, and
+//! [`fips_policy`] the policy compiled in, so applications can branch on it
+//! without their own `#[cfg(feature = "fips_140_3")]`.
+//!
+//! [`set_allowed_parameter_sets`] narrows which [`ParameterSet`]s the
+//! generic [`crate::traits`] implementations accept at runtime (all of them
+//! until it is called). The typed free functions are not affected; the
+//! whitelist is the policy lever for code written against the traits.
+
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::csp::{get_csp_export_policy, CspExportPolicy};
+use crate::state::{get_fips_state, FipsState};
+use crate::{
+    ML_DSA_65_PK_BYTES, ML_DSA_65_SIG_BYTES, ML_DSA_65_SK_BYTES, ML_DSA_KEYGEN_SEED_BYTES,
+    ML_KEM_1024_CT_BYTES, ML_KEM_1024_PK_BYTES, ML_KEM_1024_SK_BYTES, ML_KEM_KEYGEN_SEED_BYTES,
+};
+
+/// Sizes and security level of one parameter set
+///
+/// `ciphertext_bytes` is `None` for signature schemes and `signature_bytes`
+/// is `None` for KEMs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlgorithmInfo {
+    pub name: &'static str,
+    pub nist_level: u8,
+    pub public_key_bytes: usize,
+    pub secret_key_bytes: usize,
+    pub ciphertext_bytes: Option<usize>,
+    pub signature_bytes: Option<usize>,
+    pub keygen_seed_bytes: usize,
+}
+
+/// ML-KEM-1024 (FIPS 203), NIST Level 5
+pub const fn info_ml_kem_1024() -> AlgorithmInfo {
+    AlgorithmInfo {
+        name: "ML-KEM-1024",
+        nist_level: 5,
+        public_key_bytes: ML_KEM_1024_PK_BYTES,
+        secret_key_bytes: ML_KEM_1024_SK_BYTES,
+        ciphertext_bytes: Some(ML_KEM_1024_CT_BYTES),
+        signature_bytes: None,
+        keygen_seed_bytes: ML_KEM_KEYGEN_SEED_BYTES,
+    }
+}
+
+/// ML-DSA-65 (FIPS 204), NIST Level 3
+pub const fn info_ml_dsa_65() -> AlgorithmInfo {
+    AlgorithmInfo {
+        name: "ML-DSA-65",
+        nist_level: 3,
+        public_key_bytes: ML_DSA_65_PK_BYTES,
+        secret_key_bytes: ML_DSA_65_SK_BYTES,
+        ciphertext_bytes: None,
+        signature_bytes: Some(ML_DSA_65_SIG_BYTES),
+        keygen_seed_bytes: ML_DSA_KEYGEN_SEED_BYTES,
+    }
+}
+
+/// Every parameter set this crate implements
+pub const fn all_algorithms() -> [AlgorithmInfo; 2] {
+    [info_ml_kem_1024(), info_ml_dsa_65()]
+}
+
+/// A parameter set that can be allowed or refused at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterSet {
+    MlKem1024,
+    MlDsa65,
+}
+
+impl ParameterSet {
+    /// Every parameter set this crate implements
+    pub const ALL: [ParameterSet; 2] = [ParameterSet::MlKem1024, ParameterSet::MlDsa65];
+
+    /// Sizes and security level
+    pub const fn info(self) -> AlgorithmInfo {
+        match self {
+            ParameterSet::MlKem1024 => info_ml_kem_1024(),
+            ParameterSet::MlDsa65 => info_ml_dsa_65(),
+        }
+    }
+
+    const fn bit(self) -> usize {
+        1 << self as usize
+    }
+}
+
+/// Bit `1 << set` is set for each allowed [`ParameterSet`]
+static ALLOWED_PARAMETER_SETS: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Allow exactly `sets`; the trait implementations refuse every other one
+///
+/// Takes effect process-wide for calls that start afterwards. An empty
+/// slice refuses everything.
+pub fn set_allowed_parameter_sets(sets: &[ParameterSet]) {
+    let mask = sets.iter().fold(0, |mask, set| mask | set.bit());
+    ALLOWED_PARAMETER_SETS.store(mask, Ordering::Release);
+}
+
+/// Whether `set` is currently allowed
+pub fn is_parameter_set_allowed(set: ParameterSet) -> bool {
+    ALLOWED_PARAMETER_SETS.load(Ordering::Acquire) & set.bit() != 0
+}
+
+/// The currently allowed parameter sets
+pub fn allowed_parameter_sets() -> impl Iterator<Item = ParameterSet> {
+    ParameterSet::ALL.into_iter().filter(|&set| is_parameter_set_allowed(set))
+}
+
+/// `ParameterSetNotAllowed` unless `set` is on the whitelist
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+pub(crate) fn check_parameter_set(set: ParameterSet) -> crate::error::Result<()> {
+    if is_parameter_set_allowed(set) {
+        Ok(())
+    } else {
+        Err(crate::error::PqcError::ParameterSetNotAllowed)
+    }
+}
+
+/// Algorithm features compiled into this build
+const ENABLED_ALGORITHMS: &[&str] = &[
+    #[cfg(feature = "ml-kem")]
+    "ml-kem",
+    #[cfg(feature = "ml-dsa")]
+    "ml-dsa",
+    #[cfg(feature = "aes-gcm")]
+    "aes-gcm",
+];
+
+/// Build and runtime report for the cryptographic module
+///
+/// The `Display` form is a single line suitable for a startup audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleInfo {
+    /// Crate version (`CARGO_PKG_VERSION`)
+    pub version: &'static str,
+    /// Whether the `fips_140_3` feature is compiled in
+    pub fips_140_3: bool,
+    /// FIPS state at the time of the call
+    pub state: FipsState,
+    /// Enabled algorithm features (`ml-kem`, `ml-dsa`, `aes-gcm`)
+    pub algorithms: &'static [&'static str],
+}
+
+/// Report the module version, compliance features and current state
+pub fn module_info() -> ModuleInfo {
+    ModuleInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        fips_140_3: cfg!(feature = "fips_140_3"),
+        state: get_fips_state(),
+        algorithms: ENABLED_ALGORITHMS,
+    }
+}
+
+/// Whether the module was built in FIPS 140-3 mode (`fips_140_3` feature)
+pub const fn is_fips_mode() -> bool {
+    cfg!(feature = "fips_140_3")
+}
+
+/// Security policy in effect for this build
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FipsPolicy {
+    /// Whether the module was built in FIPS 140-3 mode
+    pub fips_mode: bool,
+    /// Secret key export policy applied by the `csp` guards
+    pub csp_export: CspExportPolicy,
+    /// Whether POST runs the ML-KEM / ML-DSA Known Answer Tests
+    pub kats_required: bool,
+    /// Whether plaintext secret key export is refused
+    pub plaintext_csp_export_blocked: bool,
+}
+
+/// Report the policy compiled into this build
+pub fn fips_policy() -> FipsPolicy {
+    let csp_export = get_csp_export_policy();
+    FipsPolicy {
+        fips_mode: is_fips_mode(),
+        csp_export,
+        kats_required: is_fips_mode(),
+        plaintext_csp_export_blocked: csp_export == CspExportPolicy::BlockPlaintext,
+    }
+}
+
+impl fmt::Display for ModuleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pqc-combo {} fips_140_3={} state={:?} algorithms=[",
+            self.version,
+            if self.fips_140_3 { "on" } else { "off" },
+            self.state,
+        )?;
+        for (i, alg) in self.algorithms.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(alg)?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_info_values() {
+        let kem = info_ml_kem_1024();
+        assert_eq!(kem.name, "ML-KEM-1024");
+        assert_eq!(kem.nist_level, 5);
+        assert_eq!(kem.public_key_bytes, 1568);
+        assert_eq!(kem.ciphertext_bytes, Some(1568));
+        assert_eq!(kem.signature_bytes, None);
+
+        let dsa = info_ml_dsa_65();
+        assert_eq!(dsa.name, "ML-DSA-65");
+        assert_eq!(dsa.nist_level, 3);
+        assert_eq!(dsa.secret_key_bytes, 4032);
+        assert_eq!(dsa.signature_bytes, Some(3309));
+        assert_eq!(dsa.ciphertext_bytes, None);
+
+        assert_eq!(all_algorithms(), [kem, dsa]);
+        assert_eq!(ParameterSet::ALL.map(ParameterSet::info), all_algorithms());
+    }
+
+    #[test]
+    fn test_module_info() {
+        let _guard = crate::state::lock_state_for_test();
+        crate::state::reset_fips_state();
+
+        let info = module_info();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.fips_140_3, cfg!(feature = "fips_140_3"));
+        assert_eq!(info.state, FipsState::Uninitialized);
+        assert_eq!(info.algorithms.contains(&"ml-kem"), cfg!(feature = "ml-kem"));
+        assert_eq!(info.algorithms.contains(&"ml-dsa"), cfg!(feature = "ml-dsa"));
+        assert_eq!(info.algorithms.contains(&"aes-gcm"), cfg!(feature = "aes-gcm"));
+    }
+
+    #[test]
+    fn test_fips_policy_matches_features() {
+        let policy = fips_policy();
+        let fips = cfg!(feature = "fips_140_3");
+        assert_eq!(is_fips_mode(), fips);
+        assert_eq!(policy.fips_mode, fips);
+        assert_eq!(policy.kats_required, fips);
+        assert_eq!(policy.plaintext_csp_export_blocked, fips);
+        assert_eq!(
+            policy.csp_export,
+            if fips { CspExportPolicy::BlockPlaintext } else { CspExportPolicy::AllowPlaintext }
+        );
+    }
+
+    #[test]
+    fn test_module_info_display() {
+        use std::string::ToString;
+        let info = ModuleInfo {
+            version: "1.2.3",
+            fips_140_3: true,
+            state: FipsState::Operational,
+            algorithms: &["ml-kem", "ml-dsa"],
+        };
+        assert_eq!(
+            info.to_string(),
+            "pqc-combo 1.2.3 fips_140_3=on state=Operational algorithms=[ml-kem,ml-dsa]"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_algorithm_info_matches_key_types() {
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x01; 64]);
+        let (ct, _ss) = crate::encapsulate_shared_secret_with_randomness(&keys.pk, [0x02; 32]);
+        let kem = info_ml_kem_1024();
+        assert_eq!(keys.pk.as_slice().len(), kem.public_key_bytes);
+        assert_eq!(keys.sk.as_slice().len(), kem.secret_key_bytes);
+        assert_eq!(Some(ct.as_slice().len()), kem.ciphertext_bytes);
+
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x03; 32]);
+        let sig = crate::sign_message_with_randomness(&sk, b"m", [0x04; 32]);
+        let dsa = info_ml_dsa_65();
+        assert_eq!(pk.as_slice().len(), dsa.public_key_bytes);
+        assert_eq!(sk.as_slice().len(), dsa.secret_key_bytes);
+        assert_eq!(Some(sig.as_slice().len()), dsa.signature_bytes);
+    }
+}
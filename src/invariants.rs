@@ -0,0 +1,74 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Round-Trip Invariants Shared by the Library and the Fuzz Targets
+// ------------------------------------------------------------------------
+//! Predicates for properties that must hold for every genuine key pair.
+//!
+//! The fuzz targets assert them on fuzzer-chosen seeds; the library checks
+//! them with `debug_invariant!` in debug builds, where a violation panics
+//! with the invariant's name. Release builds never evaluate them.
+//!
+//! They hold only for a matching pair: [`crate::pct`] is the check for
+//! keys of unknown origin and reports a mismatch as an error instead.
+
+#[cfg(feature = "ml-kem")]
+use crate::ct::ConstantTimeEq;
+#[cfg(feature = "ml-kem")]
+use crate::{
+    decapsulate_shared_secret, encapsulate_shared_secret_with_randomness, KyberPublicKey,
+    KyberSecretKey, ML_KEM_ENCAP_SEED_BYTES,
+};
+#[cfg(feature = "ml-dsa")]
+use crate::{
+    sign_with_any_context, verify_with_any_context, DilithiumPublicKey, DilithiumSecretKey,
+    ML_DSA_SIGN_SEED_BYTES,
+};
+
+/// Encapsulating to `pk` with `randomness` and decapsulating with `sk`
+/// gives the same shared secret
+#[cfg(feature = "ml-kem")]
+pub fn kem_roundtrip(
+    pk: &KyberPublicKey,
+    sk: &KyberSecretKey,
+    randomness: [u8; ML_KEM_ENCAP_SEED_BYTES],
+) -> bool {
+    let (ct, ss) = encapsulate_shared_secret_with_randomness(pk, randomness);
+    ss.ct_eq(&decapsulate_shared_secret(sk, &ct)).into()
+}
+
+/// A signature by `sk` over `msg` verifies under `pk`
+///
+/// Signs with an empty context, so it also runs under `require-context`.
+#[cfg(feature = "ml-dsa")]
+pub fn dsa_roundtrip(
+    pk: &DilithiumPublicKey,
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    randomness: [u8; ML_DSA_SIGN_SEED_BYTES],
+) -> bool {
+    sign_with_any_context(sk, msg, &[], randomness)
+        .is_ok_and(|sig| verify_with_any_context(pk, msg, &[], &sig))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_kem_roundtrip_only_for_matching_pairs() {
+        let a = crate::KyberKeys::generate_key_pair_with_seed([0xb1; 64]);
+        let b = crate::KyberKeys::generate_key_pair_with_seed([0xb2; 64]);
+        assert!(kem_roundtrip(&a.pk, &a.sk, [0xb3; 32]));
+        assert!(!kem_roundtrip(&a.pk, &b.sk, [0xb3; 32]));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_dsa_roundtrip_only_for_matching_pairs() {
+        let (pk_a, sk_a) = crate::generate_dilithium_keypair_with_seed([0xb4; 32]);
+        let (pk_b, _) = crate::generate_dilithium_keypair_with_seed([0xb5; 32]);
+        assert!(dsa_roundtrip(&pk_a, &sk_a, b"invariant", [0xb6; 32]));
+        assert!(!dsa_roundtrip(&pk_b, &sk_a, b"invariant", [0xb6; 32]));
+    }
+}
@@ -0,0 +1,146 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// JOSE (JWK / JWS) Support for ML-DSA-65
+// ------------------------------------------------------------------------
+//! JWK export and compact JWS verification for ML-DSA-65.
+//!
+//! Parameters follow the draft PQC JOSE specification: `kty` = "AKP",
+//! `alg` = "ML-DSA-65", with the public key base64url-encoded (no padding)
+//! in the `pub` member. JWS signatures use an empty ML-DSA context string.
+
+extern crate alloc;
+#[cfg(not(feature = "require-context"))]
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::{json, Value};
+
+use crate::error::{PqcError, Result};
+use crate::DilithiumPublicKey;
+#[cfg(not(feature = "require-context"))]
+use crate::{verify_signature, DilithiumSignature};
+
+/// JWK key type for algorithm key pairs
+pub const JWK_KTY_AKP: &str = "AKP";
+/// JOSE algorithm name for ML-DSA-65
+pub const JOSE_ALG_ML_DSA_65: &str = "ML-DSA-65";
+
+/// Encode a public key as a JWK
+pub fn to_jwk(pk: &DilithiumPublicKey) -> Value {
+    json!({
+        "kty": JWK_KTY_AKP,
+        "alg": JOSE_ALG_ML_DSA_65,
+        "pub": URL_SAFE_NO_PAD.encode(pk.as_slice()),
+    })
+}
+
+/// Decode a public key from a JWK produced by [`to_jwk`]
+pub fn from_jwk(jwk: &Value) -> Result<DilithiumPublicKey> {
+    if jwk["kty"] != JWK_KTY_AKP || jwk["alg"] != JOSE_ALG_ML_DSA_65 {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let encoded = jwk["pub"].as_str().ok_or(PqcError::InvalidEncoding)?;
+    DilithiumPublicKey::try_from(decode_b64url(encoded)?.as_slice())
+}
+
+/// Verify a compact-serialized JWS against a JWK
+///
+/// The protected header must declare `alg` = "ML-DSA-65". The signature is
+/// checked over the JWS signing input `BASE64URL(header) || '.' || BASE64URL(payload)`.
+#[cfg(not(feature = "require-context"))]
+pub fn jws_verify(jwk: &Value, compact_jws: &str) -> Result<()> {
+    let pk = from_jwk(jwk)?;
+
+    let mut parts = compact_jws.split('.');
+    let (header_b64, payload_b64, signature_b64) =
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(h), Some(p), Some(s), None) => (h, p, s),
+            _ => return Err(PqcError::InvalidEncoding),
+        };
+
+    let header: Value = serde_json::from_slice(&decode_b64url(header_b64)?)
+        .map_err(|_| PqcError::InvalidEncoding)?;
+    if header["alg"] != JOSE_ALG_ML_DSA_65 {
+        return Err(PqcError::InvalidEncoding);
+    }
+    // Payload must be valid base64url even though only its encoded form is signed
+    decode_b64url(payload_b64)?;
+
+    let signature = DilithiumSignature::try_from(decode_b64url(signature_b64)?.as_slice())?;
+
+    let mut signing_input = String::with_capacity(header_b64.len() + 1 + payload_b64.len());
+    signing_input.push_str(header_b64);
+    signing_input.push('.');
+    signing_input.push_str(payload_b64);
+
+    if verify_signature(&pk, signing_input.as_bytes(), &signature) {
+        Ok(())
+    } else {
+        Err(PqcError::VerificationFailure)
+    }
+}
+
+fn decode_b64url(encoded: &str) -> Result<Vec<u8>> {
+    URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| PqcError::InvalidEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "std", not(feature = "require-context")))]
+    fn compact_jws(sk: &crate::DilithiumSecretKey, payload: &[u8]) -> String {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"ML-DSA-65"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(payload);
+        let signing_input = alloc::format!("{}.{}", header, payload);
+        let sig = crate::sign_message_with_randomness(sk, signing_input.as_bytes(), [0x61; 32]);
+        alloc::format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(sig.as_slice()))
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_jwk_roundtrip() {
+        let (pk, _sk) = crate::generate_dilithium_keypair_with_seed([0x62; 32]);
+        let jwk = to_jwk(&pk);
+        assert_eq!(jwk["kty"], "AKP");
+        assert_eq!(jwk["alg"], "ML-DSA-65");
+        assert_eq!(from_jwk(&jwk).unwrap().as_slice(), pk.as_slice());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(feature = "require-context")))]
+    fn test_jws_roundtrip() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x63; 32]);
+        let jws = compact_jws(&sk, br#"{"sub":"device-42"}"#);
+        assert!(jws_verify(&to_jwk(&pk), &jws).is_ok());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(feature = "require-context")))]
+    fn test_jws_rejects_tampering() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x64; 32]);
+        let jwk = to_jwk(&pk);
+        let jws = compact_jws(&sk, b"original");
+
+        let mut parts: Vec<&str> = jws.split('.').collect();
+        let forged = URL_SAFE_NO_PAD.encode(b"forged");
+        parts[1] = &forged;
+        assert_eq!(jws_verify(&jwk, &parts.join(".")), Err(PqcError::VerificationFailure));
+
+        assert_eq!(jws_verify(&jwk, "a.b"), Err(PqcError::InvalidEncoding));
+        assert_eq!(jws_verify(&jwk, &alloc::format!("{}.x", jws)), Err(PqcError::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_from_jwk_rejects_wrong_parameters() {
+        let wrong_kty = json!({ "kty": "OKP", "alg": "ML-DSA-65", "pub": "AAAA" });
+        assert!(matches!(from_jwk(&wrong_kty), Err(PqcError::InvalidEncoding)));
+
+        let short_key = json!({ "kty": "AKP", "alg": "ML-DSA-65", "pub": "AAAA" });
+        assert!(matches!(from_jwk(&short_key), Err(PqcError::InvalidKeyLength)));
+    }
+}
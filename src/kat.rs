@@ -0,0 +1,237 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Known Answer Test Vector Runner
+// ------------------------------------------------------------------------
+//! Table-driven Known Answer Tests.
+//!
+//! A [`KatVector`] pairs the inputs of one operation with its expected
+//! outputs; [`run_vectors`] checks a whole table. The built-in POST vectors
+//! are expressed this way, and auditors can run their own (for example
+//! ACVP-derived) tables through the same runner.
+//!
+//! Expected secret outputs (secret keys, shared secrets) are compared in
+//! constant time; public outputs with an ordinary compare. Secret keys and
+//! shared secrets derived while checking a vector zeroize themselves when
+//! `check` returns.
+
+use sha3::{Digest, Sha3_256};
+
+use crate::ct::constant_time_eq_secret;
+use crate::error::{PqcError, Result};
+
+#[cfg(feature = "ml-kem")]
+use crate::{
+    decapsulate_shared_secret, encapsulate_shared_secret_with_randomness, validate_public_key,
+    KyberCiphertext, KyberKeys, KyberPublicKey, KyberSecretKey, ML_KEM_ENCAP_SEED_BYTES, ML_KEM_KEYGEN_SEED_BYTES,
+};
+
+#[cfg(feature = "ml-dsa")]
+use crate::{
+    generate_dilithium_keypair_with_seed, sign_with_any_context, verify_with_any_context,
+    DilithiumPublicKey, DilithiumSecretKey, DilithiumSignature, ML_DSA_KEYGEN_SEED_BYTES, ML_DSA_SIGN_SEED_BYTES,
+};
+
+/// One known-answer test case
+///
+/// Byte fields are borrowed so large tables can live in static data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KatVector<'a> {
+    /// ML-KEM-1024 key generation from `d || z`
+    #[cfg(feature = "ml-kem")]
+    MlKemKeyGen {
+        seed: [u8; ML_KEM_KEYGEN_SEED_BYTES],
+        pk: &'a [u8],
+        sk: &'a [u8],
+    },
+    /// ML-KEM-1024 encapsulation with fixed randomness `m`
+    #[cfg(feature = "ml-kem")]
+    MlKemEncap {
+        pk: &'a [u8],
+        randomness: [u8; ML_KEM_ENCAP_SEED_BYTES],
+        ct: &'a [u8],
+        ss: &'a [u8],
+    },
+    /// ML-KEM-1024 decapsulation, including implicit-rejection cases
+    #[cfg(feature = "ml-kem")]
+    MlKemDecap {
+        sk: &'a [u8],
+        ct: &'a [u8],
+        ss: &'a [u8],
+    },
+    /// ML-KEM-1024 encapsulation-key check (FIPS 203 §7.2); `valid: false`
+    /// makes this a negative vector
+    #[cfg(feature = "ml-kem")]
+    MlKemPublicKeyCheck {
+        pk: &'a [u8],
+        valid: bool,
+    },
+    /// ML-DSA-65 key generation from `xi`
+    #[cfg(feature = "ml-dsa")]
+    MlDsaKeyGen {
+        seed: [u8; ML_DSA_KEYGEN_SEED_BYTES],
+        pk: &'a [u8],
+        sk: &'a [u8],
+    },
+    /// ML-DSA-65 signing; all-zero `randomness` selects the deterministic mode
+    #[cfg(feature = "ml-dsa")]
+    MlDsaSign {
+        sk: &'a [u8],
+        message: &'a [u8],
+        context: &'a [u8],
+        randomness: [u8; ML_DSA_SIGN_SEED_BYTES],
+        sig: &'a [u8],
+    },
+    /// ML-DSA-65 verification with the expected verdict
+    #[cfg(feature = "ml-dsa")]
+    MlDsaVerify {
+        pk: &'a [u8],
+        message: &'a [u8],
+        context: &'a [u8],
+        sig: &'a [u8],
+        valid: bool,
+    },
+}
+
+impl KatVector<'_> {
+    /// Run this vector
+    ///
+    /// Returns `CastFailure` if any output differs from the expected value,
+    /// or the parse error (e.g. `InvalidKeyLength`) for a malformed input.
+    /// ML-DSA vectors are checked without the `require-context` policy, since
+    /// the FIPS 204 and ACVP vectors include empty contexts.
+    pub fn check(&self) -> Result<()> {
+        let passed = match *self {
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemKeyGen { seed, pk, sk } => {
+                let keys = KyberKeys::generate_key_pair_with_seed(seed);
+                keys.pk.as_slice() == pk && constant_time_eq_secret(keys.sk.as_slice(), sk)
+            }
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemEncap { pk, randomness, ct, ss } => {
+                let pk = KyberPublicKey::try_from(pk)?;
+                let (got_ct, got_ss) = encapsulate_shared_secret_with_randomness(&pk, randomness);
+                got_ct.as_slice() == ct && constant_time_eq_secret(&got_ss, ss)
+            }
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemDecap { sk, ct, ss } => {
+                let sk = KyberSecretKey::try_from(sk)?;
+                let ct = KyberCiphertext::try_from(ct)?;
+                constant_time_eq_secret(&decapsulate_shared_secret(&sk, &ct), ss)
+            }
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemPublicKeyCheck { pk, valid } => {
+                validate_public_key(&KyberPublicKey::try_from(pk)?) == valid
+            }
+            #[cfg(feature = "ml-dsa")]
+            KatVector::MlDsaKeyGen { seed, pk, sk } => {
+                let (got_pk, got_sk) = generate_dilithium_keypair_with_seed(seed);
+                got_pk.as_slice() == pk && constant_time_eq_secret(got_sk.as_slice(), sk)
+            }
+            #[cfg(feature = "ml-dsa")]
+            KatVector::MlDsaSign { sk, message, context, randomness, sig } => {
+                let sk = DilithiumSecretKey::try_from(sk)?;
+                // Zero randomness is the deterministic variant, so no seed check
+                sign_with_any_context(&sk, message, context, randomness)?.as_slice() == sig
+            }
+            #[cfg(feature = "ml-dsa")]
+            KatVector::MlDsaVerify { pk, message, context, sig, valid } => {
+                let pk = DilithiumPublicKey::try_from(pk)?;
+                match DilithiumSignature::try_from(sig) {
+                    Ok(sig) => verify_with_any_context(&pk, message, context, &sig) == valid,
+                    // A wrong-length signature can only be an expected rejection
+                    Err(_) => !valid,
+                }
+            }
+        };
+        if passed {
+            Ok(())
+        } else {
+            Err(PqcError::CastFailure)
+        }
+    }
+
+    /// SHA3-256 over the expected outputs, for audit records
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        match *self {
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemKeyGen { pk, sk, .. } => {
+                hasher.update(pk);
+                hasher.update(sk);
+            }
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemEncap { ct, ss, .. } => {
+                hasher.update(ct);
+                hasher.update(ss);
+            }
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemDecap { ss, .. } => hasher.update(ss),
+            #[cfg(feature = "ml-kem")]
+            KatVector::MlKemPublicKeyCheck { valid, .. } => hasher.update([valid as u8]),
+            #[cfg(feature = "ml-dsa")]
+            KatVector::MlDsaKeyGen { pk, sk, .. } => {
+                hasher.update(pk);
+                hasher.update(sk);
+            }
+            #[cfg(feature = "ml-dsa")]
+            KatVector::MlDsaSign { sig, .. } => hasher.update(sig),
+            #[cfg(feature = "ml-dsa")]
+            KatVector::MlDsaVerify { valid, .. } => hasher.update([valid as u8]),
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// Run every vector in `vectors`, stopping at the first failure
+pub fn run_vectors(vectors: &[KatVector<'_>]) -> Result<()> {
+    vectors.iter().try_for_each(KatVector::check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_ml_kem_vectors() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x51; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x52; 32]);
+        let vectors = [
+            KatVector::MlKemKeyGen { seed: [0x51; 64], pk: keys.pk.as_slice(), sk: keys.sk.as_slice() },
+            KatVector::MlKemEncap { pk: keys.pk.as_slice(), randomness: [0x52; 32], ct: ct.as_slice(), ss: &ss },
+            KatVector::MlKemDecap { sk: keys.sk.as_slice(), ct: ct.as_slice(), ss: &ss },
+        ];
+        assert_eq!(run_vectors(&vectors), Ok(()));
+
+        let wrong = KatVector::MlKemEncap { pk: keys.pk.as_slice(), randomness: [0x53; 32], ct: ct.as_slice(), ss: &ss };
+        assert_eq!(wrong.check(), Err(PqcError::CastFailure));
+        let mut bad_pk = keys.pk.as_slice().to_vec();
+        bad_pk[..2].fill(0xff);
+        assert_eq!(KatVector::MlKemPublicKeyCheck { pk: &bad_pk, valid: false }.check(), Ok(()));
+        assert_eq!(KatVector::MlKemPublicKeyCheck { pk: &bad_pk, valid: true }.check(), Err(PqcError::CastFailure));
+        assert_eq!(KatVector::MlKemPublicKeyCheck { pk: keys.pk.as_slice(), valid: true }.check(), Ok(()));
+        let short = KatVector::MlKemDecap { sk: &keys.sk.as_slice()[1..], ct: ct.as_slice(), ss: &ss };
+        assert_eq!(short.check(), Err(PqcError::InvalidKeyLength));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_ml_dsa_vectors() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x54; 32]);
+        let hedged = crate::sign_message_with_context_and_randomness(&sk, b"m", b"c", [0x55; 32]).unwrap();
+        let deterministic = crate::sign_message_deterministic(&sk, b"m", b"c").unwrap();
+        let vectors = [
+            KatVector::MlDsaKeyGen { seed: [0x54; 32], pk: pk.as_slice(), sk: sk.as_slice() },
+            KatVector::MlDsaSign { sk: sk.as_slice(), message: b"m", context: b"c", randomness: [0x55; 32], sig: hedged.as_slice() },
+            KatVector::MlDsaSign { sk: sk.as_slice(), message: b"m", context: b"c", randomness: [0; 32], sig: deterministic.as_slice() },
+            KatVector::MlDsaVerify { pk: pk.as_slice(), message: b"m", context: b"c", sig: hedged.as_slice(), valid: true },
+            KatVector::MlDsaVerify { pk: pk.as_slice(), message: b"x", context: b"c", sig: hedged.as_slice(), valid: false },
+            KatVector::MlDsaVerify { pk: pk.as_slice(), message: b"m", context: b"c", sig: &hedged.as_slice()[1..], valid: false },
+        ];
+        assert_eq!(run_vectors(&vectors), Ok(()));
+
+        let wrong = KatVector::MlDsaVerify { pk: pk.as_slice(), message: b"x", context: b"c", sig: hedged.as_slice(), valid: true };
+        assert_eq!(wrong.check(), Err(PqcError::CastFailure));
+        assert_ne!(vectors[1].fingerprint(), vectors[2].fingerprint());
+    }
+}
@@ -0,0 +1,271 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Key Derivation (Session Keys, Master Seeds)
+// ------------------------------------------------------------------------
+//! Binds an ML-KEM shared secret to the public key and ciphertext that
+//! produced it, so a higher-level handshake cannot be steered into reusing
+//! a secret under a different transcript.
+//!
+//! ```text
+//! key = SHAKE-256(DOMAIN || ss || pk || ct || len(info): u64 BE || info)[..32]
+//! ```
+//!
+//! Also hosts the master-seed expander used for reproducible provisioning
+//! of an ML-KEM and an ML-DSA key pair from one root seed, and
+//! HMAC-SHA3-256 / PBKDF2-HMAC-SHA3-256 (SP 800-132) for passphrase keys.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Shake256};
+use zeroize::Zeroize;
+
+use crate::{KyberCiphertext, KyberPublicKey, KyberSharedSecret};
+#[cfg(feature = "ml-dsa")]
+use crate::{
+    generate_dilithium_keypair_with_seed, DilithiumPublicKey, DilithiumSecretKey, KyberKeys,
+    ML_DSA_KEYGEN_SEED_BYTES, ML_KEM_KEYGEN_SEED_BYTES,
+};
+
+/// Domain-separation label prepended to every derivation
+pub const SESSION_KEY_DOMAIN: &[u8] = b"PQC-COMBO ML-KEM-1024 session key v1";
+
+/// Derived session key length
+pub const SESSION_KEY_BYTES: usize = 32;
+
+/// Derive a 32-byte session key from a shared secret and its transcript
+///
+/// `info` is optional application context (protocol name, role labels);
+/// it is length-prefixed, so distinct `info` values never collide.
+pub fn derive_session_key(
+    ss: &KyberSharedSecret,
+    pk: &KyberPublicKey,
+    ct: &KyberCiphertext,
+    info: &[u8],
+) -> [u8; SESSION_KEY_BYTES] {
+    let mut xof = Shake256::default();
+    xof.update(SESSION_KEY_DOMAIN);
+    xof.update(ss);
+    xof.update(pk.as_slice());
+    xof.update(ct.as_slice());
+    xof.update(&(info.len() as u64).to_be_bytes());
+    xof.update(info);
+
+    let mut key = [0u8; SESSION_KEY_BYTES];
+    xof.finalize_xof().read(&mut key);
+    key
+}
+
+/// Domain tag for the ML-KEM keygen seed expanded from a master seed
+pub const MASTER_KEM_SEED_DOMAIN: &[u8] = b"PQC-COMBO master seed -> ML-KEM-1024 keygen v1";
+
+/// Domain tag for the ML-DSA keygen seed expanded from a master seed
+#[cfg(feature = "ml-dsa")]
+pub const MASTER_DSA_SEED_DOMAIN: &[u8] = b"PQC-COMBO master seed -> ML-DSA-65 keygen v1";
+
+/// Deterministically derive both key pairs from one 32-byte root seed
+///
+/// ```text
+/// kem_seed = SHAKE-256(MASTER_KEM_SEED_DOMAIN || master)[..64]
+/// dsa_seed = SHAKE-256(MASTER_DSA_SEED_DOMAIN || master)[..32]
+/// ```
+///
+/// The expanded seeds are fed to the existing `*_with_seed` key generators
+/// and wiped afterwards. The same master always yields the same key pairs.
+#[cfg(feature = "ml-dsa")]
+pub fn derive_keypairs_from_master(
+    master: &[u8; 32],
+) -> (KyberKeys, (DilithiumPublicKey, DilithiumSecretKey)) {
+    let mut kem_seed = [0u8; ML_KEM_KEYGEN_SEED_BYTES];
+    let mut dsa_seed = [0u8; ML_DSA_KEYGEN_SEED_BYTES];
+    expand_master(master, MASTER_KEM_SEED_DOMAIN, &mut kem_seed);
+    expand_master(master, MASTER_DSA_SEED_DOMAIN, &mut dsa_seed);
+
+    let kem = KyberKeys::generate_key_pair_with_seed(kem_seed);
+    let dsa = generate_dilithium_keypair_with_seed(dsa_seed);
+    kem_seed.zeroize();
+    dsa_seed.zeroize();
+    (kem, dsa)
+}
+
+#[cfg(feature = "ml-dsa")]
+fn expand_master(master: &[u8; 32], domain: &[u8], out: &mut [u8]) {
+    let mut xof = Shake256::default();
+    xof.update(domain);
+    xof.update(master);
+    xof.finalize_xof().read(out);
+}
+
+/// SHA3-256 rate, the HMAC block size
+const HMAC_BLOCK_BYTES: usize = 136;
+
+/// HMAC key block: `key`, or SHA3-256(`key`) if longer than a block
+fn hmac_key_block(key: &[u8]) -> [u8; HMAC_BLOCK_BYTES] {
+    let mut block = [0u8; HMAC_BLOCK_BYTES];
+    if key.len() > HMAC_BLOCK_BYTES {
+        block[..32].copy_from_slice(&Sha3_256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    block
+}
+
+/// HMAC-SHA3-256 (FIPS 198-1) over the concatenation of `parts`
+#[cfg(any(feature = "alloc", test))]
+pub(crate) fn hmac_sha3_256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut block = hmac_key_block(key);
+
+    block.iter_mut().for_each(|b| *b ^= 0x36);
+    let mut inner = Sha3_256::new();
+    Digest::update(&mut inner, block);
+    parts.iter().for_each(|part| Digest::update(&mut inner, part));
+    let mut inner_hash: [u8; 32] = inner.finalize().into();
+
+    // 0x36 ^ 0x5c turns the inner pad into the outer pad
+    block.iter_mut().for_each(|b| *b ^= 0x36 ^ 0x5c);
+    let mut outer = Sha3_256::new();
+    Digest::update(&mut outer, block);
+    Digest::update(&mut outer, inner_hash);
+    block.zeroize();
+    inner_hash.zeroize();
+    outer.finalize().into()
+}
+
+/// PBKDF2 (SP 800-132) with HMAC-SHA3-256 as the PRF, filling `out`
+///
+/// SP 800-132 asks for at least 1000 iterations and a salt of at least
+/// 128 bits; callers enforce those limits.
+pub fn pbkdf2_hmac_sha3_256(passphrase: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    // Absorb the padded key once; every PRF call starts from a clone
+    let mut block = hmac_key_block(passphrase);
+    block.iter_mut().for_each(|b| *b ^= 0x36);
+    let mut inner_keyed = Sha3_256::new();
+    Digest::update(&mut inner_keyed, block);
+    block.iter_mut().for_each(|b| *b ^= 0x36 ^ 0x5c);
+    let mut outer_keyed = Sha3_256::new();
+    Digest::update(&mut outer_keyed, block);
+    block.zeroize();
+
+    let prf = |parts: &[&[u8]], out: &mut [u8; 32]| {
+        let mut inner = inner_keyed.clone();
+        parts.iter().for_each(|part| Digest::update(&mut inner, part));
+        let mut outer = outer_keyed.clone();
+        Digest::update(&mut outer, inner.finalize());
+        out.copy_from_slice(&outer.finalize());
+    };
+
+    for (index, chunk) in out.chunks_mut(32).enumerate() {
+        let block_index = (index as u32 + 1).to_be_bytes();
+        let mut u = [0u8; 32];
+        prf(&[salt, &block_index], &mut u);
+        let mut t = u;
+        for _ in 1..iterations {
+            let previous = u;
+            prf(&[&previous], &mut u);
+            t.iter_mut().zip(u.iter()).for_each(|(t, u)| *t ^= u);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+        u.zeroize();
+        t.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decapsulate_shared_secret, encapsulate_shared_secret_with_randomness, KyberKeys};
+
+    // Cross-checked against Python's hashlib.shake_256 over the same input
+    const EXPECTED: [u8; SESSION_KEY_BYTES] = [
+        0xe3, 0x8f, 0xee, 0x85, 0x7e, 0x2b, 0x64, 0x7c, 0x13, 0x5b, 0x3a, 0xce, 0x9f, 0x5b, 0x90, 0x7a,
+        0xa8, 0xdc, 0x45, 0x36, 0x44, 0x27, 0x0f, 0x0e, 0xda, 0x0d, 0xe8, 0x5d, 0x3e, 0xd1, 0xb4, 0x83,
+    ];
+
+    #[test]
+    fn test_derive_session_key_kat() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x42; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x24; 32]);
+        let key = derive_session_key(&ss, &keys.pk, &ct, b"handshake");
+        assert_eq!(key, EXPECTED);
+
+        // Receiver derives the same key from the decapsulated secret
+        let ss_receiver = decapsulate_shared_secret(&keys.sk, &ct);
+        assert_eq!(derive_session_key(&ss_receiver, &keys.pk, &ct, b"handshake"), EXPECTED);
+    }
+
+    #[test]
+    fn test_derive_session_key_binds_transcript() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x42; 64]);
+        let other = KyberKeys::generate_key_pair_with_seed([0x43; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x24; 32]);
+        let (other_ct, _) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x25; 32]);
+
+        assert_ne!(derive_session_key(&ss, &keys.pk, &ct, b"a"), EXPECTED);
+        assert_ne!(derive_session_key(&ss, &other.pk, &ct, b"handshake"), EXPECTED);
+        assert_ne!(derive_session_key(&ss, &keys.pk, &other_ct, b"handshake"), EXPECTED);
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_master_seed_expansion_kat() {
+        let master = [0x5a; 32];
+        let mut kem_seed = [0u8; 64];
+        let mut dsa_seed = [0u8; 32];
+        expand_master(&master, MASTER_KEM_SEED_DOMAIN, &mut kem_seed);
+        expand_master(&master, MASTER_DSA_SEED_DOMAIN, &mut dsa_seed);
+        // Cross-checked against Python's hashlib.shake_256
+        assert_eq!(
+            hex::encode(kem_seed),
+            "68b54ed0b7494c92706e2908e22ece6e5cb1f48c33e64ba3622e2e0a57776319\
+             9b8cfc62bf16fc0f5deb530903c0fb935e4d777e8c0c0d61b59ec59b59aaf009"
+        );
+        assert_eq!(
+            hex::encode(dsa_seed),
+            "69f836ffe992d42bddfeeaa7810278a4d1f5de03c6d78f6f217b8b4118692ff4"
+        );
+
+        let (kem, (pk, sk)) = derive_keypairs_from_master(&master);
+        let expected_kem = KyberKeys::generate_key_pair_with_seed(kem_seed);
+        let (expected_pk, expected_sk) = generate_dilithium_keypair_with_seed(dsa_seed);
+        assert!(kem.pk == expected_kem.pk);
+        assert!(crate::ct::secret_key_eq(&kem.sk, &expected_kem.sk));
+        assert!(pk == expected_pk);
+        assert!(crate::ct::secret_key_eq(&sk, &expected_sk));
+
+        let (other_kem, (other_pk, _)) = derive_keypairs_from_master(&[0x5b; 32]);
+        assert!(other_kem.pk != kem.pk);
+        assert!(other_pk != pk);
+    }
+
+    #[test]
+    fn test_hmac_long_key() {
+        assert_eq!(
+            hex::encode(hmac_sha3_256(&[0u8; 200], &[b"x"])),
+            "83b2d9c0de9fc313aec5e66b8baa3edb55c49c8becce97504eee94c0f3ab301d"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_hmac_sha3_256_vectors() {
+        // Cross-checked against Python's hashlib.pbkdf2_hmac("sha3_256", ...)
+        let mut key = [0u8; 32];
+        pbkdf2_hmac_sha3_256(b"correct horse battery staple", b"pqc-combo salt!!", 1000, &mut key);
+        assert_eq!(
+            hex::encode(key),
+            "cba0a6065a9f8c9dad31f51d735046b96874eed271e66505d769ad3569ba20b6"
+        );
+
+        // Two output blocks, the second truncated
+        let mut long = [0u8; 40];
+        pbkdf2_hmac_sha3_256(b"password", b"salt", 1, &mut long);
+        assert_eq!(
+            hex::encode(long),
+            "94613f3ee2ea730e0b06754f3fc816d4f87c9be9cbd8556b5d59b52330e333a801e338de77f38790"
+        );
+
+        // Passphrase longer than the HMAC block is hashed first
+        pbkdf2_hmac_sha3_256(&[b'p'; 200], b"salt", 2, &mut key);
+        assert_eq!(
+            hex::encode(key),
+            "0154e2878ec57691f170c25149b4fc43e872f0411d5f761d9ee74b04f37249f4"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Key Encapsulation Re-exports
+// ------------------------------------------------------------------------
+//! ML-KEM-1024 (FIPS 203) surface, available with the `ml-kem` feature.
+//!
+//! `use pqc_combo::kem::prelude::*;` brings in exactly the key encapsulation
+//! types, functions and constants, instead of everything `pqc_combo::*`
+//! exports.
+
+/// Everything needed to generate ML-KEM keys and encapsulate/decapsulate
+pub mod prelude {
+    pub use crate::error::{PqcError, Result};
+    pub use crate::rng::SeedSource;
+    pub use crate::traits::{Kem, MlKem1024};
+    pub use crate::{
+        decapsulate_into, decapsulate_shared_secret, decapsulate_with_status, derive_session_key,
+        encapsulate_into_with_randomness, encapsulate_into_with_rng, encapsulate_shared_secret_with_randomness,
+        encapsulate_shared_secret_with_randomness_mut, encapsulate_shared_secret_with_rng,
+        import_kyber_keypair, try_decapsulate_shared_secret,
+        try_encapsulate_shared_secret_with_randomness, validate_public_key, KyberCiphertext,
+        DecapStatus, KyberKeys, KyberPublicKey, KyberSecretKey, KyberSharedSecret, SharedSecret, ML_KEM_1024_CT_BYTES,
+        ML_KEM_1024_PK_BYTES, ML_KEM_1024_SK_BYTES, ML_KEM_1024_SS_BYTES,
+        ML_KEM_ENCAP_SEED_BYTES, ML_KEM_KEYGEN_SEED_BYTES,
+    };
+    #[cfg(not(feature = "explicit-rng"))]
+    pub use crate::{encapsulate_shared_secret, try_encapsulate_shared_secret};
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    pub use crate::encapsulate_into;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    fn test_prelude_round_trip() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x64; ML_KEM_KEYGEN_SEED_BYTES]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x65; ML_KEM_ENCAP_SEED_BYTES]);
+        let recovered: KyberSharedSecret = decapsulate_shared_secret(&keys.sk, &ct);
+        assert_eq!(recovered, ss);
+        assert_eq!(ct.as_slice().len(), ML_KEM_1024_CT_BYTES);
+    }
+}
@@ -0,0 +1,256 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Passphrase-Encrypted Key Storage for ML-KEM-1024
+// ------------------------------------------------------------------------
+//! Protects a persisted key pair with a passphrase:
+//!
+//! ```text
+//! "PQCE" || version (1) || alg_id (0x01, ML-KEM-1024) || kdf_id (0x01)
+//!        || iterations: u32 BE || salt (16) || nonce (12)
+//!        || pk || AES-256-GCM(key, nonce, aad = everything before, sk) || tag
+//! ```
+//!
+//! The wrapping key is PBKDF2-HMAC-SHA3-256 (SP 800-132) over the
+//! passphrase and a fresh salt. PBKDF2 is used rather than a memory-hard
+//! KDF because it is the FIPS-approved passphrase KDF. The public key stays
+//! in the clear but is authenticated together with the KDF parameters, so
+//! no header byte can be changed without failing decryption.
+//!
+//! Under the `BlockPlaintext` CSP export policy (`fips_140_3`) this is the
+//! supported way to persist a key pair: the secret key only leaves the
+//! module wrapped with approved algorithms. The module must be Operational.
+
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+use crate::error::{PqcError, Result};
+use crate::kdf::pbkdf2_hmac_sha3_256;
+use crate::rng::SeedSource;
+use crate::state::check_operational;
+use crate::{
+    decrypt_aes_gcm_into_with_aad, encrypt_aes_gcm_into_with_aad, KyberKeys, KyberPublicKey,
+    KyberSecretKey, SecretBox, AES_KEY_BYTES, AES_NONCE_BYTES, AES_TAG_BYTES, ML_KEM_1024_PK_BYTES,
+    ML_KEM_1024_SK_BYTES, ML_KEM_1024_SK_EK_OFFSET,
+};
+
+/// Default PBKDF2 iteration count for [`KyberKeys::export_encrypted`]
+pub const PASSPHRASE_KDF_ITERATIONS: u32 = 600_000;
+
+/// Lowest iteration count accepted on export or import (SP 800-132 §5.2)
+pub const PASSPHRASE_KDF_MIN_ITERATIONS: u32 = 1_000;
+
+/// Highest iteration count accepted on export or import
+///
+/// The count is read from the blob before anything is authenticated, so
+/// without a cap a crafted blob could make `import_encrypted` spin for
+/// hours on `u32::MAX` PBKDF2 rounds.
+pub const PASSPHRASE_KDF_MAX_ITERATIONS: u32 = 10_000_000;
+
+/// Salt length (SP 800-132 requires at least 128 bits)
+pub const PASSPHRASE_SALT_BYTES: usize = 16;
+
+const ENCRYPTED_KEY_MAGIC: [u8; 4] = *b"PQCE";
+const ENCRYPTED_KEY_VERSION: u8 = 1;
+const ENCRYPTED_KEY_ALG_ML_KEM_1024: u8 = 0x01;
+const ENCRYPTED_KEY_KDF_PBKDF2_HMAC_SHA3_256: u8 = 0x01;
+const ENCRYPTED_KEY_HEADER_BYTES: usize = 7 + 4 + PASSPHRASE_SALT_BYTES + AES_NONCE_BYTES;
+const ENCRYPTED_KEY_BYTES: usize =
+    ENCRYPTED_KEY_HEADER_BYTES + ML_KEM_1024_PK_BYTES + ML_KEM_1024_SK_BYTES + AES_TAG_BYTES;
+
+impl KyberKeys {
+    /// Encrypt the key pair under `passphrase` with OS randomness
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    pub fn export_encrypted(&self, passphrase: &[u8]) -> Result<Vec<u8>> {
+        self.export_encrypted_with_rng(passphrase, PASSPHRASE_KDF_ITERATIONS, &mut rand::rngs::OsRng)
+    }
+
+    /// Encrypt the key pair, drawing salt and nonce from `rng`
+    ///
+    /// Returns `WeakKdfParameters` if `iterations` is below
+    /// [`PASSPHRASE_KDF_MIN_ITERATIONS`] and `InvalidEncoding` if it is above
+    /// [`PASSPHRASE_KDF_MAX_ITERATIONS`].
+    pub fn export_encrypted_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        passphrase: &[u8],
+        iterations: u32,
+        rng: &mut R,
+    ) -> Result<Vec<u8>> {
+        check_operational()?;
+        check_iterations(iterations)?;
+        let mut salt = [0u8; PASSPHRASE_SALT_BYTES];
+        let mut nonce = [0u8; AES_NONCE_BYTES];
+        rng.fill(&mut salt)?;
+        rng.fill(&mut nonce)?;
+
+        let mut out = Vec::with_capacity(ENCRYPTED_KEY_BYTES);
+        out.extend_from_slice(&ENCRYPTED_KEY_MAGIC);
+        out.push(ENCRYPTED_KEY_VERSION);
+        out.push(ENCRYPTED_KEY_ALG_ML_KEM_1024);
+        out.push(ENCRYPTED_KEY_KDF_PBKDF2_HMAC_SHA3_256);
+        out.extend_from_slice(&iterations.to_be_bytes());
+        out.extend_from_slice(&salt);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(self.pk.as_slice());
+        let aad_len = out.len();
+        out.resize(ENCRYPTED_KEY_BYTES, 0);
+
+        let key = wrapping_key(passphrase, &salt, iterations);
+        let (aad, sealed) = out.split_at_mut(aad_len);
+        encrypt_aes_gcm_into_with_aad(&key, &nonce, aad, self.sk.as_slice(), sealed)?;
+        Ok(out)
+    }
+
+    /// Decrypt a key pair written by [`KyberKeys::export_encrypted`]
+    ///
+    /// A wrong passphrase, or any modified byte, fails authentication with
+    /// `AeadOperationFailed`. A bad magic, version, algorithm or KDF id
+    /// returns `InvalidEncoding`, a wrong overall length `InvalidKeyLength`,
+    /// an iteration count below the minimum `WeakKdfParameters` and one
+    /// above [`PASSPHRASE_KDF_MAX_ITERATIONS`] `InvalidEncoding`, before any
+    /// key derivation.
+    pub fn import_encrypted(bytes: &[u8], passphrase: &[u8]) -> Result<Self> {
+        check_operational()?;
+        if bytes.len() < ENCRYPTED_KEY_HEADER_BYTES {
+            return Err(PqcError::InvalidKeyLength);
+        }
+        if bytes[..4] != ENCRYPTED_KEY_MAGIC
+            || bytes[4] != ENCRYPTED_KEY_VERSION
+            || bytes[5] != ENCRYPTED_KEY_ALG_ML_KEM_1024
+            || bytes[6] != ENCRYPTED_KEY_KDF_PBKDF2_HMAC_SHA3_256
+        {
+            return Err(PqcError::InvalidEncoding);
+        }
+        if bytes.len() != ENCRYPTED_KEY_BYTES {
+            return Err(PqcError::InvalidKeyLength);
+        }
+
+        let (params, rest) = bytes[7..ENCRYPTED_KEY_HEADER_BYTES].split_at(4);
+        let iterations = u32::from_be_bytes(params.try_into().map_err(|_| PqcError::InvalidEncoding)?);
+        check_iterations(iterations)?;
+        let (salt, nonce) = rest.split_at(PASSPHRASE_SALT_BYTES);
+        let nonce: &[u8; AES_NONCE_BYTES] = nonce.try_into().map_err(|_| PqcError::InvalidEncoding)?;
+
+        let (aad, sealed) = bytes.split_at(ENCRYPTED_KEY_HEADER_BYTES + ML_KEM_1024_PK_BYTES);
+        let key = wrapping_key(passphrase, salt, iterations);
+        let mut sk = Zeroizing::new([0u8; ML_KEM_1024_SK_BYTES]);
+        decrypt_aes_gcm_into_with_aad(&key, nonce, aad, sealed, &mut sk[..])?;
+
+        let pk = &aad[ENCRYPTED_KEY_HEADER_BYTES..];
+        // FIPS 203 dk layout: dk_PKE (384 * k bytes) || ek || H(ek) || z
+        if pk != &sk[ML_KEM_1024_SK_EK_OFFSET..ML_KEM_1024_SK_EK_OFFSET + ML_KEM_1024_PK_BYTES] {
+            return Err(PqcError::InvalidEncoding);
+        }
+        Ok(Self {
+            pk: KyberPublicKey::try_from(pk)?,
+            sk: SecretBox::new(KyberSecretKey::from(*sk)),
+        })
+    }
+}
+
+fn check_iterations(iterations: u32) -> Result<()> {
+    if iterations < PASSPHRASE_KDF_MIN_ITERATIONS {
+        Err(PqcError::WeakKdfParameters)
+    } else if iterations > PASSPHRASE_KDF_MAX_ITERATIONS {
+        Err(PqcError::InvalidEncoding)
+    } else {
+        Ok(())
+    }
+}
+
+fn wrapping_key(passphrase: &[u8], salt: &[u8], iterations: u32) -> Zeroizing<[u8; AES_KEY_BYTES]> {
+    let mut key = Zeroizing::new([0u8; AES_KEY_BYTES]);
+    pbkdf2_hmac_sha3_256(passphrase, salt, iterations, &mut key[..]);
+    key
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::state::{lock_state_for_test, reset_fips_state};
+
+    const ITERATIONS: u32 = PASSPHRASE_KDF_MIN_ITERATIONS;
+
+    fn operational() -> std::sync::MutexGuard<'static, ()> {
+        let guard = lock_state_for_test();
+        reset_fips_state();
+        crate::run_post().unwrap();
+        guard
+    }
+
+    #[test]
+    fn test_encrypted_export_roundtrip() {
+        let _guard = operational();
+        let keys = KyberKeys::generate_key_pair_with_seed([0x61; 64]);
+        let blob = keys
+            .export_encrypted_with_rng(b"hunter2", ITERATIONS, &mut rand::rngs::OsRng)
+            .unwrap();
+        assert_eq!(blob.len(), ENCRYPTED_KEY_BYTES);
+        assert_eq!(&blob[..7], b"PQCE\x01\x01\x01");
+        assert_eq!(&blob[7..11], &ITERATIONS.to_be_bytes());
+        // The secret key never appears in the clear
+        assert!(!blob.windows(32).any(|w| w == &keys.sk.as_slice()[..32]));
+
+        let restored = KyberKeys::import_encrypted(&blob, b"hunter2").unwrap();
+        assert!(restored.pk == keys.pk);
+        assert!(crate::ct::secret_key_eq(&restored.sk, &keys.sk));
+
+        // Fresh salt and nonce on every export
+        let again = keys
+            .export_encrypted_with_rng(b"hunter2", ITERATIONS, &mut rand::rngs::OsRng)
+            .unwrap();
+        assert_ne!(blob, again);
+        reset_fips_state();
+    }
+
+    #[test]
+    fn test_encrypted_import_failures() {
+        let _guard = operational();
+        let keys = KyberKeys::generate_key_pair_with_seed([0x62; 64]);
+        let blob = keys
+            .export_encrypted_with_rng(b"hunter2", ITERATIONS, &mut rand::rngs::OsRng)
+            .unwrap();
+
+        assert!(matches!(KyberKeys::import_encrypted(&blob, b"hunter3"), Err(PqcError::AeadOperationFailed)));
+        assert!(matches!(KyberKeys::import_encrypted(&blob, b""), Err(PqcError::AeadOperationFailed)));
+
+        // Authenticated header: salt, nonce and public key
+        for index in [11, ENCRYPTED_KEY_HEADER_BYTES - 1, ENCRYPTED_KEY_HEADER_BYTES + 5, blob.len() - 1] {
+            let mut tampered = blob.clone();
+            tampered[index] ^= 1;
+            assert!(matches!(
+                KyberKeys::import_encrypted(&tampered, b"hunter2"),
+                Err(PqcError::AeadOperationFailed)
+            ));
+        }
+
+        let mut weak = blob.clone();
+        weak[7..11].copy_from_slice(&1u32.to_be_bytes());
+        assert!(matches!(KyberKeys::import_encrypted(&weak, b"hunter2"), Err(PqcError::WeakKdfParameters)));
+        // Rejected before PBKDF2 runs, so this returns at once
+        for excessive in [PASSPHRASE_KDF_MAX_ITERATIONS + 1, u32::MAX] {
+            let mut slow = blob.clone();
+            slow[7..11].copy_from_slice(&excessive.to_be_bytes());
+            assert!(matches!(KyberKeys::import_encrypted(&slow, b"hunter2"), Err(PqcError::InvalidEncoding)));
+        }
+        let mut other_kdf = blob.clone();
+        other_kdf[6] = 0x02;
+        assert!(matches!(KyberKeys::import_encrypted(&other_kdf, b"hunter2"), Err(PqcError::InvalidEncoding)));
+        assert!(matches!(
+            KyberKeys::import_encrypted(&blob[..blob.len() - 1], b"hunter2"),
+            Err(PqcError::InvalidKeyLength)
+        ));
+        assert!(matches!(KyberKeys::import_encrypted(&blob[..3], b"hunter2"), Err(PqcError::InvalidKeyLength)));
+
+        assert!(matches!(
+            keys.export_encrypted_with_rng(b"hunter2", ITERATIONS - 1, &mut rand::rngs::OsRng),
+            Err(PqcError::WeakKdfParameters)
+        ));
+        assert!(matches!(
+            keys.export_encrypted_with_rng(b"hunter2", PASSPHRASE_KDF_MAX_ITERATIONS + 1, &mut rand::rngs::OsRng),
+            Err(PqcError::InvalidEncoding)
+        ));
+
+        reset_fips_state();
+        assert!(matches!(KyberKeys::import_encrypted(&blob, b"hunter2"), Err(PqcError::FipsNotInitialized)));
+    }
+}
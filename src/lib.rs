@@ -8,20 +8,124 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(all(feature = "alloc", any(feature = "aes-gcm")))]
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+#[cfg(all(feature = "alloc", feature = "aes-gcm"))]
 use alloc::vec::Vec;
 
+/// `debug_assert!` for the properties in [`invariants`]
+///
+/// Debug builds panic with `what` when `cond` is false; release builds do
+/// not evaluate `cond` at all.
+#[cfg(feature = "ml-kem")]
+macro_rules! debug_invariant {
+    ($cond:expr, $what:expr) => {
+        if cfg!(debug_assertions) && !$cond {
+            panic!("pqc-combo invariant violated: {}", $what);
+        }
+    };
+}
+
 // === Public Modules ===
 pub mod error;
+pub mod ct;
+pub mod events;
+pub mod info;
 pub mod rng;
 pub mod cast;
 pub mod state;
 pub mod pct;
 pub mod preop;
+#[cfg(feature = "std")]
+pub mod status;
+#[cfg(feature = "std")]
+pub mod entropy;
+#[cfg(feature = "async-post")]
+pub mod async_post;
+pub mod traits;
+
+#[cfg(feature = "ml-kem")]
+pub mod kdf;
+
+#[cfg(feature = "ml-kem")]
+pub mod kem;
+
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+pub mod hybrid;
+
+#[cfg(feature = "ml-dsa")]
+pub mod sig;
+
+#[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+pub mod ake;
+
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+pub mod rotation;
+
+#[cfg(feature = "aes-gcm")]
+pub mod aead;
+
+#[cfg(feature = "aes-gcm")]
+pub mod gcm_context;
+
+#[cfg(feature = "acvp")]
+pub mod prehash;
+
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+pub mod kat;
+
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+pub mod invariants;
+
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+pub mod monitor;
+
+pub mod operational;
+
+pub mod indicator;
+
+#[cfg(all(feature = "alloc", feature = "ml-dsa"))]
+pub mod envelope;
+
+#[cfg(feature = "alloc")]
+pub mod encoding;
+
+#[cfg(feature = "alloc")]
+pub mod ct_codec;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(all(feature = "alloc", feature = "ml-dsa"))]
+pub mod ssh;
+
+#[cfg(all(feature = "alloc", feature = "ml-kem"))]
+pub mod wire;
+
+#[cfg(all(feature = "std", feature = "ml-kem", feature = "aes-gcm"))]
+pub mod stream;
+
+#[cfg(all(feature = "alloc", feature = "ml-kem", feature = "aes-gcm"))]
+pub mod keystore;
 
-#[cfg(feature = "fips_140_3")]
 pub mod csp;
 
+#[cfg(all(feature = "mlock", unix))]
+pub mod locked;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "cose")]
+pub mod cose;
+
+#[cfg(feature = "jose")]
+pub mod jose;
+
+#[cfg(feature = "acvp")]
+pub mod acvp;
+
 // KAT modules (internal to FIPS POST, not public API)
 #[cfg(all(feature = "ml-kem", feature = "fips_140_3"))]
 pub(crate) mod kat_kyber;
@@ -29,13 +133,69 @@ pub(crate) mod kat_kyber;
 #[cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
 pub(crate) mod kat_dilithium;
 
+// Size constants checked against libcrux at compile time
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+mod size_checks;
+
 // === Re-exports ===
 pub use error::{PqcError, Result};
-pub use state::{FipsState, get_fips_state, is_operational, reset_fips_state};
-pub use preop::{run_post, run_post_or_panic};
+pub use rng::{Reseeding, SeedSource, ShakeRng, request_reseed};
+pub use traits::{Kem, Signer, Verifier};
+#[cfg(feature = "ml-kem")]
+pub use traits::MlKem1024;
+pub use events::{FipsEvent, SelfTestId, SelfTestKind};
+#[cfg(feature = "std")]
+pub use events::{clear_event_handler, set_event_handler};
+pub use state::{FipsState, get_fips_state, is_operational, is_retriable, zeroize_on_error};
+#[cfg(any(test, feature = "testing"))]
+pub use state::reset_fips_state;
+#[cfg(feature = "std")]
+pub use state::{ZeroizerId, register_zeroizer, unregister_zeroizer, wait_until_operational};
+pub use preop::{ensure_initialized, run_post, run_post_or_panic, recover_from_error};
+#[cfg(feature = "std")]
+pub use preop::{PostFailure, SelfTestRecord, SelfTestReport, reinitialize, run_post_with_report};
+#[cfg(feature = "std")]
+pub use status::{SelfTestStatus, TestOutcome, self_test_status};
+#[cfg(feature = "std")]
+pub use entropy::{EntropyReport, EntropySource, entropy_report};
+#[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+pub use preop::{KatFingerprint, KatReport, kat_report};
+pub use info::{
+    AlgorithmInfo, FipsPolicy, ModuleInfo, ParameterSet, all_algorithms, allowed_parameter_sets,
+    fips_policy, info_ml_dsa_65, info_ml_kem_1024, is_fips_mode, is_parameter_set_allowed,
+    module_info, set_allowed_parameter_sets,
+};
+
+#[cfg(feature = "ml-kem")]
+pub use kdf::derive_session_key;
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+pub use hybrid::HybridCombiner;
+#[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+pub use kdf::derive_keypairs_from_master;
+#[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+pub use ake::{ake_initiate_with_randomness, ake_initiate_with_rng, ake_respond, AKE_CONTEXT};
+#[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+pub use ake::ake_initiate;
+#[cfg(feature = "ml-kem")]
+pub use rotation::rotate_kyber_with_rng;
+#[cfg(feature = "ml-dsa")]
+pub use rotation::rotate_dilithium_with_rng;
+#[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+pub use rotation::rotate_kyber;
+#[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+pub use rotation::rotate_dilithium;
+#[cfg(all(feature = "mlock", unix))]
+pub use locked::LockedSecret;
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+pub use monitor::MonitoredCrypto;
+pub use operational::{FipsModule, OperationalHandle};
+pub use indicator::{ApprovedResult, FipsIndicator};
+#[cfg(feature = "aes-gcm")]
+pub use gcm_context::{AesGcmContext, NonceReuseDetector};
+#[cfg(all(feature = "alloc", feature = "ml-dsa"))]
+pub use envelope::SignedMessage;
 
-#[cfg(feature = "fips_140_3")]
-pub use csp::{CspExportPolicy, get_csp_export_policy};
+pub use csp::{CspExportPolicy, SecretBox, SecretKeyGuard, get_csp_export_policy, leaked_secret_count};
 
 // === Constants ===
 pub const ML_KEM_1024_PK_BYTES: usize = 1568;
@@ -44,70 +204,385 @@ pub const ML_KEM_1024_CT_BYTES: usize = 1568;
 pub const ML_KEM_1024_SS_BYTES: usize = 32;
 pub const ML_KEM_KEYGEN_SEED_BYTES: usize = 64;
 pub const ML_KEM_ENCAP_SEED_BYTES: usize = 32;
+/// Offset of `ek` inside an ML-KEM-1024 `dk` (FIPS 203: dk_PKE is 384 * k bytes)
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+pub(crate) const ML_KEM_1024_SK_EK_OFFSET: usize = 384 * 4;
 
 pub const ML_DSA_65_PK_BYTES: usize = 1952;
 pub const ML_DSA_65_SK_BYTES: usize = 4032; // Note: libcrux uses 4032, not 4000
 pub const ML_DSA_65_SIG_BYTES: usize = 3309; // Note: libcrux uses 3309, not 3293
 pub const ML_DSA_KEYGEN_SEED_BYTES: usize = 32; // ML-DSA uses 32-byte seed
 pub const ML_DSA_SIGN_SEED_BYTES: usize = 32;
+pub const ML_DSA_MAX_CONTEXT_BYTES: usize = 255; // FIPS 204 context string limit
 
 #[cfg(feature = "aes-gcm")]
 pub const AES_KEY_BYTES: usize = 32;
 #[cfg(feature = "aes-gcm")]
 pub const AES_NONCE_BYTES: usize = 12;
+#[cfg(feature = "aes-gcm")]
+pub const AES_TAG_BYTES: usize = 16;
+/// Longest plaintext GCM allows under one (key, nonce): 2^39 - 256 bits
+/// (SP 800-38D §5.2.1.1), i.e. 68,719,476,704 bytes
+#[cfg(feature = "aes-gcm")]
+pub const AES_GCM_MAX_PLAINTEXT_BYTES: u64 = (1 << 36) - 32;
+
+// === Byte Wrapper Types ===
+
+/// Newtype over a fixed-size libcrux key, ciphertext or signature type
+///
+/// Owning the type lets the crate implement std traits on it; the raw bytes
+/// are reachable through `as_slice()`, `From<[u8; N]>` and `TryFrom<&[u8]>`.
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+macro_rules! impl_byte_wrapper {
+    ($name:ident, $len:expr, $ctor:path) => {
+        impl_byte_wrapper!($name, $len, $ctor, InvalidKeyLength);
+    };
+    ($name:ident, $len:expr, $ctor:path, $len_err:ident) => {
+        impl $name {
+            /// Raw byte view
+            pub fn as_slice(&self) -> &[u8] {
+                self.0.as_slice()
+            }
+
+            /// Parse untrusted bytes; a length mismatch returns the type's
+            /// length error (`InvalidKeyLength`, `InvalidCiphertext` for ciphertexts)
+            pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+                Self::try_from(bytes)
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                Self($ctor(bytes))
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = PqcError;
+
+            /// Strict length check
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                let mut array: [u8; $len] =
+                    bytes.try_into().map_err(|_| PqcError::$len_err)?;
+                let value = Self::from(array);
+                zeroize::Zeroize::zeroize(&mut array);
+                Ok(value)
+            }
+        }
+
+        impl Clone for $name {
+            fn clone(&self) -> Self {
+                let mut bytes = [0u8; $len];
+                bytes.copy_from_slice(self.as_slice());
+                let cloned = Self::from(bytes);
+                zeroize::Zeroize::zeroize(&mut bytes);
+                cloned
+            }
+        }
+    };
+}
+
+/// Owned fixed-size copy of a wrapper's bytes
+///
+/// Public values get a plain array; `secret` wrappers get a
+/// `Zeroizing` array so the copy is wiped when dropped.
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+macro_rules! impl_to_array {
+    ($name:ident, $len:expr) => {
+        impl $name {
+            /// Copy the encoding into a fixed-size array
+            pub fn to_array(&self) -> [u8; $len] {
+                let mut bytes = [0u8; $len];
+                bytes.copy_from_slice(self.as_slice());
+                bytes
+            }
+        }
+    };
+    ($name:ident, $len:expr, secret) => {
+        impl $name {
+            /// Copy the encoding into a fixed-size array, wiped on drop
+            pub fn to_array(&self) -> zeroize::Zeroizing<[u8; $len]> {
+                let mut bytes = zeroize::Zeroizing::new([0u8; $len]);
+                bytes.copy_from_slice(self.as_slice());
+                bytes
+            }
+        }
+    };
+}
+
+/// Ordinary (variable-time) equality and `AsRef<[u8]>` for public values
+///
+/// Public keys, ciphertexts and signatures are not secret, so a short-circuit
+/// compare is fine, and handing their bytes to any `impl AsRef<[u8]>`
+/// consumer (hashers, writers, base64 encoders) is harmless. Secret keys
+/// deliberately have neither; use [`ct::secret_key_eq`] and `as_slice()`.
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+macro_rules! impl_public_value {
+    ($name:ident) => {
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.as_slice()
+            }
+        }
+    };
+}
+
+/// `Hash` and byte-wise `Ord` for public keys, so they can key a
+/// `HashSet`/`BTreeMap` of trusted keys
+///
+/// Like [`impl_public_value`] these are variable-time and therefore never
+/// implemented for secret keys.
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+macro_rules! impl_public_key_ord {
+    ($name:ident) => {
+        impl core::hash::Hash for $name {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.as_slice().hash(state);
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.as_slice().cmp(other.as_slice())
+            }
+        }
+    };
+}
 
 // === ML-KEM (Kyber) Types ===
 #[cfg(feature = "ml-kem")]
 use libcrux_ml_kem::mlkem1024::{
     MlKem1024Ciphertext, MlKem1024PrivateKey, MlKem1024PublicKey,
-    generate_key_pair, encapsulate, decapsulate,
+    generate_key_pair, encapsulate, decapsulate, validate_public_key as kem_validate_public_key,
+    validate_private_key as kem_validate_private_key,
 };
 
+/// ML-KEM-1024 public (encapsulation) key
+///
+/// `Hash` and `Ord` follow the encoded bytes. [`KyberSecretKey`] has
+/// neither: variable-time comparison of secret keys would leak timing.
+#[cfg(feature = "ml-kem")]
+pub struct KyberPublicKey(pub(crate) MlKem1024PublicKey);
+#[cfg(feature = "ml-kem")]
+impl_byte_wrapper!(KyberPublicKey, ML_KEM_1024_PK_BYTES, MlKem1024PublicKey::from);
+#[cfg(feature = "ml-kem")]
+impl_to_array!(KyberPublicKey, ML_KEM_1024_PK_BYTES);
+#[cfg(feature = "ml-kem")]
+impl_public_value!(KyberPublicKey);
+#[cfg(feature = "ml-kem")]
+impl_public_key_ord!(KyberPublicKey);
+
+/// ML-KEM-1024 secret (decapsulation) key
+#[cfg(feature = "ml-kem")]
+pub struct KyberSecretKey(pub(crate) MlKem1024PrivateKey);
+#[cfg(feature = "ml-kem")]
+impl_byte_wrapper!(KyberSecretKey, ML_KEM_1024_SK_BYTES, MlKem1024PrivateKey::from);
+#[cfg(feature = "ml-kem")]
+impl_to_array!(KyberSecretKey, ML_KEM_1024_SK_BYTES, secret);
+
+#[cfg(feature = "ml-kem")]
+impl zeroize::Zeroize for KyberSecretKey {
+    fn zeroize(&mut self) {
+        self.0[..ML_KEM_1024_SK_BYTES].zeroize();
+    }
+}
+
+/// Wipes the key bytes, including keys derived inside the KATs and PCTs
+#[cfg(feature = "ml-kem")]
+impl Drop for KyberSecretKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+#[cfg(feature = "ml-kem")]
+impl zeroize::ZeroizeOnDrop for KyberSecretKey {}
+
+/// ML-KEM-1024 ciphertext
+#[cfg(feature = "ml-kem")]
+pub struct KyberCiphertext(pub(crate) MlKem1024Ciphertext);
+#[cfg(feature = "ml-kem")]
+impl_byte_wrapper!(KyberCiphertext, ML_KEM_1024_CT_BYTES, MlKem1024Ciphertext::from, InvalidCiphertext);
+#[cfg(feature = "ml-kem")]
+impl_to_array!(KyberCiphertext, ML_KEM_1024_CT_BYTES);
+#[cfg(feature = "ml-kem")]
+impl_public_value!(KyberCiphertext);
+
+/// ML-KEM-1024 shared secret
+///
+/// `==` and [`subtle::ConstantTimeEq`] compare in constant time, the bytes
+/// are zeroized on drop, and it derefs to `&[u8]` for KDF input. `.into()`
+/// converts to and from a bare `[u8; 32]`.
+#[cfg(feature = "ml-kem")]
+#[derive(Clone)]
+pub struct SharedSecret([u8; ML_KEM_1024_SS_BYTES]);
+
+/// Former name of [`SharedSecret`], kept for existing callers
+#[cfg(feature = "ml-kem")]
+pub type KyberSharedSecret = SharedSecret;
+
+#[cfg(feature = "ml-kem")]
+impl SharedSecret {
+    /// Raw 32-byte view
+    pub fn as_bytes(&self) -> &[u8; ML_KEM_1024_SS_BYTES] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+impl From<[u8; ML_KEM_1024_SS_BYTES]> for SharedSecret {
+    fn from(bytes: [u8; ML_KEM_1024_SS_BYTES]) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+impl From<SharedSecret> for [u8; ML_KEM_1024_SS_BYTES] {
+    fn from(ss: SharedSecret) -> Self {
+        ss.0
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+impl core::ops::Deref for SharedSecret {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+impl subtle::ConstantTimeEq for SharedSecret {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// Constant-time, via [`subtle::ConstantTimeEq`]
+#[cfg(feature = "ml-kem")]
+impl PartialEq for SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self, other).into()
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+impl Eq for SharedSecret {}
+
+/// Redacted: never prints the secret bytes
+#[cfg(feature = "ml-kem")]
+impl core::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SharedSecret(..)")
+    }
+}
+
 #[cfg(feature = "ml-kem")]
-pub type KyberPublicKey = MlKem1024PublicKey;
+impl zeroize::Zeroize for SharedSecret {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 #[cfg(feature = "ml-kem")]
-pub type KyberSecretKey = MlKem1024PrivateKey;
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
 #[cfg(feature = "ml-kem")]
-pub type KyberCiphertext = MlKem1024Ciphertext;
+impl zeroize::ZeroizeOnDrop for SharedSecret {}
+
+/// Encapsulation randomness for the debug-build keygen round-trip check
 #[cfg(feature = "ml-kem")]
-pub type KyberSharedSecret = [u8; 32]; // ML-KEM shared secret is 32 bytes
+const DEBUG_INVARIANT_RANDOMNESS: [u8; ML_KEM_ENCAP_SEED_BYTES] = [0x5a; ML_KEM_ENCAP_SEED_BYTES];
 
 /// Kyber key pair wrapper
 #[cfg(feature = "ml-kem")]
 pub struct KyberKeys {
     pub pk: KyberPublicKey,
-    pub sk: KyberSecretKey,
+    pub sk: SecretBox<KyberSecretKey>,
 }
 
 #[cfg(feature = "ml-kem")]
 impl KyberKeys {
     /// Generate a new Kyber key pair (requires std feature)
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
     pub fn generate_key_pair() -> Self {
         let seed = rng::generate_seed_64();
         Self::generate_key_pair_with_seed(seed)
     }
 
+    /// Generate a new Kyber key pair from a caller-supplied entropy source
+    pub fn generate_key_pair_with_rng<R: SeedSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        let mut seed = rng::seed_64_from(rng)?;
+        let keys = Self::generate_key_pair_with_seed(seed);
+        zeroize::Zeroize::zeroize(&mut seed);
+        Ok(keys)
+    }
+
     /// Generate key pair from provided seed
+    ///
+    /// `seed` is `d || z` (FIPS 203 ML-KEM.KeyGen_internal): bytes 0..32 are
+    /// `d`, bytes 32..64 are `z`, each taken as a byte string with no
+    /// endianness conversion. Concatenating the ACVP `d` and `z` hex fields,
+    /// in that order, gives the seed for a keyGen vector.
     pub fn generate_key_pair_with_seed(seed: [u8; ML_KEM_KEYGEN_SEED_BYTES]) -> Self {
         rng::validate_seed_64(&seed);
-        let _secure = rng::SecureSeed(seed);
-        let keypair = generate_key_pair(seed);
+        // `seed` is this function's own copy; wipe it on every exit path
+        let seed = zeroize::Zeroizing::new(seed);
+        let keypair = generate_key_pair(*seed);
         // Clone returns arrays, use .into() to convert to wrapper types
-        Self { 
-            pk: keypair.pk().clone().into(),
-            sk: keypair.sk().clone().into(),
-        }
+        let keys = Self {
+            pk: (*keypair.pk()).into(),
+            sk: SecretBox::new((*keypair.sk()).into()),
+        };
+        debug_invariant!(
+            invariants::kem_roundtrip(&keys.pk, &keys.sk, DEBUG_INVARIANT_RANDOMNESS),
+            "fresh ML-KEM key pair round-trips"
+        );
+        keys
     }
 
     /// Generate key pair with PCT validation (FIPS mode)
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
     pub fn generate_key_pair_with_pct() -> Result<Self> {
         let keys = Self::generate_key_pair();
         pct::kyber_pct(&keys)?;
         Ok(keys)
     }
 
+    /// Generate key pair from `rng` with PCT validation (no `std` needed)
+    ///
+    /// Seed and PCT randomness both come from `rng`.
+    pub fn generate_key_pair_with_rng_and_pct<R: SeedSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        let keys = Self::generate_key_pair_with_rng(rng)?;
+        pct::kyber_pct_with_rng(&keys, rng)?;
+        Ok(keys)
+    }
+
     /// Generate key pair with seed and PCT validation
     pub fn generate_key_pair_with_seed_and_pct(
         seed: [u8; ML_KEM_KEYGEN_SEED_BYTES]
@@ -118,6 +593,83 @@ impl KyberKeys {
     }
 }
 
+/// Magic, version and algorithm id that prefix a serialized key pair
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+const KEY_BLOB_MAGIC: [u8; 4] = *b"PQCK";
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+const KEY_BLOB_VERSION: u8 = 1;
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+const KEY_BLOB_ALG_ML_KEM_1024: u8 = 0x01;
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+const KEY_BLOB_HEADER_BYTES: usize = 6;
+
+#[cfg(all(feature = "ml-kem", feature = "alloc"))]
+impl KyberKeys {
+    /// Serialize as `"PQCK" || version || alg_id || pk || sk`
+    ///
+    /// The blob contains the plaintext secret key, so it is subject to the
+    /// CSP export policy (`CspExportBlocked` in FIPS mode) and is wiped on drop.
+    pub fn to_bytes(&self) -> Result<zeroize::Zeroizing<alloc::vec::Vec<u8>>> {
+        csp::check_csp_export_allowed()?;
+        let mut blob = zeroize::Zeroizing::new(alloc::vec::Vec::with_capacity(
+            KEY_BLOB_HEADER_BYTES + ML_KEM_1024_PK_BYTES + ML_KEM_1024_SK_BYTES,
+        ));
+        blob.extend_from_slice(&KEY_BLOB_MAGIC);
+        blob.push(KEY_BLOB_VERSION);
+        blob.push(KEY_BLOB_ALG_ML_KEM_1024);
+        blob.extend_from_slice(self.pk.as_slice());
+        blob.extend_from_slice(self.sk.as_slice());
+        Ok(blob)
+    }
+
+    /// Parse a blob written by [`KyberKeys::to_bytes`]
+    ///
+    /// Returns `InvalidEncoding` for a bad magic, unknown version, another
+    /// algorithm's blob, or a public key that does not match the one
+    /// embedded in the secret key; `InvalidKeyLength` for a truncated or
+    /// oversized blob.
+    pub fn from_bytes(blob: &[u8]) -> Result<Self> {
+        if blob.len() < KEY_BLOB_HEADER_BYTES {
+            return Err(PqcError::InvalidKeyLength);
+        }
+        let (header, body) = blob.split_at(KEY_BLOB_HEADER_BYTES);
+        if header[..4] != KEY_BLOB_MAGIC
+            || header[4] != KEY_BLOB_VERSION
+            || header[5] != KEY_BLOB_ALG_ML_KEM_1024
+        {
+            return Err(PqcError::InvalidEncoding);
+        }
+        if body.len() != ML_KEM_1024_PK_BYTES + ML_KEM_1024_SK_BYTES {
+            return Err(PqcError::InvalidKeyLength);
+        }
+        let (pk, sk) = body.split_at(ML_KEM_1024_PK_BYTES);
+        // FIPS 203 dk layout: dk_PKE || ek || H(ek) || z
+        let embedded_pk = &sk[ML_KEM_1024_SK_EK_OFFSET..ML_KEM_1024_SK_EK_OFFSET + ML_KEM_1024_PK_BYTES];
+        if pk != embedded_pk {
+            return Err(PqcError::InvalidEncoding);
+        }
+        Ok(Self {
+            pk: KyberPublicKey::try_from(pk)?,
+            sk: SecretBox::new(KyberSecretKey::try_from(sk)?),
+        })
+    }
+}
+
+/// Reload a persisted Kyber key pair and confirm the halves belong together
+///
+/// Both byte strings must have the exact ML-KEM-1024 lengths
+/// (`InvalidKeyLength` otherwise). The pair is then checked with
+/// [`pct::kyber_pct`]; a mismatch returns `PairwiseConsistencyTestFailure`.
+#[cfg(feature = "ml-kem")]
+pub fn import_kyber_keypair(pk_bytes: &[u8], sk_bytes: &[u8]) -> Result<KyberKeys> {
+    let keys = KyberKeys {
+        pk: KyberPublicKey::try_from(pk_bytes)?,
+        sk: SecretBox::new(KyberSecretKey::try_from(sk_bytes)?),
+    };
+    pct::kyber_pct(&keys)?;
+    Ok(keys)
+}
+
 // === ML-DSA (Dilithium) Types ===
 #[cfg(feature = "ml-dsa")]
 use libcrux_ml_dsa::ml_dsa_65::{
@@ -127,16 +679,59 @@ use libcrux_ml_dsa::ml_dsa_65::{
     verify as dsa_verify,
 };
 
+/// ML-DSA-65 public (verification) key
+///
+/// `Hash` and `Ord` follow the encoded bytes. [`DilithiumSecretKey`] has
+/// neither: variable-time comparison of secret keys would leak timing.
+#[cfg(feature = "ml-dsa")]
+pub struct DilithiumPublicKey(pub(crate) MLDSA65VerificationKey);
+#[cfg(feature = "ml-dsa")]
+impl_byte_wrapper!(DilithiumPublicKey, ML_DSA_65_PK_BYTES, MLDSA65VerificationKey::new);
+#[cfg(feature = "ml-dsa")]
+impl_to_array!(DilithiumPublicKey, ML_DSA_65_PK_BYTES);
+#[cfg(feature = "ml-dsa")]
+impl_public_value!(DilithiumPublicKey);
+#[cfg(feature = "ml-dsa")]
+impl_public_key_ord!(DilithiumPublicKey);
+
+/// ML-DSA-65 secret (signing) key
+#[cfg(feature = "ml-dsa")]
+pub struct DilithiumSecretKey(pub(crate) MLDSA65SigningKey);
+#[cfg(feature = "ml-dsa")]
+impl_byte_wrapper!(DilithiumSecretKey, ML_DSA_65_SK_BYTES, MLDSA65SigningKey::new);
+#[cfg(feature = "ml-dsa")]
+impl_to_array!(DilithiumSecretKey, ML_DSA_65_SK_BYTES, secret);
+
+#[cfg(feature = "ml-dsa")]
+impl zeroize::Zeroize for DilithiumSecretKey {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
+
+/// Wipes the key bytes, including keys derived inside the KATs and PCTs
+#[cfg(feature = "ml-dsa")]
+impl Drop for DilithiumSecretKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+#[cfg(feature = "ml-dsa")]
+impl zeroize::ZeroizeOnDrop for DilithiumSecretKey {}
+
+/// ML-DSA-65 signature
+#[cfg(feature = "ml-dsa")]
+pub struct DilithiumSignature(pub(crate) MLDSA65Signature);
 #[cfg(feature = "ml-dsa")]
-pub type DilithiumPublicKey = MLDSA65VerificationKey;
+impl_byte_wrapper!(DilithiumSignature, ML_DSA_65_SIG_BYTES, MLDSA65Signature::new);
 #[cfg(feature = "ml-dsa")]
-pub type DilithiumSecretKey = MLDSA65SigningKey;
+impl_to_array!(DilithiumSignature, ML_DSA_65_SIG_BYTES);
 #[cfg(feature = "ml-dsa")]
-pub type DilithiumSignature = MLDSA65Signature;
+impl_public_value!(DilithiumSignature);
 
 // === ML-KEM Functions ===
 
-#[cfg(feature = "ml-kem")]
+#[cfg(all(feature = "ml-kem", not(feature = "explicit-rng")))]
 pub fn encapsulate_shared_secret(
     _pk: &KyberPublicKey
 ) -> (KyberCiphertext, KyberSharedSecret) {
@@ -151,14 +746,130 @@ pub fn encapsulate_shared_secret(
     }
 }
 
+/// Encapsulate with randomness drawn from a caller-supplied entropy source
+///
+/// With `std`, `rng` can be any `rand` `CryptoRng`, e.g.
+/// `&mut rand::thread_rng()`.
+#[cfg(feature = "ml-kem")]
+pub fn encapsulate_shared_secret_with_rng<R: SeedSource + ?Sized>(
+    pk: &KyberPublicKey,
+    rng: &mut R,
+) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+    let mut randomness = rng::seed_32_from(rng)?;
+    let result = encapsulate_shared_secret_with_randomness(pk, randomness);
+    zeroize::Zeroize::zeroize(&mut randomness);
+    Ok(result)
+}
+
+/// Encapsulate with caller-supplied randomness `m`
+///
+/// `randomness` is taken by value, so it is a copy: this function wipes its
+/// own copy before returning, but the caller's original stays on the
+/// caller's stack. Use [`encapsulate_shared_secret_with_randomness_mut`] to
+/// have the caller's buffer wiped as well.
 #[cfg(feature = "ml-kem")]
 pub fn encapsulate_shared_secret_with_randomness(
     pk: &KyberPublicKey,
     randomness: [u8; ML_KEM_ENCAP_SEED_BYTES]
 ) -> (KyberCiphertext, KyberSharedSecret) {
     rng::validate_seed_32(&randomness);
-    let _secure = rng::SecureSeed32(randomness);
-    encapsulate(pk, randomness)
+    let randomness = zeroize::Zeroizing::new(randomness);
+    let (ct, ss) = encapsulate(&pk.0, *randomness);
+    (KyberCiphertext(ct), SharedSecret(ss))
+}
+
+/// [`encapsulate_shared_secret_with_randomness`] that zeroizes `randomness`
+/// in place after use
+#[cfg(feature = "ml-kem")]
+pub fn encapsulate_shared_secret_with_randomness_mut(
+    pk: &KyberPublicKey,
+    randomness: &mut [u8; ML_KEM_ENCAP_SEED_BYTES]
+) -> (KyberCiphertext, KyberSharedSecret) {
+    let result = encapsulate_shared_secret_with_randomness(pk, *randomness);
+    zeroize::Zeroize::zeroize(randomness);
+    result
+}
+
+/// Encapsulate with OS randomness, writing the shared secret into `out`
+#[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+pub fn encapsulate_into(
+    pk: &KyberPublicKey,
+    out: &mut [u8; ML_KEM_1024_SS_BYTES]
+) -> KyberCiphertext {
+    let mut randomness = rng::generate_seed_32();
+    encapsulate_into_with_randomness(pk, &mut randomness, out)
+}
+
+/// [`encapsulate_into`] with randomness drawn from `rng`
+#[cfg(feature = "ml-kem")]
+pub fn encapsulate_into_with_rng<R: SeedSource + ?Sized>(
+    pk: &KyberPublicKey,
+    rng: &mut R,
+    out: &mut [u8; ML_KEM_1024_SS_BYTES]
+) -> Result<KyberCiphertext> {
+    let mut randomness = rng::seed_32_from(rng)?;
+    Ok(encapsulate_into_with_randomness(pk, &mut randomness, out))
+}
+
+/// Encapsulate, writing the shared secret into the caller's buffer
+///
+/// Unlike [`encapsulate_shared_secret_with_randomness_mut`] no
+/// [`SharedSecret`] is returned by value: the only copy that outlives the
+/// call is `out`, which the caller owns and can keep in a zeroizing
+/// wrapper. `randomness` is zeroized in place.
+#[cfg(feature = "ml-kem")]
+pub fn encapsulate_into_with_randomness(
+    pk: &KyberPublicKey,
+    randomness: &mut [u8; ML_KEM_ENCAP_SEED_BYTES],
+    out: &mut [u8; ML_KEM_1024_SS_BYTES]
+) -> KyberCiphertext {
+    rng::validate_seed_32(randomness);
+    let (ct, mut ss) = encapsulate(&pk.0, *randomness);
+    zeroize::Zeroize::zeroize(randomness);
+    out.copy_from_slice(&ss);
+    zeroize::Zeroize::zeroize(&mut ss);
+    KyberCiphertext(ct)
+}
+
+/// FIPS 203 §7.2 encapsulation key check (modulus check)
+///
+/// Returns `false` if any encoded coefficient is not reduced mod q. Run this
+/// on every public key received from outside the module.
+#[cfg(feature = "ml-kem")]
+pub fn validate_public_key(pk: &KyberPublicKey) -> bool {
+    kem_validate_public_key(&pk.0)
+}
+
+/// Encapsulate to an untrusted public key
+///
+/// Unlike [`encapsulate_shared_secret`], the key is validated first and a
+/// malformed key yields `Err(PqcError::InvalidPublicKey)`. Use this for keys
+/// received over the wire.
+#[cfg(all(feature = "ml-kem", not(feature = "explicit-rng")))]
+pub fn try_encapsulate_shared_secret(
+    pk: &KyberPublicKey
+) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+    #[cfg(feature = "std")]
+    {
+        let randomness = rng::generate_seed_32();
+        try_encapsulate_shared_secret_with_randomness(pk, randomness)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = pk;
+        panic!("try_encapsulate_shared_secret requires std feature or use try_encapsulate_shared_secret_with_randomness");
+    }
+}
+
+#[cfg(feature = "ml-kem")]
+pub fn try_encapsulate_shared_secret_with_randomness(
+    pk: &KyberPublicKey,
+    randomness: [u8; ML_KEM_ENCAP_SEED_BYTES]
+) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+    if !validate_public_key(pk) {
+        return Err(PqcError::InvalidPublicKey);
+    }
+    Ok(encapsulate_shared_secret_with_randomness(pk, randomness))
 }
 
 #[cfg(feature = "ml-kem")]
@@ -166,12 +877,97 @@ pub fn decapsulate_shared_secret(
     sk: &KyberSecretKey,
     ct: &KyberCiphertext
 ) -> KyberSharedSecret {
-    decapsulate(sk, ct)
+    let ss = SharedSecret(decapsulate(&sk.0, &ct.0));
+    // Probability 2^-256 for a working backend, real or implicit rejection
+    debug_invariant!(ss.0 != [0u8; ML_KEM_1024_SS_BYTES], "decapsulation output is not all zero");
+    ss
+}
+
+/// Decapsulate, writing the shared secret into the caller's buffer
+///
+/// Same result as [`decapsulate_shared_secret`] without a [`SharedSecret`]
+/// returned by value; the backend's temporary is wiped before returning.
+#[cfg(feature = "ml-kem")]
+pub fn decapsulate_into(
+    sk: &KyberSecretKey,
+    ct: &KyberCiphertext,
+    out: &mut [u8; ML_KEM_1024_SS_BYTES]
+) {
+    let mut ss = decapsulate(&sk.0, &ct.0);
+    out.copy_from_slice(&ss);
+    zeroize::Zeroize::zeroize(&mut ss);
+}
+
+/// Decapsulate after the FIPS 203 §7.3 input checks
+///
+/// [`decapsulate_shared_secret`] never fails: ML-KEM answers a bad ciphertext
+/// with an implicit-rejection key. This variant first runs the §7.3 checks
+/// (ciphertext type check and the decapsulation key hash check
+/// `H(ek) == h`) and returns `DecapsulationFailure` if either fails.
+/// Ciphertexts of the wrong length are already rejected when parsed with
+/// `KyberCiphertext::try_from`.
+#[cfg(feature = "ml-kem")]
+pub fn try_decapsulate_shared_secret(
+    sk: &KyberSecretKey,
+    ct: &KyberCiphertext
+) -> Result<KyberSharedSecret> {
+    if !kem_validate_private_key(&sk.0, &ct.0) {
+        return Err(PqcError::DecapsulationFailure);
+    }
+    Ok(SharedSecret(decapsulate(&sk.0, &ct.0)))
+}
+
+/// Whether [`decapsulate_with_status`] took the implicit-rejection path
+#[cfg(feature = "ml-kem")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecapStatus {
+    /// The ciphertext re-encrypted correctly; the secret is the sender's
+    Valid,
+    /// The re-encryption check failed; the secret is `J(z || c)`
+    ImplicitlyRejected,
+}
+
+/// Decapsulate and report whether implicit rejection occurred
+///
+/// The shared secret is returned in both cases, exactly as
+/// [`decapsulate_shared_secret`] returns it (FIPS 203 §6.3). The status comes
+/// from the same re-encryption check: ML-KEM returns the rejection key
+/// `K̄ = J(z || c)` if and only if the re-encrypted ciphertext differs from
+/// `ct`, so the secret is compared in constant time against `K̄` recomputed
+/// from `z`, the last 32 bytes of `sk`.
+///
+/// Only the final status is data-dependent. It is meant for debugging and for
+/// protocols that must report failures; do not branch on it in code whose
+/// timing an attacker can observe, which would recreate the decryption-failure
+/// oracle implicit rejection exists to remove.
+#[cfg(feature = "ml-kem")]
+pub fn decapsulate_with_status(
+    sk: &KyberSecretKey,
+    ct: &KyberCiphertext
+) -> (KyberSharedSecret, DecapStatus) {
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+    use subtle::ConstantTimeEq;
+
+    let ss = decapsulate_shared_secret(sk, ct);
+
+    let z = &sk.as_slice()[ML_KEM_1024_SK_BYTES - ML_KEM_1024_SS_BYTES..];
+    let mut rejection_key = zeroize::Zeroizing::new([0u8; ML_KEM_1024_SS_BYTES]);
+    let mut j = sha3::Shake256::default();
+    j.update(z);
+    j.update(ct.as_slice());
+    j.finalize_xof().read(&mut *rejection_key);
+
+    let status = if bool::from(ss.as_bytes().ct_eq(&*rejection_key)) {
+        DecapStatus::ImplicitlyRejected
+    } else {
+        DecapStatus::Valid
+    };
+    (ss, status)
 }
 
 // === ML-DSA Functions ===
 
-#[cfg(feature = "ml-dsa")]
+#[cfg(all(feature = "ml-dsa", not(feature = "explicit-rng")))]
 pub fn generate_dilithium_keypair() -> (DilithiumPublicKey, DilithiumSecretKey) {
     #[cfg(feature = "std")]
     {
@@ -184,26 +980,141 @@ pub fn generate_dilithium_keypair() -> (DilithiumPublicKey, DilithiumSecretKey)
     }
 }
 
+/// Generate an ML-DSA-65 key pair from a caller-supplied entropy source
+#[cfg(feature = "ml-dsa")]
+pub fn generate_dilithium_keypair_with_rng<R: SeedSource + ?Sized>(
+    rng: &mut R,
+) -> Result<(DilithiumPublicKey, DilithiumSecretKey)> {
+    let mut seed = rng::seed_32_from(rng)?;
+    let keypair = generate_dilithium_keypair_with_seed(seed);
+    zeroize::Zeroize::zeroize(&mut seed);
+    Ok(keypair)
+}
+
+/// Generate an ML-DSA-65 key pair from the 32-byte seed `xi`
+///
+/// `seed` is used as a byte string, exactly as the ACVP keyGen `seed` hex
+/// field spells it (FIPS 204 ML-DSA.KeyGen_internal).
 #[cfg(feature = "ml-dsa")]
 pub fn generate_dilithium_keypair_with_seed(
     seed: [u8; ML_DSA_KEYGEN_SEED_BYTES]
 ) -> (DilithiumPublicKey, DilithiumSecretKey) {
     rng::validate_seed_32(&seed);
-    let _secure = rng::SecureSeed32(seed);
-    let keypair = dsa_generate_key_pair(seed);
+    let seed = zeroize::Zeroizing::new(seed);
+    let keypair = dsa_generate_key_pair(*seed);
     // ML-DSA keypair fields are public, just clone them directly
     // No need to convert through bytes
-    (keypair.verification_key, keypair.signing_key)
+    (
+        DilithiumPublicKey(keypair.verification_key),
+        DilithiumSecretKey(keypair.signing_key),
+    )
 }
 
-#[cfg(feature = "ml-dsa")]
+#[cfg(all(feature = "ml-dsa", not(feature = "explicit-rng")))]
 pub fn generate_dilithium_keypair_with_pct() -> Result<(DilithiumPublicKey, DilithiumSecretKey)> {
     let (pk, sk) = generate_dilithium_keypair();
     pct::dilithium_pct(&pk, &sk)?;
     Ok((pk, sk))
 }
 
+/// Generate an ML-DSA-65 key pair from `rng` with PCT validation (no `std` needed)
+///
+/// Seed and PCT randomness both come from `rng`.
+#[cfg(feature = "ml-dsa")]
+pub fn generate_dilithium_keypair_with_rng_and_pct<R: SeedSource + ?Sized>(
+    rng: &mut R,
+) -> Result<(DilithiumPublicKey, DilithiumSecretKey)> {
+    let (pk, sk) = generate_dilithium_keypair_with_rng(rng)?;
+    pct::dilithium_pct_with_rng(&pk, &sk, rng)?;
+    Ok((pk, sk))
+}
+
+/// Reload a persisted ML-DSA-65 key pair and confirm the halves belong together
+///
+/// Both byte strings must have the exact ML-DSA-65 lengths
+/// (`InvalidKeyLength` otherwise). The pair is then checked with
+/// [`pct::dilithium_pct`]; a mismatch returns `PairwiseConsistencyTestFailure`.
+#[cfg(feature = "ml-dsa")]
+pub fn import_dilithium_keypair(
+    pk_bytes: &[u8],
+    sk_bytes: &[u8],
+) -> Result<(DilithiumPublicKey, DilithiumSecretKey)> {
+    let pk = DilithiumPublicKey::try_from(pk_bytes)?;
+    let sk = DilithiumSecretKey::try_from(sk_bytes)?;
+    pct::dilithium_pct(&pk, &sk)?;
+    Ok((pk, sk))
+}
+
+/// Dilithium key pair wrapper
+///
+/// Named fields avoid mixing up the halves of the `(pk, sk)` tuple returned
+/// by the free functions. The secret key zeroizes itself on drop.
+#[cfg(feature = "ml-dsa")]
+pub struct DilithiumKeypair {
+    pub pk: DilithiumPublicKey,
+    pub sk: SecretBox<DilithiumSecretKey>,
+}
+
+#[cfg(feature = "ml-dsa")]
+impl DilithiumKeypair {
+    /// Generate a new ML-DSA-65 key pair with OS randomness
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    pub fn generate() -> Self {
+        Self::from(generate_dilithium_keypair())
+    }
+
+    /// Generate a new key pair from a caller-supplied entropy source
+    pub fn generate_with_rng<R: SeedSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        generate_dilithium_keypair_with_rng(rng).map(Self::from)
+    }
+
+    /// Generate key pair from provided seed `xi`
+    pub fn generate_with_seed(seed: [u8; ML_DSA_KEYGEN_SEED_BYTES]) -> Self {
+        Self::from(generate_dilithium_keypair_with_seed(seed))
+    }
+
+    /// Generate key pair with PCT validation (FIPS mode)
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    pub fn generate_with_pct() -> Result<Self> {
+        generate_dilithium_keypair_with_pct().map(Self::from)
+    }
+
+    /// Generate key pair from `rng` with PCT validation (no `std` needed)
+    pub fn generate_with_rng_and_pct<R: SeedSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        generate_dilithium_keypair_with_rng_and_pct(rng).map(Self::from)
+    }
+
+    /// Hedged signature over `msg` with an empty context
+    #[cfg(all(feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    pub fn sign(&self, msg: &[u8]) -> DilithiumSignature {
+        sign_message(&self.sk, msg)
+    }
+
+    /// Hedged signature with randomness drawn from `rng`
+    #[cfg(not(feature = "require-context"))]
+    pub fn sign_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        msg: &[u8],
+        rng: &mut R,
+    ) -> Result<DilithiumSignature> {
+        sign_message_with_rng(&self.sk, msg, rng)
+    }
+
+    /// Verify a signature under this pair's public key
+    #[cfg(not(feature = "require-context"))]
+    pub fn verify(&self, msg: &[u8], sig: &DilithiumSignature) -> bool {
+        verify_signature(&self.pk, msg, sig)
+    }
+}
+
 #[cfg(feature = "ml-dsa")]
+impl From<(DilithiumPublicKey, DilithiumSecretKey)> for DilithiumKeypair {
+    fn from((pk, sk): (DilithiumPublicKey, DilithiumSecretKey)) -> Self {
+        Self { pk, sk: SecretBox::new(sk) }
+    }
+}
+
+#[cfg(all(feature = "ml-dsa", not(feature = "explicit-rng"), not(feature = "require-context")))]
 pub fn sign_message(_sk: &DilithiumSecretKey, _msg: &[u8]) -> DilithiumSignature {
     #[cfg(feature = "std")]
     {
@@ -216,62 +1127,465 @@ pub fn sign_message(_sk: &DilithiumSecretKey, _msg: &[u8]) -> DilithiumSignature
     }
 }
 
-#[cfg(feature = "ml-dsa")]
+/// Sign with hedging randomness drawn from a caller-supplied entropy source
+///
+/// With `std`, `rng` can be any `rand` `CryptoRng`, e.g.
+/// `&mut rand::thread_rng()`.
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+pub fn sign_message_with_rng<R: SeedSource + ?Sized>(
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    rng: &mut R,
+) -> Result<DilithiumSignature> {
+    let mut randomness = rng::seed_32_from(rng)?;
+    let sig = sign_message_with_randomness(sk, msg, randomness);
+    zeroize::Zeroize::zeroize(&mut randomness);
+    Ok(sig)
+}
+
+/// Sign with caller-supplied hedging randomness `rnd`
+///
+/// As with [`encapsulate_shared_secret_with_randomness`], only this
+/// function's by-value copy of `randomness` is wiped; see
+/// [`sign_message_with_randomness_mut`] to wipe the caller's buffer.
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
 pub fn sign_message_with_randomness(
     sk: &DilithiumSecretKey,
     msg: &[u8],
     randomness: [u8; ML_DSA_SIGN_SEED_BYTES]
 ) -> DilithiumSignature {
     rng::validate_seed_32(&randomness);
-    let _secure = rng::SecureSeed32(randomness);
-    // libcrux sign takes: signing_key, message, context, randomness
-    // context is typically empty for standard usage
-    dsa_sign(sk, msg, &[], randomness)
+    sign_with_any_context(sk, msg, &[], randomness)
         .expect("Signing failed - this should not happen with valid keys")
 }
 
+/// [`sign_message_with_randomness`] that zeroizes `randomness` in place after use
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+pub fn sign_message_with_randomness_mut(
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    randomness: &mut [u8; ML_DSA_SIGN_SEED_BYTES]
+) -> DilithiumSignature {
+    let sig = sign_message_with_randomness(sk, msg, *randomness);
+    zeroize::Zeroize::zeroize(randomness);
+    sig
+}
+
+/// Parse an untrusted ML-DSA-65 signature encoding
+///
+/// The length is checked before any bytes are copied, so oversized or
+/// truncated input is rejected with `InvalidKeyLength` in O(1), whatever
+/// its size.
 #[cfg(feature = "ml-dsa")]
+pub fn signature_from_bytes(bytes: &[u8]) -> Result<DilithiumSignature> {
+    DilithiumSignature::try_from(bytes)
+}
+
+/// Verify an ML-DSA-65 signature
+///
+/// A `DilithiumSignature` always holds exactly `ML_DSA_65_SIG_BYTES`; parse
+/// wire input with [`signature_from_bytes`] so wrong-length encodings are
+/// rejected before any verification work starts.
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
 pub fn verify_signature(
     pk: &DilithiumPublicKey,
     msg: &[u8],
     sig: &DilithiumSignature
+) -> bool {
+    verify_with_any_context(pk, msg, &[], sig)
+}
+
+/// Verify a signature held as raw bytes
+///
+/// Returns `InvalidKeyLength` if `sig_bytes` is not `ML_DSA_65_SIG_BYTES`
+/// long (checked before any verification work) and `VerificationFailure`
+/// if the signature does not verify.
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+pub fn verify_signature_bytes(
+    pk: &DilithiumPublicKey,
+    msg: &[u8],
+    sig_bytes: &[u8]
+) -> Result<()> {
+    let sig = signature_from_bytes(sig_bytes)?;
+    if verify_signature(pk, msg, &sig) {
+        Ok(())
+    } else {
+        Err(PqcError::VerificationFailure)
+    }
+}
+
+/// Sign with a FIPS 204 domain-separation context (at most 255 bytes)
+///
+/// With the `require-context` feature an empty `ctx` is `EmptyContext`;
+/// the same holds for every `*_with_context` signing function and
+/// [`sign_message_deterministic`].
+#[cfg(all(feature = "ml-dsa", not(feature = "explicit-rng")))]
+pub fn sign_message_with_context(
+    _sk: &DilithiumSecretKey,
+    _msg: &[u8],
+    _ctx: &[u8]
+) -> Result<DilithiumSignature> {
+    #[cfg(feature = "std")]
+    {
+        let randomness = rng::generate_seed_32();
+        sign_message_with_context_and_randomness(_sk, _msg, _ctx, randomness)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        panic!("sign_message_with_context requires std feature or use sign_message_with_context_and_randomness");
+    }
+}
+
+#[cfg(feature = "ml-dsa")]
+pub fn sign_message_with_context_and_randomness(
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; ML_DSA_SIGN_SEED_BYTES]
+) -> Result<DilithiumSignature> {
+    check_context(ctx)?;
+    rng::check_seed_32(&randomness)?;
+    sign_with_any_context(sk, msg, ctx, randomness)
+}
+
+/// Sign in the FIPS 204 deterministic (non-hedged) mode
+///
+/// Uses an all-zero `rnd` (FIPS 204 Algorithm 2 with the deterministic
+/// variant), which is an approved mode: the same key, message and context
+/// always give byte-identical signatures. Prefer the hedged functions unless
+/// reproducibility is required. Returns `ContextTooLong` if `ctx` exceeds
+/// `ML_DSA_MAX_CONTEXT_BYTES`.
+#[cfg(feature = "ml-dsa")]
+pub fn sign_message_deterministic(
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    ctx: &[u8]
+) -> Result<DilithiumSignature> {
+    check_context(ctx)?;
+    // Zero rnd is the deterministic mode, so it bypasses `validate_seed_32`
+    sign_with_any_context(sk, msg, ctx, [0u8; ML_DSA_SIGN_SEED_BYTES])
+}
+
+/// Verify a signature made with a FIPS 204 domain-separation context
+///
+/// With the `require-context` feature an empty `ctx` never verifies.
+#[cfg(feature = "ml-dsa")]
+pub fn verify_signature_with_context(
+    pk: &DilithiumPublicKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &DilithiumSignature
+) -> bool {
+    check_context(ctx).is_ok() && verify_with_any_context(pk, msg, ctx, sig)
+}
+
+/// Verify, and also insist that `sig` is the canonical encoding
+///
+/// ML-DSA signatures are **not** unique per message: signing is hedged,
+/// so the same key signs the same message to a different signature every
+/// time, and all of them verify. Never use signature bytes as a dedup or
+/// idempotency key; use the message (or its hash) and the public key.
+///
+/// What this function adds is that nobody without the secret key can turn
+/// an accepted signature into different accepted bytes. The hint section
+/// is decoded and re-encoded (FIPS 204 Algorithms 20 and 21), and any
+/// difference from the input, such as unsorted hint indices or non-zero
+/// padding, is `NonCanonicalSignature`, whether or not the backend would
+/// tolerate it. The `z` section needs no such check because its bit
+/// packing maps each encoding to a distinct value. A signature that is
+/// canonical but does not verify is `VerificationFailure`. `ctx` follows
+/// the same rules as [`verify_signature_with_context`].
+#[cfg(feature = "ml-dsa")]
+pub fn verify_strict(
+    pk: &DilithiumPublicKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &DilithiumSignature
+) -> Result<()> {
+    let hint = &sig.as_slice()[ML_DSA_65_SIG_BYTES - ML_DSA_65_HINT_BYTES..];
+    if !hint_is_canonical(hint) {
+        return Err(PqcError::NonCanonicalSignature);
+    }
+    if !verify_signature_with_context(pk, msg, ctx, sig) {
+        return Err(PqcError::VerificationFailure);
+    }
+    Ok(())
+}
+
+/// Hint section of an ML-DSA-65 signature: omega = 55 positions, k = 6 counts
+#[cfg(feature = "ml-dsa")]
+const ML_DSA_65_OMEGA: usize = 55;
+#[cfg(feature = "ml-dsa")]
+const ML_DSA_65_HINT_BYTES: usize = ML_DSA_65_OMEGA + 6;
+
+/// HintBitUnpack followed by HintBitPack reproduces `hint` exactly
+#[cfg(feature = "ml-dsa")]
+fn hint_is_canonical(hint: &[u8]) -> bool {
+    let (positions, counts) = hint.split_at(ML_DSA_65_OMEGA);
+    let mut repacked = [0u8; ML_DSA_65_HINT_BYTES];
+    let mut start = 0usize;
+    for (row, &end) in counts.iter().enumerate() {
+        let end = usize::from(end);
+        if end < start || end > ML_DSA_65_OMEGA {
+            return false;
+        }
+        // Positions within a row must be strictly increasing
+        if positions[start..end].windows(2).any(|pair| pair[0] >= pair[1]) {
+            return false;
+        }
+        repacked[start..end].copy_from_slice(&positions[start..end]);
+        repacked[ML_DSA_65_OMEGA + row] = end as u8;
+        start = end;
+    }
+    // Unused position slots re-encode as zero
+    repacked[..] == *hint
+}
+
+/// `ContextTooLong` past 255 bytes; `EmptyContext` under `require-context`
+#[cfg(feature = "ml-dsa")]
+pub(crate) fn check_context(ctx: &[u8]) -> Result<()> {
+    if ctx.len() > ML_DSA_MAX_CONTEXT_BYTES {
+        return Err(PqcError::ContextTooLong);
+    }
+    if cfg!(feature = "require-context") && ctx.is_empty() {
+        return Err(PqcError::EmptyContext);
+    }
+    Ok(())
+}
+
+/// ML-DSA-65 signing with no context policy applied
+///
+/// Only the length limit is enforced, so the self-tests, which sign with an
+/// empty context, keep working under `require-context`.
+#[cfg(feature = "ml-dsa")]
+pub(crate) fn sign_with_any_context(
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; ML_DSA_SIGN_SEED_BYTES]
+) -> Result<DilithiumSignature> {
+    if ctx.len() > ML_DSA_MAX_CONTEXT_BYTES {
+        return Err(PqcError::ContextTooLong);
+    }
+    let randomness = zeroize::Zeroizing::new(randomness);
+    // libcrux sign takes: signing_key, message, context, randomness
+    let sig = dsa_sign(&sk.0, msg, ctx, *randomness).map_err(|_| PqcError::SigningFailure)?;
+    Ok(DilithiumSignature(sig))
+}
+
+/// Verification counterpart of [`sign_with_any_context`]
+#[cfg(feature = "ml-dsa")]
+pub(crate) fn verify_with_any_context(
+    pk: &DilithiumPublicKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &DilithiumSignature
 ) -> bool {
     // libcrux verify takes: verification_key, message, context, signature
-    dsa_verify(pk, msg, &[], sig).is_ok()
+    dsa_verify(&pk.0, msg, ctx, &sig.0).is_ok()
 }
 
 // === AES-GCM Functions ===
 
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
+use aes_gcm::aead::Aead;
 #[cfg(feature = "aes-gcm")]
 use aes_gcm::{
-    aead::{Aead, KeyInit},
-    Aes256Gcm, Key, Nonce,
+    aead::{AeadInPlace, KeyInit},
+    Aes256Gcm, Key, Nonce, Tag,
 };
 
-#[cfg(feature = "aes-gcm")]
+/// Encrypt to `ciphertext || tag`
+///
+/// Plaintext longer than [`AES_GCM_MAX_PLAINTEXT_BYTES`] is rejected with
+/// `MessageTooLarge` before the cipher runs; the same bound applies to the
+/// body of every ciphertext the decrypt functions accept.
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
 pub fn encrypt_aes_gcm(
     key_bytes: &[u8; AES_KEY_BYTES],
     nonce_bytes: &[u8; AES_NONCE_BYTES],
     plaintext: &[u8],
 ) -> Result<Vec<u8>> {
+    check_gcm_len(plaintext.len())?;
     let key = Key::<Aes256Gcm>::from_slice(key_bytes);
     let cipher = Aes256Gcm::new(key);
     let nonce = Nonce::from_slice(nonce_bytes);
     cipher.encrypt(nonce, plaintext)
-        .map_err(|_| PqcError::AesGcmOperationFailed)
+        .map_err(|_| PqcError::AeadOperationFailed)
 }
 
-#[cfg(feature = "aes-gcm")]
+/// Decrypt `ciphertext || tag`
+///
+/// Input shorter than `AES_TAG_BYTES` cannot hold a tag and is rejected
+/// with `AeadOperationFailed` before the cipher runs, as is a bad tag.
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
 pub fn decrypt_aes_gcm(
     key_bytes: &[u8; AES_KEY_BYTES],
     nonce_bytes: &[u8; AES_NONCE_BYTES],
     ciphertext: &[u8],
 ) -> Result<Vec<u8>> {
+    if ciphertext.len() < AES_TAG_BYTES {
+        return Err(PqcError::AeadOperationFailed);
+    }
+    check_gcm_len(ciphertext.len() - AES_TAG_BYTES)?;
     let key = Key::<Aes256Gcm>::from_slice(key_bytes);
     let cipher = Aes256Gcm::new(key);
     let nonce = Nonce::from_slice(nonce_bytes);
     cipher.decrypt(nonce, ciphertext)
-        .map_err(|_| PqcError::AesGcmOperationFailed)
+        .map_err(|_| PqcError::AeadOperationFailed)
+}
+
+/// Decrypt `ciphertext || tag` where the tag is `tag_len` bytes long
+///
+/// The module's FIPS policy approves only full 16-byte tags; the truncated
+/// 12..=15-byte tags some legacy peers send are accepted only outside
+/// `fips_140_3`. Any other length returns `InvalidTagLength`; a
+/// short input or bad tag returns `AeadOperationFailed`.
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
+pub fn decrypt_aes_gcm_with_tag_len(
+    key_bytes: &[u8; AES_KEY_BYTES],
+    nonce_bytes: &[u8; AES_NONCE_BYTES],
+    ciphertext: &[u8],
+    tag_len: usize,
+) -> Result<Vec<u8>> {
+    use aes_gcm::aead::consts::U16;
+    #[cfg(not(feature = "fips_140_3"))]
+    use aes_gcm::aead::consts::{U12, U13, U14, U15};
+
+    check_gcm_len(ciphertext.len().saturating_sub(tag_len))?;
+    match tag_len {
+        16 => decrypt_aes_gcm_tag::<U16>(key_bytes, nonce_bytes, ciphertext),
+        #[cfg(not(feature = "fips_140_3"))]
+        12 => decrypt_aes_gcm_tag::<U12>(key_bytes, nonce_bytes, ciphertext),
+        #[cfg(not(feature = "fips_140_3"))]
+        13 => decrypt_aes_gcm_tag::<U13>(key_bytes, nonce_bytes, ciphertext),
+        #[cfg(not(feature = "fips_140_3"))]
+        14 => decrypt_aes_gcm_tag::<U14>(key_bytes, nonce_bytes, ciphertext),
+        #[cfg(not(feature = "fips_140_3"))]
+        15 => decrypt_aes_gcm_tag::<U15>(key_bytes, nonce_bytes, ciphertext),
+        _ => Err(PqcError::InvalidTagLength),
+    }
+}
+
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
+fn decrypt_aes_gcm_tag<T: aes_gcm::TagSize>(
+    key_bytes: &[u8; AES_KEY_BYTES],
+    nonce_bytes: &[u8; AES_NONCE_BYTES],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    let cipher = aes_gcm::AesGcm::<aes_gcm::aes::Aes256, aes_gcm::aead::consts::U12, T>::new(
+        Key::<Aes256Gcm>::from_slice(key_bytes),
+    );
+    cipher.decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|_| PqcError::AeadOperationFailed)
+}
+
+/// Encrypt into a caller-provided buffer (no allocation)
+///
+/// Writes `ciphertext || tag` to the front of `out` and returns its length,
+/// `plaintext.len() + AES_TAG_BYTES`. Returns `BufferTooSmall` if `out` is
+/// shorter than that and `MessageTooLarge` if `plaintext` exceeds
+/// [`AES_GCM_MAX_PLAINTEXT_BYTES`].
+#[cfg(feature = "aes-gcm")]
+pub fn encrypt_aes_gcm_into(
+    key_bytes: &[u8; AES_KEY_BYTES],
+    nonce_bytes: &[u8; AES_NONCE_BYTES],
+    plaintext: &[u8],
+    out: &mut [u8],
+) -> Result<usize> {
+    encrypt_aes_gcm_into_with_aad(key_bytes, nonce_bytes, &[], plaintext, out)
+}
+
+/// [`encrypt_aes_gcm_into`] with additional authenticated data
+///
+/// `aad` is authenticated but not encrypted; decryption must supply the
+/// same bytes.
+#[cfg(feature = "aes-gcm")]
+pub fn encrypt_aes_gcm_into_with_aad(
+    key_bytes: &[u8; AES_KEY_BYTES],
+    nonce_bytes: &[u8; AES_NONCE_BYTES],
+    aad: &[u8],
+    plaintext: &[u8],
+    out: &mut [u8],
+) -> Result<usize> {
+    check_gcm_len(plaintext.len())?;
+    let total = plaintext.len()
+        .checked_add(AES_TAG_BYTES)
+        .ok_or(PqcError::BufferTooSmall)?;
+    if out.len() < total {
+        return Err(PqcError::BufferTooSmall);
+    }
+    let (body, rest) = out.split_at_mut(plaintext.len());
+    body.copy_from_slice(plaintext);
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key_bytes));
+    let tag = cipher
+        .encrypt_in_place_detached(Nonce::from_slice(nonce_bytes), aad, body)
+        .map_err(|_| PqcError::AeadOperationFailed)?;
+    rest[..AES_TAG_BYTES].copy_from_slice(&tag);
+    Ok(total)
+}
+
+/// Decrypt into a caller-provided buffer (no allocation)
+///
+/// `ciphertext` is `ciphertext || tag` as produced by [`encrypt_aes_gcm_into`].
+/// Writes the plaintext to the front of `out` and returns its length. On
+/// authentication failure the written bytes are wiped before returning.
+#[cfg(feature = "aes-gcm")]
+pub fn decrypt_aes_gcm_into(
+    key_bytes: &[u8; AES_KEY_BYTES],
+    nonce_bytes: &[u8; AES_NONCE_BYTES],
+    ciphertext: &[u8],
+    out: &mut [u8],
+) -> Result<usize> {
+    decrypt_aes_gcm_into_with_aad(key_bytes, nonce_bytes, &[], ciphertext, out)
+}
+
+/// [`decrypt_aes_gcm_into`] with additional authenticated data
+///
+/// Fails with `AeadOperationFailed` unless `aad` matches the bytes given
+/// to [`encrypt_aes_gcm_into_with_aad`].
+#[cfg(feature = "aes-gcm")]
+pub fn decrypt_aes_gcm_into_with_aad(
+    key_bytes: &[u8; AES_KEY_BYTES],
+    nonce_bytes: &[u8; AES_NONCE_BYTES],
+    aad: &[u8],
+    ciphertext: &[u8],
+    out: &mut [u8],
+) -> Result<usize> {
+    let body_len = ciphertext.len()
+        .checked_sub(AES_TAG_BYTES)
+        .ok_or(PqcError::AeadOperationFailed)?;
+    check_gcm_len(body_len)?;
+    if out.len() < body_len {
+        return Err(PqcError::BufferTooSmall);
+    }
+    let (body, tag) = ciphertext.split_at(body_len);
+    let plaintext = &mut out[..body_len];
+    plaintext.copy_from_slice(body);
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key_bytes));
+    match cipher.decrypt_in_place_detached(
+        Nonce::from_slice(nonce_bytes),
+        aad,
+        plaintext,
+        Tag::from_slice(tag),
+    ) {
+        Ok(()) => Ok(body_len),
+        Err(_) => {
+            zeroize::Zeroize::zeroize(plaintext);
+            Err(PqcError::AeadOperationFailed)
+        }
+    }
+}
+
+/// `MessageTooLarge` past [`AES_GCM_MAX_PLAINTEXT_BYTES`]
+#[cfg(feature = "aes-gcm")]
+fn check_gcm_len(len: usize) -> Result<()> {
+    match u64::try_from(len) {
+        Ok(len) if len <= AES_GCM_MAX_PLAINTEXT_BYTES => Ok(()),
+        _ => Err(PqcError::MessageTooLarge),
+    }
 }
 
 // === Tests ===
@@ -281,18 +1595,95 @@ mod tests {
     use super::*;
 
     #[test]
-    #[cfg(all(feature = "ml-kem", feature = "std"))]
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
     fn test_kyber_roundtrip() {
         let keys = KyberKeys::generate_key_pair();
         let (ct, ss1) = encapsulate_shared_secret(&keys.pk);
         let ss2 = decapsulate_shared_secret(&keys.sk, &ct);
         
-        // Compare shared secrets (they're both [u8; 32])
+        // SharedSecret compares in constant time
         assert_eq!(ss1, ss2);
     }
 
     #[test]
-    #[cfg(all(feature = "ml-dsa", feature = "std"))]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "explicit-rng", not(feature = "require-context")))]
+    fn test_explicit_rng_round_trip() {
+        let mut rng = rng::ShakeRng::new(&[0x5e; 48]).unwrap();
+
+        let keys = KyberKeys::generate_key_pair_with_rng(&mut rng).unwrap();
+        let (ct, ss) = encapsulate_shared_secret_with_rng(&keys.pk, &mut rng).unwrap();
+        assert_eq!(decapsulate_shared_secret(&keys.sk, &ct), ss);
+
+        let (pk, sk) = generate_dilithium_keypair_with_rng(&mut rng).unwrap();
+        let sig = sign_message_with_rng(&sk, b"m", &mut rng).unwrap();
+        assert!(verify_signature(&pk, b"m", &sig));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_to_array_matches_slice() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x6c; 64]);
+        let (ct, _) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x6d; 32]);
+        assert_eq!(&keys.pk.to_array()[..], keys.pk.as_slice());
+        assert_eq!(&keys.sk.to_array()[..], keys.sk.as_slice());
+        assert_eq!(&ct.to_array()[..], ct.as_slice());
+
+        let dsa = DilithiumKeypair::generate_with_seed([0x6e; 32]);
+        let sig = sign_message_with_randomness(&dsa.sk, b"m", [0x6f; 32]);
+        assert!(DilithiumPublicKey::from(dsa.pk.to_array()) == dsa.pk);
+        assert!(ct::secret_key_eq(&DilithiumSecretKey::from(*dsa.sk.to_array()), &dsa.sk));
+        assert!(DilithiumSignature::from(sig.to_array()) == sig);
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+    fn test_secret_keys_zeroize_on_drop() {
+        use core::mem::{size_of, MaybeUninit};
+
+        // Run Drop in place, then read the storage back as raw bytes; the
+        // wrappers hold their key inline, so the storage is the key
+        fn storage_after_drop<T, const N: usize>(value: T) -> [u8; N] {
+            assert_eq!(size_of::<T>(), N);
+            let mut slot = MaybeUninit::new(value);
+            let mut bytes = [0xffu8; N];
+            // SAFETY: `slot` is initialised and dropped exactly once; the
+            // wipe leaves all N bytes written, and they are only copied out
+            // as plain bytes, never used as a `T` again
+            unsafe {
+                slot.assume_init_drop();
+                core::ptr::copy_nonoverlapping(slot.as_ptr().cast::<u8>(), bytes.as_mut_ptr(), N);
+            }
+            bytes
+        }
+
+        let kem = storage_after_drop::<_, ML_KEM_1024_SK_BYTES>(KyberSecretKey::from([0xab; ML_KEM_1024_SK_BYTES]));
+        let dsa = storage_after_drop::<_, ML_DSA_65_SK_BYTES>(DilithiumSecretKey::from([0xcd; ML_DSA_65_SK_BYTES]));
+        assert!(kem.iter().all(|&b| b == 0));
+        assert!(dsa.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_shared_secret_wrapper() {
+        use subtle::ConstantTimeEq;
+
+        let keys = KyberKeys::generate_key_pair_with_seed([0x67; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x68; 32]);
+        let decapsulated = decapsulate_shared_secret(&keys.sk, &ct);
+        assert!(bool::from(ss.ct_eq(&decapsulated)));
+
+        let (_, other) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x69; 32]);
+        assert!(!bool::from(ss.ct_eq(&other)));
+        assert!(ss != other);
+
+        let bytes: [u8; 32] = ss.clone().into();
+        assert_eq!(&bytes[..], &*ss);
+        assert_eq!(SharedSecret::from(bytes), ss);
+        assert_eq!(format!("{:?}", ss), "SharedSecret(..)");
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
     fn test_dilithium_sign_verify() {
         let (pk, sk) = generate_dilithium_keypair();
         let msg = b"test message";
@@ -300,6 +1691,445 @@ mod tests {
         assert!(verify_signature(&pk, msg, &sig));
     }
 
+    #[test]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    fn test_dilithium_context_sign_verify() {
+        let (pk, sk) = generate_dilithium_keypair();
+        let msg = b"test message";
+        let sig = sign_message_with_context(&sk, msg, b"app-v1").unwrap();
+        assert!(verify_signature_with_context(&pk, msg, b"app-v1", &sig));
+        assert!(!verify_signature_with_context(&pk, msg, b"app-v2", &sig));
+        assert!(!verify_signature(&pk, msg, &sig));
+
+        let long_ctx = [0u8; ML_DSA_MAX_CONTEXT_BYTES + 1];
+        assert!(matches!(
+            sign_message_with_context(&sk, msg, &long_ctx),
+            Err(PqcError::ContextTooLong)
+        ));
+        assert!(matches!(
+            sign_message_with_context_and_randomness(&sk, msg, b"app-v1", [0u8; 32]),
+            Err(PqcError::InvalidSeed)
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_randomness_mut_variants_wipe_caller_buffer() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x29; 64]);
+        let mut m = [0x2a; 32];
+        let (ct, ss) = encapsulate_shared_secret_with_randomness_mut(&keys.pk, &mut m);
+        assert_eq!(m, [0u8; 32]);
+        let (expected_ct, expected_ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x2a; 32]);
+        assert!(ct == expected_ct);
+        assert_eq!(ss, expected_ss);
+
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x2b; 32]);
+        let mut rnd = [0x2c; 32];
+        let sig = sign_message_with_randomness_mut(&sk, b"msg", &mut rnd);
+        assert_eq!(rnd, [0u8; 32]);
+        assert!(verify_signature(&pk, b"msg", &sig));
+        assert!(sig == sign_message_with_randomness(&sk, b"msg", [0x2c; 32]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "alloc", not(feature = "fips_140_3")))]
+    fn test_kyber_keys_blob_roundtrip() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x27; 64]);
+        let blob = keys.to_bytes().unwrap();
+        assert_eq!(&blob[..6], b"PQCK\x01\x01");
+        let loaded = KyberKeys::from_bytes(&blob).unwrap();
+        assert!(loaded.pk == keys.pk);
+        assert_eq!(loaded.sk.as_slice(), keys.sk.as_slice());
+
+        let mut wrong_alg = blob.to_vec();
+        wrong_alg[5] = 0x02;
+        assert!(matches!(KyberKeys::from_bytes(&wrong_alg), Err(PqcError::InvalidEncoding)));
+        let mut bad_magic = blob.to_vec();
+        bad_magic[0] ^= 1;
+        assert!(matches!(KyberKeys::from_bytes(&bad_magic), Err(PqcError::InvalidEncoding)));
+        assert!(matches!(KyberKeys::from_bytes(&blob[..blob.len() - 1]), Err(PqcError::InvalidKeyLength)));
+        assert!(matches!(KyberKeys::from_bytes(&blob[..3]), Err(PqcError::InvalidKeyLength)));
+
+        // Public key from another pair
+        let other = KyberKeys::generate_key_pair_with_seed([0x28; 64]);
+        let mut mixed = blob.to_vec();
+        mixed[6..6 + ML_KEM_1024_PK_BYTES].copy_from_slice(other.pk.as_slice());
+        assert!(matches!(KyberKeys::from_bytes(&mixed), Err(PqcError::InvalidEncoding)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "alloc", feature = "fips_140_3"))]
+    fn test_kyber_keys_blob_blocked_in_fips_mode() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x27; 64]);
+        assert!(matches!(keys.to_bytes(), Err(PqcError::CspExportBlocked)));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_ciphertext_from_slice() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x25; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x26; 32]);
+
+        let parsed = KyberCiphertext::from_slice(ct.as_slice()).unwrap();
+        assert_eq!(try_decapsulate_shared_secret(&keys.sk, &parsed), Ok(ss));
+
+        let mut wire = ct.as_slice().to_vec();
+        wire.push(0);
+        assert!(matches!(KyberCiphertext::from_slice(&wire), Err(PqcError::InvalidCiphertext)));
+        assert!(matches!(
+            KyberCiphertext::from_slice(&wire[..ML_KEM_1024_CT_BYTES - 1]),
+            Err(PqcError::InvalidCiphertext)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_try_decapsulate_checks_inputs() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x23; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x24; 32]);
+        assert_eq!(try_decapsulate_shared_secret(&keys.sk, &ct), Ok(ss));
+
+        // Corrupt H(ek), stored after dk_pke and ek in the decapsulation key
+        let mut sk_bytes = keys.sk.to_array();
+        sk_bytes[ML_KEM_1024_SK_EK_OFFSET + ML_KEM_1024_PK_BYTES] ^= 0x01;
+        let bad_sk = KyberSecretKey::from(*sk_bytes);
+        assert_eq!(
+            try_decapsulate_shared_secret(&bad_sk, &ct),
+            Err(PqcError::DecapsulationFailure)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_decapsulate_with_status() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x25; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x26; 32]);
+        let (recovered, status) = decapsulate_with_status(&keys.sk, &ct);
+        assert_eq!(status, DecapStatus::Valid);
+        assert_eq!(recovered, ss);
+
+        // A flipped bit is implicitly rejected; the secret is still returned
+        let mut bad = ct.to_array();
+        bad[0] ^= 0x01;
+        let bad = KyberCiphertext::from(bad);
+        let (rejected, status) = decapsulate_with_status(&keys.sk, &bad);
+        assert_eq!(status, DecapStatus::ImplicitlyRejected);
+        assert_eq!(rejected, decapsulate_shared_secret(&keys.sk, &bad));
+        assert_ne!(rejected, ss);
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_encapsulate_decapsulate_into() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x28; 64]);
+        let mut randomness = [0x29; 32];
+        let mut sent = zeroize::Zeroizing::new([0u8; ML_KEM_1024_SS_BYTES]);
+        let ct = encapsulate_into_with_randomness(&keys.pk, &mut randomness, &mut sent);
+        assert_eq!(randomness, [0u8; 32]);
+
+        // Same ciphertext and secret as the by-value API
+        let (expected_ct, expected_ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x29; 32]);
+        assert!(ct == expected_ct);
+        assert_eq!(&*sent, expected_ss.as_bytes());
+
+        let mut received = zeroize::Zeroizing::new([0u8; ML_KEM_1024_SS_BYTES]);
+        decapsulate_into(&keys.sk, &ct, &mut received);
+        assert_eq!(*received, *sent);
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std"))]
+    fn test_public_keys_as_set_members() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let kyber: Vec<_> = (1..=3).map(|i| KyberKeys::generate_key_pair_with_seed([i; 64]).pk).collect();
+        let mut trusted: HashSet<KyberPublicKey> = kyber.iter().cloned().collect();
+        assert!(!trusted.insert(kyber[1].clone()));
+        assert!(trusted.contains(&KyberKeys::generate_key_pair_with_seed([2; 64]).pk));
+        assert!(!trusted.contains(&KyberKeys::generate_key_pair_with_seed([4; 64]).pk));
+
+        let dilithium: Vec<_> = (1..=3).map(|i| generate_dilithium_keypair_with_seed([i; 32]).0).collect();
+        let trusted: HashSet<&DilithiumPublicKey> = dilithium.iter().collect();
+        assert_eq!(trusted.len(), 3);
+
+        // Ordered by encoded bytes
+        let ordered: BTreeSet<&DilithiumPublicKey> = dilithium.iter().collect();
+        let bytes: Vec<&[u8]> = ordered.iter().map(|pk| pk.as_slice()).collect();
+        assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+    fn test_public_values_as_ref() {
+        use sha3::{Digest, Sha3_256};
+
+        fn digest(value: impl AsRef<[u8]>) -> [u8; 32] {
+            Sha3_256::digest(value.as_ref()).into()
+        }
+
+        let keys = KyberKeys::generate_key_pair_with_seed([0x28; 64]);
+        let (ct, _) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x29; 32]);
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x2a; 32]);
+        let sig = sign_message_deterministic(&sk, b"as_ref", b"ctx").unwrap();
+
+        assert_eq!(digest(&keys.pk), digest(keys.pk.as_slice()));
+        assert_eq!(ct.as_ref(), ct.as_slice());
+        assert_eq!(pk.as_ref(), pk.as_slice());
+        assert_eq!(AsRef::<[u8]>::as_ref(&sig), sig.as_slice());
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_verify_signature_bytes() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x27; 32]);
+        let stored = sign_message_deterministic(&sk, b"stored", b"").unwrap().to_array();
+        assert_eq!(verify_signature_bytes(&pk, b"stored", &stored), Ok(()));
+        assert_eq!(verify_signature_bytes(&pk, b"altered", &stored), Err(PqcError::VerificationFailure));
+        assert_eq!(
+            verify_signature_bytes(&pk, b"stored", &stored[..ML_DSA_65_SIG_BYTES - 1]),
+            Err(PqcError::InvalidKeyLength)
+        );
+        assert_eq!(verify_signature_bytes(&pk, b"stored", &[]), Err(PqcError::InvalidKeyLength));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_dilithium_deterministic_sign() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x21; 32]);
+        let msg = b"reproducible";
+        let a = sign_message_deterministic(&sk, msg, b"ctx").unwrap();
+        let b = sign_message_deterministic(&sk, msg, b"ctx").unwrap();
+        assert!(a == b);
+        assert!(verify_signature_with_context(&pk, msg, b"ctx", &a));
+
+        let hedged = sign_message_with_context_and_randomness(&sk, msg, b"ctx", [0x22; 32]).unwrap();
+        assert!(hedged != a);
+
+        let long_ctx = [0u8; ML_DSA_MAX_CONTEXT_BYTES + 1];
+        assert!(matches!(
+            sign_message_deterministic(&sk, msg, &long_ctx),
+            Err(PqcError::ContextTooLong)
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_wrapper_try_from_slice() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x10; 64]);
+        let (ct, _ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x11; 32]);
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x12; 32]);
+        let sig = sign_message_with_randomness(&sk, b"m", [0x13; 32]);
+
+        assert!(KyberPublicKey::try_from(keys.pk.as_slice()).unwrap() == keys.pk);
+        assert!(ct::secret_key_eq(&KyberSecretKey::try_from(keys.sk.as_slice()).unwrap(), &keys.sk));
+        assert!(KyberCiphertext::try_from(ct.as_slice()).unwrap() == ct);
+        assert!(DilithiumPublicKey::try_from(pk.as_slice()).unwrap() == pk);
+        assert!(ct::secret_key_eq(&DilithiumSecretKey::try_from(sk.as_slice()).unwrap(), &sk));
+        assert!(DilithiumSignature::try_from(sig.as_slice()).unwrap() == sig);
+
+        let long = [0u8; ML_DSA_65_SK_BYTES + 1];
+        assert!(matches!(KyberPublicKey::try_from(&long[..ML_KEM_1024_PK_BYTES - 1]), Err(PqcError::InvalidKeyLength)));
+        assert!(matches!(KyberSecretKey::try_from(&long[..]), Err(PqcError::InvalidKeyLength)));
+        assert!(matches!(KyberCiphertext::try_from(&long[..0]), Err(PqcError::InvalidCiphertext)));
+        assert!(matches!(DilithiumPublicKey::try_from(&long[..]), Err(PqcError::InvalidKeyLength)));
+        assert!(matches!(DilithiumSecretKey::try_from(&long[..]), Err(PqcError::InvalidKeyLength)));
+        assert!(matches!(DilithiumSignature::try_from(&long[..]), Err(PqcError::InvalidKeyLength)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_with_rng_apis() {
+        struct Counter(u8);
+        impl SeedSource for Counter {
+            fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+                for b in dest.iter_mut() {
+                    self.0 = self.0.wrapping_add(1);
+                    *b = self.0;
+                }
+                Ok(())
+            }
+        }
+
+        let keys = KyberKeys::generate_key_pair_with_rng(&mut Counter(0)).unwrap();
+        let (ct, ss) = encapsulate_shared_secret_with_rng(&keys.pk, &mut Counter(7)).unwrap();
+        assert_eq!(decapsulate_shared_secret(&keys.sk, &ct), ss);
+        // Same source state, same keys
+        assert!(KyberKeys::generate_key_pair_with_rng(&mut Counter(0)).unwrap().pk == keys.pk);
+
+        let (pk, sk) = generate_dilithium_keypair_with_rng(&mut Counter(1)).unwrap();
+        let sig = sign_message_with_rng(&sk, b"m", &mut Counter(2)).unwrap();
+        assert!(verify_signature(&pk, b"m", &sig));
+
+        struct Broken;
+        impl SeedSource for Broken {
+            fn fill(&mut self, _dest: &mut [u8]) -> Result<()> {
+                Err(PqcError::RngFailure)
+            }
+        }
+        assert!(matches!(KyberKeys::generate_key_pair_with_rng(&mut Broken), Err(PqcError::RngFailure)));
+        assert!(matches!(sign_message_with_rng(&sk, b"m", &mut Broken), Err(PqcError::RngFailure)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std"))]
+    fn test_with_rng_apis_take_rand_rngs() {
+        use rand::SeedableRng;
+
+        let keys = KyberKeys::generate_key_pair_with_seed([0x16; 64]);
+        let (ct, ss) = encapsulate_shared_secret_with_rng(&keys.pk, &mut rand::thread_rng()).unwrap();
+        assert_eq!(decapsulate_shared_secret(&keys.sk, &ct), ss);
+
+        // A seeded generator reproduces the encapsulation
+        let seeded = || rand::rngs::StdRng::seed_from_u64(17);
+        let (first, _) = encapsulate_shared_secret_with_rng(&keys.pk, &mut seeded()).unwrap();
+        let (second, _) = encapsulate_shared_secret_with_rng(&keys.pk, &mut seeded()).unwrap();
+        assert!(first == second);
+
+        #[cfg(not(feature = "require-context"))]
+        {
+            let (pk, sk) = generate_dilithium_keypair_with_seed([0x18; 32]);
+            let sig = sign_message_with_rng(&sk, b"m", &mut rand::thread_rng()).unwrap();
+            assert!(verify_signature(&pk, b"m", &sig));
+            let a = sign_message_with_rng(&sk, b"m", &mut seeded()).unwrap();
+            assert!(a == sign_message_with_rng(&sk, b"m", &mut seeded()).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_verify_strict() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x19; 32]);
+        let sig = sign_message_with_context_and_randomness(&sk, b"m", b"ctx", [0x1a; 32]).unwrap();
+        assert_eq!(verify_strict(&pk, b"m", b"ctx", &sig), Ok(()));
+        assert_eq!(verify_strict(&pk, b"n", b"ctx", &sig), Err(PqcError::VerificationFailure));
+
+        // Same message, same key, fresh hedge: another valid signature
+        let other = sign_message_with_context_and_randomness(&sk, b"m", b"ctx", [0x1b; 32]).unwrap();
+        assert!(other != sig);
+        assert_eq!(verify_strict(&pk, b"m", b"ctx", &other), Ok(()));
+
+        let hint_at = ML_DSA_65_SIG_BYTES - ML_DSA_65_HINT_BYTES;
+        let used = usize::from(sig.as_slice()[ML_DSA_65_SIG_BYTES - 1]);
+        assert!(used < ML_DSA_65_OMEGA);
+
+        // Non-zero padding after the last hint position
+        let mut padded = sig.to_array();
+        padded[hint_at + ML_DSA_65_OMEGA - 1] = 1;
+        let padded = DilithiumSignature::from(padded);
+        assert_eq!(verify_strict(&pk, b"m", b"ctx", &padded), Err(PqcError::NonCanonicalSignature));
+
+        // A row count running backwards, or past omega
+        let mut counts = sig.to_array();
+        counts[ML_DSA_65_SIG_BYTES - 1] = ML_DSA_65_OMEGA as u8 + 1;
+        let counts = DilithiumSignature::from(counts);
+        assert_eq!(verify_strict(&pk, b"m", b"ctx", &counts), Err(PqcError::NonCanonicalSignature));
+
+        // Hand-built hint sections
+        let mut hint = [0u8; ML_DSA_65_HINT_BYTES];
+        assert!(hint_is_canonical(&hint));
+        hint[..3].copy_from_slice(&[4, 9, 200]);
+        hint[ML_DSA_65_OMEGA..].copy_from_slice(&[2, 3, 3, 3, 3, 3]);
+        assert!(hint_is_canonical(&hint));
+        hint[1] = 4;
+        assert!(!hint_is_canonical(&hint));
+        hint[1] = 9;
+        hint[ML_DSA_65_OMEGA + 1] = 1;
+        assert!(!hint_is_canonical(&hint));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    fn test_signature_from_bytes() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x14; 32]);
+        let sig = sign_message_with_randomness(&sk, b"m", [0x15; 32]);
+
+        let parsed = signature_from_bytes(sig.as_slice()).unwrap();
+        assert!(verify_signature(&pk, b"m", &parsed));
+
+        let mut oversized = [0u8; ML_DSA_65_SIG_BYTES + 1];
+        oversized[..ML_DSA_65_SIG_BYTES].copy_from_slice(sig.as_slice());
+        assert!(matches!(signature_from_bytes(&oversized), Err(PqcError::InvalidKeyLength)));
+        assert!(matches!(
+            signature_from_bytes(&sig.as_slice()[..ML_DSA_65_SIG_BYTES - 1]),
+            Err(PqcError::InvalidKeyLength)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_try_encapsulate_rejects_invalid_public_key() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x33; 64]);
+        assert!(validate_public_key(&keys.pk));
+        let (ct, ss) = try_encapsulate_shared_secret_with_randomness(&keys.pk, [0x44; 32]).unwrap();
+        assert_eq!(decapsulate_shared_secret(&keys.sk, &ct), ss);
+
+        // 0xFFF coefficients are >= q and fail the modulus check
+        let malformed = KyberPublicKey::from([0xFF; ML_KEM_1024_PK_BYTES]);
+        assert!(!validate_public_key(&malformed));
+        assert!(matches!(
+            try_encapsulate_shared_secret_with_randomness(&malformed, [0x44; 32]),
+            Err(PqcError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+    fn test_import_kyber_keypair() {
+        let keys = KyberKeys::generate_key_pair();
+        let other = KyberKeys::generate_key_pair();
+
+        let imported = import_kyber_keypair(keys.pk.as_slice(), keys.sk.as_slice()).unwrap();
+        assert!(imported.pk == keys.pk);
+        assert!(ct::secret_key_eq(&imported.sk, &keys.sk));
+
+        assert!(matches!(
+            import_kyber_keypair(keys.pk.as_slice(), other.sk.as_slice()),
+            Err(PqcError::PairwiseConsistencyTestFailure)
+        ));
+        assert!(matches!(
+            import_kyber_keypair(&keys.pk.as_slice()[1..], keys.sk.as_slice()),
+            Err(PqcError::InvalidKeyLength)
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+    fn test_import_dilithium_keypair() {
+        let (pk, sk) = generate_dilithium_keypair();
+        let (_other_pk, other_sk) = generate_dilithium_keypair();
+
+        let (imported_pk, imported_sk) =
+            import_dilithium_keypair(pk.as_slice(), sk.as_slice()).unwrap();
+        assert!(imported_pk == pk);
+        assert!(ct::secret_key_eq(&imported_sk, &sk));
+
+        assert!(matches!(
+            import_dilithium_keypair(pk.as_slice(), other_sk.as_slice()),
+            Err(PqcError::PairwiseConsistencyTestFailure)
+        ));
+        assert!(matches!(
+            import_dilithium_keypair(pk.as_slice(), &sk.as_slice()[..100]),
+            Err(PqcError::InvalidKeyLength)
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    fn test_dilithium_keypair_struct() {
+        let keys = DilithiumKeypair::generate_with_seed([0x63; 32]);
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x63; 32]);
+        assert!(keys.pk == pk);
+        assert!(ct::secret_key_eq(&*keys.sk, &sk));
+
+        let sig = keys.sign(b"named fields");
+        assert!(keys.verify(b"named fields", &sig));
+        assert!(!keys.verify(b"other", &sig));
+
+        let fresh = DilithiumKeypair::generate_with_pct().unwrap();
+        assert!(fresh.pk != keys.pk);
+        assert!(!keys.verify(b"named fields", &fresh.sign(b"named fields")));
+    }
+
     #[test]
     #[cfg(all(feature = "aes-gcm", feature = "alloc"))]
     fn test_aes_gcm_roundtrip() {
@@ -311,5 +2141,118 @@ mod tests {
         let decrypted = decrypt_aes_gcm(&key, &nonce, &ciphertext).unwrap();
         
         assert_eq!(plaintext, &decrypted[..]);
+
+        for len in [0, 1, AES_TAG_BYTES - 1] {
+            assert_eq!(decrypt_aes_gcm(&key, &nonce, &ciphertext[..len]), Err(PqcError::AeadOperationFailed));
+        }
+        let empty = encrypt_aes_gcm(&key, &nonce, b"").unwrap();
+        assert_eq!(empty.len(), AES_TAG_BYTES);
+        assert_eq!(decrypt_aes_gcm(&key, &nonce, &empty).unwrap(), b"");
+    }
+
+    #[test]
+    #[cfg(feature = "aes-gcm")]
+    fn test_aes_gcm_into_roundtrip() {
+        let key = [1u8; 32];
+        let nonce = [2u8; 12];
+        let plaintext = b"secret data";
+
+        let mut ct = [0u8; 64];
+        let ct_len = encrypt_aes_gcm_into(&key, &nonce, plaintext, &mut ct).unwrap();
+        assert_eq!(ct_len, plaintext.len() + AES_TAG_BYTES);
+
+        let mut pt = [0u8; 64];
+        let pt_len = decrypt_aes_gcm_into(&key, &nonce, &ct[..ct_len], &mut pt).unwrap();
+        assert_eq!(&pt[..pt_len], plaintext);
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(encrypt_aes_gcm(&key, &nonce, plaintext).unwrap(), &ct[..ct_len]);
+
+        ct[0] ^= 0x01;
+        assert_eq!(
+            decrypt_aes_gcm_into(&key, &nonce, &ct[..ct_len], &mut pt),
+            Err(PqcError::AeadOperationFailed)
+        );
+        assert!(pt[..pt_len].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "aes-gcm", feature = "alloc"))]
+    fn test_aes_gcm_tag_len() {
+        let key = [0x31u8; 32];
+        let nonce = [0x32u8; 12];
+        let ct = encrypt_aes_gcm(&key, &nonce, b"legacy peer").unwrap();
+        assert_eq!(decrypt_aes_gcm_with_tag_len(&key, &nonce, &ct, 16).unwrap(), b"legacy peer");
+
+        // GCM tags truncate to their leading bytes
+        let short = &ct[..ct.len() - 4];
+        #[cfg(not(feature = "fips_140_3"))]
+        {
+            assert_eq!(decrypt_aes_gcm_with_tag_len(&key, &nonce, short, 12).unwrap(), b"legacy peer");
+            assert_eq!(
+                decrypt_aes_gcm_with_tag_len(&key, &nonce, short, 13),
+                Err(PqcError::AeadOperationFailed)
+            );
+        }
+        #[cfg(feature = "fips_140_3")]
+        assert_eq!(decrypt_aes_gcm_with_tag_len(&key, &nonce, short, 12), Err(PqcError::InvalidTagLength));
+
+        assert_eq!(decrypt_aes_gcm_with_tag_len(&key, &nonce, &ct, 8), Err(PqcError::InvalidTagLength));
+        assert_eq!(decrypt_aes_gcm_with_tag_len(&key, &nonce, &ct, 17), Err(PqcError::InvalidTagLength));
+    }
+
+    #[test]
+    #[cfg(feature = "aes-gcm")]
+    fn test_aes_gcm_into_with_aad() {
+        let key = [1u8; 32];
+        let nonce = [2u8; 12];
+        let mut ct = [0u8; 32];
+        let ct_len = encrypt_aes_gcm_into_with_aad(&key, &nonce, b"hdr", b"body", &mut ct).unwrap();
+
+        let mut pt = [0u8; 32];
+        let pt_len = decrypt_aes_gcm_into_with_aad(&key, &nonce, b"hdr", &ct[..ct_len], &mut pt).unwrap();
+        assert_eq!(&pt[..pt_len], b"body");
+        assert_eq!(
+            decrypt_aes_gcm_into_with_aad(&key, &nonce, b"hdX", &ct[..ct_len], &mut pt),
+            Err(PqcError::AeadOperationFailed)
+        );
+        assert_eq!(
+            decrypt_aes_gcm_into(&key, &nonce, &ct[..ct_len], &mut pt),
+            Err(PqcError::AeadOperationFailed)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "aes-gcm")]
+    fn test_aes_gcm_into_buffer_too_small() {
+        let key = [1u8; 32];
+        let nonce = [2u8; 12];
+        let mut out = [0u8; 16 + 3];
+        assert_eq!(
+            encrypt_aes_gcm_into(&key, &nonce, b"four", &mut out),
+            Err(PqcError::BufferTooSmall)
+        );
+        let len = encrypt_aes_gcm_into(&key, &nonce, b"thr", &mut out).unwrap();
+
+        let mut short = [0u8; 2];
+        assert_eq!(
+            decrypt_aes_gcm_into(&key, &nonce, &out[..len], &mut short),
+            Err(PqcError::BufferTooSmall)
+        );
+        assert_eq!(
+            decrypt_aes_gcm_into(&key, &nonce, &out[..AES_TAG_BYTES - 1], &mut short),
+            Err(PqcError::AeadOperationFailed)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "aes-gcm", target_pointer_width = "64"))]
+    fn test_aes_gcm_length_limit() {
+        // Only the length is checked, so no buffer of this size is needed
+        let max = AES_GCM_MAX_PLAINTEXT_BYTES as usize;
+        assert_eq!(check_gcm_len(0), Ok(()));
+        assert_eq!(check_gcm_len(max), Ok(()));
+        assert_eq!(check_gcm_len(max + 1), Err(PqcError::MessageTooLarge));
+        assert_eq!(check_gcm_len(usize::MAX), Err(PqcError::MessageTooLarge));
     }
 }
\ No newline at end of file
@@ -0,0 +1,125 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Swap-Protected Secret Buffers (mlock/munlock)
+// ------------------------------------------------------------------------
+//! [`LockedSecret`] copies secret bytes into a heap buffer whose pages are
+//! pinned in RAM with `mlock(2)`, so the key material is never written to
+//! swap. On drop the buffer is zeroized and then unlocked.
+//!
+//! Locking is subject to `RLIMIT_MEMLOCK`; when the kernel refuses,
+//! construction fails with `PqcError::MemoryLockFailed` instead of silently
+//! holding the secret in swappable memory.
+
+use core::fmt;
+use std::boxed::Box;
+
+use zeroize::Zeroize;
+
+use crate::error::{PqcError, Result};
+
+/// Secret bytes held in `mlock`ed memory
+///
+/// ```ignore
+/// let locked = LockedSecret::new(keys.sk.as_slice())?;
+/// ```
+pub struct LockedSecret {
+    bytes: Box<[u8]>,
+}
+
+impl LockedSecret {
+    /// Copy `secret` into a freshly locked buffer
+    ///
+    /// The caller remains responsible for wiping its own copy of `secret`.
+    pub fn new(secret: &[u8]) -> Result<Self> {
+        let mut bytes: Box<[u8]> = secret.into();
+        if !bytes.is_empty() {
+            // SAFETY: the pointer and length describe the live allocation
+            // owned by `bytes`.
+            let rc = unsafe { libc::mlock(bytes.as_ptr().cast(), bytes.len()) };
+            if rc != 0 {
+                bytes.zeroize();
+                return Err(PqcError::MemoryLockFailed);
+            }
+        }
+        Ok(Self { bytes })
+    }
+
+    /// Borrow the secret bytes
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl Drop for LockedSecret {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+        if !self.bytes.is_empty() {
+            // SAFETY: same allocation that was locked in `new`. A failed
+            // unlock only leaks the lock, never the secret.
+            unsafe {
+                libc::munlock(self.bytes.as_ptr().cast(), self.bytes.len());
+            }
+        }
+    }
+}
+
+impl fmt::Debug for LockedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LockedSecret([REDACTED; {}])", self.bytes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memlock_limit() -> libc::rlim_t {
+        let mut limit = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+        // SAFETY: `limit` is a valid out-pointer for getrlimit.
+        let rc = unsafe { libc::getrlimit(libc::RLIMIT_MEMLOCK, &mut limit) };
+        assert_eq!(rc, 0);
+        limit.rlim_cur
+    }
+
+    #[test]
+    fn test_locked_secret_construction() {
+        let secret = [0x5au8; 64];
+        match LockedSecret::new(&secret) {
+            Ok(locked) => {
+                assert_eq!(locked.as_slice(), &secret);
+                assert_eq!(locked.len(), 64);
+                assert_eq!(std::format!("{:?}", locked), "LockedSecret([REDACTED; 64])");
+            }
+            Err(err) => {
+                // Only acceptable when the process may not lock any memory
+                assert_eq!(err, PqcError::MemoryLockFailed);
+                assert_eq!(memlock_limit(), 0, "mlock failed despite a non-zero RLIMIT_MEMLOCK");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_locked_secret_holds_kyber_key() {
+        if memlock_limit() < crate::ML_KEM_1024_SK_BYTES as libc::rlim_t {
+            return;
+        }
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x09; 64]);
+        let locked = LockedSecret::new(keys.sk.as_slice()).unwrap();
+        assert_eq!(locked.as_slice(), keys.sk.as_slice());
+    }
+
+    #[test]
+    fn test_locked_secret_empty() {
+        let locked = LockedSecret::new(&[]).unwrap();
+        assert!(locked.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Health-Monitored Operations (Periodic CASTs)
+// ------------------------------------------------------------------------
+//! Wrapper that re-runs the hash CASTs every `interval` operations.
+//!
+//! [`MonitoredCrypto`] counts each KEM/DSA call. When the count reaches a
+//! multiple of the interval, [`run_hash_casts`] runs before the operation;
+//! a failure moves the module to the Error state, after which every call
+//! returns `PqcError::FipsErrorState` until [`crate::recover_from_error`]
+//! succeeds. All operations also require the module to be Operational.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::cast::run_hash_casts;
+use crate::error::Result;
+use crate::rng::SeedSource;
+use crate::state::{check_operational, enter_error_state};
+
+#[cfg(feature = "ml-kem")]
+use crate::{KyberCiphertext, KyberPublicKey, KyberSecretKey, KyberSharedSecret};
+
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+use crate::{DilithiumPublicKey, DilithiumSecretKey, DilithiumSignature};
+
+/// KEM/DSA front end that periodically re-runs the hash CASTs
+pub struct MonitoredCrypto {
+    interval: u64,
+    operations: AtomicU64,
+    cast: fn() -> Result<()>,
+}
+
+impl MonitoredCrypto {
+    /// Re-run the CASTs every `interval` operations (0 is treated as 1)
+    pub fn new(interval: u64) -> Self {
+        Self::with_cast(interval, run_hash_casts)
+    }
+
+    fn with_cast(interval: u64, cast: fn() -> Result<()>) -> Self {
+        Self {
+            interval: interval.max(1),
+            operations: AtomicU64::new(0),
+            cast,
+        }
+    }
+
+    /// Configured CAST interval
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Operations served (or attempted) so far
+    pub fn operation_count(&self) -> u64 {
+        self.operations.load(Ordering::Relaxed)
+    }
+
+    /// Gate one operation: state check, count, and CASTs when due
+    fn before_operation(&self) -> Result<()> {
+        check_operational()?;
+        let count = self.operations.fetch_add(1, Ordering::Relaxed) + 1;
+        if count.is_multiple_of(self.interval) {
+            if let Err(e) = (self.cast)() {
+                enter_error_state();
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "ml-kem")]
+    pub fn encapsulate_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        pk: &KyberPublicKey,
+        rng: &mut R,
+    ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        self.before_operation()?;
+        crate::encapsulate_shared_secret_with_rng(pk, rng)
+    }
+
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+    pub fn encapsulate(&self, pk: &KyberPublicKey) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        self.encapsulate_with_rng(pk, &mut rand::rngs::OsRng)
+    }
+
+    #[cfg(feature = "ml-kem")]
+    pub fn decapsulate(&self, sk: &KyberSecretKey, ct: &KyberCiphertext) -> Result<KyberSharedSecret> {
+        self.before_operation()?;
+        Ok(crate::decapsulate_shared_secret(sk, ct))
+    }
+
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    pub fn sign_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        sk: &DilithiumSecretKey,
+        msg: &[u8],
+        rng: &mut R,
+    ) -> Result<DilithiumSignature> {
+        self.before_operation()?;
+        crate::sign_message_with_rng(sk, msg, rng)
+    }
+
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    pub fn sign(&self, sk: &DilithiumSecretKey, msg: &[u8]) -> Result<DilithiumSignature> {
+        self.sign_with_rng(sk, msg, &mut rand::rngs::OsRng)
+    }
+
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    pub fn verify(
+        &self,
+        pk: &DilithiumPublicKey,
+        msg: &[u8],
+        sig: &DilithiumSignature,
+    ) -> Result<bool> {
+        self.before_operation()?;
+        Ok(crate::verify_signature(pk, msg, sig))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::error::PqcError;
+    use crate::state::{lock_state_for_test, reset_fips_state};
+    #[cfg(all(feature = "ml-kem", not(feature = "explicit-rng")))]
+    use crate::state::{get_fips_state, FipsState};
+
+    #[cfg(all(feature = "ml-kem", not(feature = "explicit-rng")))]
+    fn failing_cast() -> Result<()> {
+        Err(PqcError::CastFailure)
+    }
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_requires_operational() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        let monitor = MonitoredCrypto::new(10);
+
+        #[cfg(feature = "ml-dsa")]
+        {
+            let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x01; 32]);
+            let sig = crate::sign_message_with_randomness(&sk, b"m", [0x02; 32]);
+            assert_eq!(monitor.verify(&pk, b"m", &sig), Err(PqcError::FipsNotInitialized));
+        }
+        assert_eq!(monitor.operation_count(), 0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    fn test_casts_rerun_and_operations_served() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        crate::run_post().unwrap();
+
+        let monitor = MonitoredCrypto::new(2);
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x03; 64]);
+        let (ct, ss) = monitor.encapsulate(&keys.pk).unwrap();
+        assert_eq!(monitor.decapsulate(&keys.sk, &ct).unwrap(), ss);
+
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x04; 32]);
+        let sig = monitor.sign(&sk, b"msg").unwrap();
+        assert_eq!(monitor.verify(&pk, b"msg", &sig), Ok(true));
+
+        assert_eq!(monitor.operation_count(), 4);
+        assert_eq!(get_fips_state(), FipsState::Operational);
+        reset_fips_state();
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", not(feature = "explicit-rng")))]
+    fn test_cast_failure_stops_serving() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        crate::run_post().unwrap();
+
+        let monitor = MonitoredCrypto::with_cast(3, failing_cast);
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x05; 64]);
+        let (ct, _ss) = crate::encapsulate_shared_secret_with_randomness(&keys.pk, [0x06; 32]);
+
+        assert!(monitor.decapsulate(&keys.sk, &ct).is_ok());
+        assert!(monitor.decapsulate(&keys.sk, &ct).is_ok());
+        assert_eq!(monitor.decapsulate(&keys.sk, &ct), Err(PqcError::CastFailure));
+        assert_eq!(get_fips_state(), FipsState::Error);
+
+        assert_eq!(monitor.decapsulate(&keys.sk, &ct), Err(PqcError::FipsErrorState));
+        assert!(matches!(monitor.encapsulate(&keys.pk), Err(PqcError::FipsErrorState)));
+        reset_fips_state();
+    }
+
+    #[test]
+    fn test_zero_interval_treated_as_one() {
+        assert_eq!(MonitoredCrypto::new(0).interval(), 1);
+    }
+}
@@ -0,0 +1,144 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Typestate Handle for Operational-Only Operations
+// ------------------------------------------------------------------------
+//! Compile-time proof that POST has passed.
+//!
+//! [`OperationalHandle`] cannot be constructed outside this module; the only
+//! way to get one is [`FipsModule::try_into_operational`], which succeeds
+//! only once the module is Operational. Code that takes a handle therefore
+//! cannot reach the guarded operations before initialization.
+//!
+//! The handle is a proof of *past* success: every operation still runs
+//! [`check_operational`], so a later transition to the Error state is
+//! reported at run time. Dropping a handle never changes the FIPS state.
+
+use crate::error::{PqcError, Result};
+use crate::preop::run_post;
+use crate::state::check_operational;
+
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+use crate::rng::SeedSource;
+
+#[cfg(feature = "ml-kem")]
+use crate::{KyberCiphertext, KyberPublicKey, KyberSecretKey, KyberSharedSecret};
+
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+use crate::{DilithiumPublicKey, DilithiumSecretKey, DilithiumSignature};
+
+/// Entry point of the typestate API
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FipsModule;
+
+impl FipsModule {
+    /// Get a handle, running POST first if it has not run yet
+    ///
+    /// From Uninitialized this runs [`run_post`]; from Operational it returns
+    /// immediately. The Error and POST states are reported as
+    /// `FipsErrorState` / `FipsPostInProgress`.
+    pub fn try_into_operational(self) -> Result<OperationalHandle> {
+        match check_operational() {
+            Ok(()) => {}
+            Err(PqcError::FipsNotInitialized) => run_post()?,
+            Err(e) => return Err(e),
+        }
+        Ok(OperationalHandle { _proof: () })
+    }
+}
+
+/// Exposes the cryptographic operations; only exists after a passing POST
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationalHandle {
+    _proof: (),
+}
+
+impl OperationalHandle {
+    #[cfg(feature = "ml-kem")]
+    pub fn encapsulate_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        pk: &KyberPublicKey,
+        rng: &mut R,
+    ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        check_operational()?;
+        crate::encapsulate_shared_secret_with_rng(pk, rng)
+    }
+
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+    pub fn encapsulate(&self, pk: &KyberPublicKey) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        self.encapsulate_with_rng(pk, &mut rand::rngs::OsRng)
+    }
+
+    #[cfg(feature = "ml-kem")]
+    pub fn decapsulate(&self, sk: &KyberSecretKey, ct: &KyberCiphertext) -> Result<KyberSharedSecret> {
+        check_operational()?;
+        Ok(crate::decapsulate_shared_secret(sk, ct))
+    }
+
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    pub fn sign_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        sk: &DilithiumSecretKey,
+        msg: &[u8],
+        rng: &mut R,
+    ) -> Result<DilithiumSignature> {
+        check_operational()?;
+        crate::sign_message_with_rng(sk, msg, rng)
+    }
+
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    pub fn sign(&self, sk: &DilithiumSecretKey, msg: &[u8]) -> Result<DilithiumSignature> {
+        self.sign_with_rng(sk, msg, &mut rand::rngs::OsRng)
+    }
+
+    #[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+    pub fn verify(
+        &self,
+        pk: &DilithiumPublicKey,
+        msg: &[u8],
+        sig: &DilithiumSignature,
+    ) -> Result<bool> {
+        check_operational()?;
+        Ok(crate::verify_signature(pk, msg, sig))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::state::{enter_error_state, get_fips_state, lock_state_for_test, reset_fips_state, FipsState};
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    fn test_handle_after_post() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+
+        let handle = FipsModule.try_into_operational().unwrap();
+        assert_eq!(get_fips_state(), FipsState::Operational);
+
+        let keys = crate::KyberKeys::generate_key_pair_with_seed([0x70; 64]);
+        let (ct, ss) = handle.encapsulate(&keys.pk).unwrap();
+        assert_eq!(handle.decapsulate(&keys.sk, &ct).unwrap(), ss);
+
+        let dsa = crate::DilithiumKeypair::generate_with_seed([0x71; 32]);
+        let sig = handle.sign(&dsa.sk, b"typestate").unwrap();
+        assert_eq!(handle.verify(&dsa.pk, b"typestate", &sig), Ok(true));
+
+        // A handle taken earlier still sees a later failure
+        enter_error_state();
+        assert_eq!(handle.decapsulate(&keys.sk, &ct), Err(PqcError::FipsErrorState));
+        assert_eq!(get_fips_state(), FipsState::Error);
+        reset_fips_state();
+    }
+
+    #[test]
+    fn test_no_handle_from_error_state() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        enter_error_state();
+
+        assert_eq!(FipsModule.try_into_operational(), Err(PqcError::FipsErrorState));
+        assert_eq!(get_fips_state(), FipsState::Error);
+        reset_fips_state();
+    }
+}
@@ -8,17 +8,31 @@
 #[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
 use crate::error::{PqcError, Result};
 
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+use crate::rng::SeedSource;
+
+#[cfg(feature = "ml-kem")]
+use subtle::ConstantTimeEq;
+
 #[cfg(feature = "ml-kem")]
 use crate::{
-    decapsulate_shared_secret, encapsulate_shared_secret, KyberKeys,
+    decapsulate_shared_secret, encapsulate_shared_secret_with_rng, KyberKeys, KyberSharedSecret,
 };
 
 #[cfg(feature = "ml-dsa")]
 use crate::{
-    sign_message, verify_signature,
-    DilithiumPublicKey, DilithiumSecretKey,
+    sign_with_any_context, verify_with_any_context, DilithiumPublicKey, DilithiumSecretKey,
+    DilithiumSignature,
 };
 
+/// Message signed by the ML-DSA PCT when no random one can be drawn
+#[cfg(feature = "ml-dsa")]
+const PCT_MESSAGE: &[u8] = b"FIPS 140-3 Pair-wise Consistency Test";
+
+/// Encapsulation randomness for [`kyber_pct`] when no implicit RNG is linked
+#[cfg(all(feature = "ml-kem", feature = "explicit-rng"))]
+const PCT_ENCAP_RANDOMNESS: [u8; 32] = [0x5a; 32];
+
 /// Performs Pair-wise Consistency Test (PCT) for Kyber key generation.
 ///
 /// FIPS 140-3 requirement: Verify that a newly generated key pair is consistent
@@ -32,14 +46,35 @@ use crate::{
 /// * `Err(PqcError::PairwiseConsistencyTestFailure)` if the test fails
 #[cfg(feature = "ml-kem")]
 pub fn kyber_pct(keys: &KyberKeys) -> Result<()> {
-    // 1. Encapsulate with the public key
-    let (ciphertext, ss_encap) = encapsulate_shared_secret(&keys.pk);
+    // 1. Encapsulate with the public key (fixed randomness under `explicit-rng`;
+    //    the shared secret never leaves this function)
+    #[cfg(not(feature = "explicit-rng"))]
+    let (ciphertext, ss_encap) = crate::encapsulate_shared_secret(&keys.pk);
+    #[cfg(feature = "explicit-rng")]
+    let (ciphertext, ss_encap) =
+        crate::encapsulate_shared_secret_with_randomness(&keys.pk, PCT_ENCAP_RANDOMNESS);
 
     // 2. Decapsulate with the secret key
     let ss_decap = decapsulate_shared_secret(&keys.sk, &ciphertext);
 
-    // 3. Verify shared secrets match (both are [u8; 32])
-    if ss_encap == ss_decap {
+    // 3. Verify shared secrets match (both are [u8; 32], compared in constant time)
+    shared_secrets_match(&ss_encap, &ss_decap)
+}
+
+/// [`kyber_pct`] with encapsulation randomness from `rng` (no `std` needed)
+///
+/// Returns `RngFailure` if `rng` fails, otherwise as [`kyber_pct`].
+#[cfg(feature = "ml-kem")]
+pub fn kyber_pct_with_rng<R: SeedSource + ?Sized>(keys: &KyberKeys, rng: &mut R) -> Result<()> {
+    let (ciphertext, ss_encap) = encapsulate_shared_secret_with_rng(&keys.pk, rng)?;
+    let ss_decap = decapsulate_shared_secret(&keys.sk, &ciphertext);
+    shared_secrets_match(&ss_encap, &ss_decap)
+}
+
+/// Constant-time: the shared secrets are CSPs
+#[cfg(feature = "ml-kem")]
+fn shared_secrets_match(a: &KyberSharedSecret, b: &KyberSharedSecret) -> Result<()> {
+    if bool::from(a.ct_eq(b)) {
         Ok(())
     } else {
         Err(PqcError::PairwiseConsistencyTestFailure)
@@ -49,7 +84,11 @@ pub fn kyber_pct(keys: &KyberKeys) -> Result<()> {
 /// Performs Pair-wise Consistency Test (PCT) for Dilithium key generation.
 ///
 /// FIPS 140-3 requirement: Verify that a newly generated key pair is consistent
-/// by signing a known message and verifying the signature with the public key.
+/// by signing a test message and verifying the signature with the public key.
+///
+/// With `std` the message is 32 fresh random bytes on every call, so no PCT
+/// can be satisfied by a replayed signature. Without an implicit RNG
+/// (`no_std` or `explicit-rng`) the fixed `PCT_MESSAGE` is signed instead.
 ///
 /// # Arguments
 /// * `pk` - The public key to test
@@ -60,14 +99,66 @@ pub fn kyber_pct(keys: &KyberKeys) -> Result<()> {
 /// * `Err(PqcError::PairwiseConsistencyTestFailure)` if the test fails
 #[cfg(feature = "ml-dsa")]
 pub fn dilithium_pct(pk: &DilithiumPublicKey, sk: &DilithiumSecretKey) -> Result<()> {
-    // Use a fixed test message for PCT
-    const PCT_MESSAGE: &[u8] = b"FIPS 140-3 Pair-wise Consistency Test";
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    {
+        dilithium_pct_with_message(pk, sk, &crate::rng::generate_seed_32())
+    }
+    #[cfg(not(all(feature = "std", not(feature = "explicit-rng"))))]
+    {
+        dilithium_pct_with_message(pk, sk, PCT_MESSAGE)
+    }
+}
+
+/// [`dilithium_pct`] over a caller-chosen message
+///
+/// Signs hedged with OS randomness, or deterministically without `std` or
+/// under `explicit-rng`. The PCT signs with an empty context even under
+/// `require-context`.
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_pct_with_message(
+    pk: &DilithiumPublicKey,
+    sk: &DilithiumSecretKey,
+    msg: &[u8],
+) -> Result<()> {
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    let randomness = crate::rng::generate_seed_32();
+    #[cfg(not(all(feature = "std", not(feature = "explicit-rng"))))]
+    let randomness = [0u8; crate::ML_DSA_SIGN_SEED_BYTES];
+    let signature = sign_with_any_context(sk, msg, &[], randomness)?;
 
-    // 1. Sign the test message with the secret key
-    let signature = sign_message(sk, PCT_MESSAGE);
+    signature_verifies(pk, msg, &signature)
+}
 
-    // 2. Verify the signature with the public key
-    if verify_signature(pk, PCT_MESSAGE, &signature) {
+/// Reproducible [`dilithium_pct`]: fixed `PCT_MESSAGE`, deterministic signing
+///
+/// For tests that need the same signature on every run.
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_pct_deterministic(pk: &DilithiumPublicKey, sk: &DilithiumSecretKey) -> Result<()> {
+    let signature = sign_with_any_context(sk, PCT_MESSAGE, &[], [0u8; crate::ML_DSA_SIGN_SEED_BYTES])?;
+    signature_verifies(pk, PCT_MESSAGE, &signature)
+}
+
+/// [`dilithium_pct`] with the message and hedging randomness from `rng`
+/// (no `std` needed)
+///
+/// Returns `RngFailure` if `rng` fails, otherwise as [`dilithium_pct`].
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_pct_with_rng<R: SeedSource + ?Sized>(
+    pk: &DilithiumPublicKey,
+    sk: &DilithiumSecretKey,
+    rng: &mut R,
+) -> Result<()> {
+    let message = crate::rng::seed_32_from(rng)?;
+    let mut randomness = crate::rng::seed_32_from(rng)?;
+    let signature = sign_with_any_context(sk, &message, &[], randomness);
+    zeroize::Zeroize::zeroize(&mut randomness);
+    let signature = signature?;
+    signature_verifies(pk, &message, &signature)
+}
+
+#[cfg(feature = "ml-dsa")]
+fn signature_verifies(pk: &DilithiumPublicKey, msg: &[u8], signature: &DilithiumSignature) -> Result<()> {
+    if verify_with_any_context(pk, msg, &[], signature) {
         Ok(())
     } else {
         Err(PqcError::PairwiseConsistencyTestFailure)
@@ -80,7 +171,7 @@ mod tests {
     use super::*;
 
     #[test]
-    #[cfg(all(feature = "ml-kem", feature = "std"))]
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
     fn test_kyber_pct_success() {
         use crate::KyberKeys;
         let keys = KyberKeys::generate_key_pair();
@@ -88,7 +179,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "ml-dsa", feature = "std"))]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
     fn test_dilithium_pct_success() {
         use crate::generate_dilithium_keypair;
         let (pk, sk) = generate_dilithium_keypair();
@@ -98,8 +189,76 @@ mod tests {
         );
     }
 
+    /// Deterministic non-zero source for RNG-injected PCTs
+    #[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+    struct CountingSource(u8);
+
+    #[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+    impl SeedSource for CountingSource {
+        fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+            for byte in dest.iter_mut() {
+                self.0 = self.0.wrapping_add(1);
+                *byte = self.0;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_kyber_pct_with_rng() {
+        use crate::KyberKeys;
+        let mut rng = CountingSource(0);
+        let keys = KyberKeys::generate_key_pair_with_rng_and_pct(&mut rng).unwrap();
+        assert!(kyber_pct_with_rng(&keys, &mut rng).is_ok());
+
+        let other = KyberKeys::generate_key_pair_with_seed([0x41; 64]);
+        let mismatched = KyberKeys { pk: keys.pk, sk: other.sk };
+        assert_eq!(
+            kyber_pct_with_rng(&mismatched, &mut rng),
+            Err(PqcError::PairwiseConsistencyTestFailure)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_dilithium_pct_with_rng() {
+        use crate::{generate_dilithium_keypair_with_rng_and_pct, generate_dilithium_keypair_with_seed};
+        let mut rng = CountingSource(0x80);
+        let (pk, sk) = generate_dilithium_keypair_with_rng_and_pct(&mut rng).unwrap();
+        assert!(dilithium_pct_with_rng(&pk, &sk, &mut rng).is_ok());
+
+        let (_pk2, sk2) = generate_dilithium_keypair_with_seed([0x42; 32]);
+        assert_eq!(
+            dilithium_pct_with_rng(&pk, &sk2, &mut rng),
+            Err(PqcError::PairwiseConsistencyTestFailure)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_dilithium_pct_with_message_and_deterministic() {
+        use crate::generate_dilithium_keypair_with_seed;
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x43; 32]);
+        let (_pk2, sk2) = generate_dilithium_keypair_with_seed([0x44; 32]);
+        #[cfg(any(feature = "std", feature = "explicit-rng"))]
+        {
+            assert!(dilithium_pct_with_message(&pk, &sk, b"validation lab message").is_ok());
+            assert!(dilithium_pct_with_message(&pk, &sk, b"").is_ok());
+            assert_eq!(
+                dilithium_pct_with_message(&pk, &sk2, b"validation lab message"),
+                Err(PqcError::PairwiseConsistencyTestFailure)
+            );
+        }
+        assert!(dilithium_pct_deterministic(&pk, &sk).is_ok());
+        assert_eq!(
+            dilithium_pct_deterministic(&pk, &sk2),
+            Err(PqcError::PairwiseConsistencyTestFailure)
+        );
+    }
+
     #[test]
-    #[cfg(all(feature = "ml-kem", feature = "std"))]
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
     fn test_kyber_pct_failure_mismatched_keys() {
         use crate::KyberKeys;
         // Create two different key pairs
@@ -122,7 +281,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "ml-dsa", feature = "std"))]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
     fn test_dilithium_pct_failure_mismatched_keys() {
         use crate::generate_dilithium_keypair;
         let (pk1, _sk1) = generate_dilithium_keypair();
@@ -138,7 +297,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "std", feature = "ml-kem", feature = "ml-dsa"))]
+    #[cfg(all(feature = "std", feature = "ml-kem", feature = "ml-dsa", not(feature = "explicit-rng")))]
     fn test_pct_multiple_iterations() {
         use crate::{KyberKeys, generate_dilithium_keypair};
         // Verify PCT works consistently across multiple key generations
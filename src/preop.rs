@@ -7,21 +7,49 @@
 //! 2. Known Answer Tests (KATs) - FIPS mode only
 //! 3. Pair-wise Consistency Tests (PCT) for key generation
 
-use crate::error::Result;
-use crate::cast::run_hash_casts;
-use crate::state::{enter_post_state, enter_operational_state, enter_error_state};
+use crate::error::{PqcError, Result};
+use crate::cast::{sha3_256_cast, sha3_512_cast, shake128_cast, shake256_cast};
+use crate::events::{emit, self_test_result, FipsEvent, SelfTestId};
+use crate::state::{
+    enter_post_state, enter_operational_state, enter_error_state,
+    transition_fips_state, FipsState,
+};
 
 #[cfg(all(feature = "ml-kem", feature = "fips_140_3"))]
 use crate::kat_kyber::run_kyber_decap_kat;
+#[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+use crate::kat_kyber::run_kyber_decap_kat_report;
 
 #[cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
 use crate::kat_dilithium::run_dilithium_verify_kat;
+#[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+use crate::kat_dilithium::run_dilithium_verify_kat_report;
 
-#[cfg(all(feature = "ml-kem", feature = "std"))]
+#[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
 use crate::{pct::kyber_pct, KyberKeys};
 
-#[cfg(all(feature = "ml-dsa", feature = "std"))]
-use crate::{pct::dilithium_pct, generate_dilithium_keypair};
+#[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+use crate::{generate_dilithium_keypair_with_rng, pct::dilithium_pct_with_rng, rng::SeedSource};
+
+#[cfg(feature = "std")]
+use crate::events::SelfTestKind;
+#[cfg(feature = "std")]
+use crate::state::restart_post_state;
+#[cfg(feature = "std")]
+use std::{
+    sync::Mutex,
+    time::Instant,
+    vec::Vec,
+};
+
+#[cfg(not(feature = "std"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Executes one self-test and reports its outcome
+///
+/// The plain POST only emits the event; [`run_post_with_report`] also times
+/// the test and records it.
+type TestRunner<'a> = &'a mut dyn FnMut(SelfTestId, &mut dyn FnMut() -> Result<()>) -> Result<()>;
 
 /// Run complete Pre-Operational Self-Tests (POST)
 /// 
@@ -37,56 +65,293 @@ use crate::{pct::dilithium_pct, generate_dilithium_keypair};
 /// 
 /// Returns Ok(()) if all tests pass, Err otherwise.
 /// On success, module enters Operational state.
-/// On failure, module enters Error state.
+/// On failure, module enters Error state and all registered zeroizers run
+/// (see [`crate::state::zeroize_on_error`]).
+///
+/// POST may start from Uninitialized or Operational. From Error use
+/// [`recover_from_error`]; from Error or while another POST is running this
+/// returns `PqcError::IllegalStateTransition` without touching the state.
 pub fn run_post() -> Result<()> {
     // Enter POST state
-    enter_post_state();
-    
-    // Run all self-tests
-    let result = run_all_self_tests();
+    enter_post_state()?;
     
-    // Update state based on result
+    // Run all self-tests and update state based on result
+    run_entered_post()
+}
+
+/// Self-tests and final state change of a POST whose state is already entered
+pub(crate) fn run_entered_post() -> Result<()> {
+    finish_self_tests(run_all_self_tests(&mut |id, test| self_test_result(id, test())))
+}
+
+/// Recover from the Error state by re-running the full self-test suite
+///
+/// Only valid while the module is in the Error state; any other state is
+/// rejected with `PqcError::IllegalStateTransition` and left unchanged.
+/// On success, module enters Operational state.
+/// On failure, module returns to Error state.
+pub fn recover_from_error() -> Result<()> {
+    if !transition_fips_state(FipsState::Error, FipsState::POST) {
+        return Err(PqcError::IllegalStateTransition);
+    }
+
+    run_entered_post()
+}
+
+/// Process id and result of the POST run by the first [`ensure_initialized`]
+/// call; keyed on the pid so a `fork()`ed child runs its own POST instead of
+/// trusting the parent's cached result
+#[cfg(feature = "std")]
+static FIRST_POST: Mutex<Option<(u32, Result<()>)>> = Mutex::new(None);
+
+/// Set once the first [`ensure_initialized`] call has claimed the POST run
+#[cfg(not(feature = "std"))]
+static FIRST_POST_CLAIMED: AtomicBool = AtomicBool::new(false);
+
+/// Run POST on first use; afterwards a cheap state check
+///
+/// The first call runs [`run_post`] exactly once per process (with `std`,
+/// concurrent first callers block until it finishes, and a `fork()`ed child
+/// counts as a new process) and returns its result. Later
+/// calls return `Ok(())` while the module is Operational and otherwise
+/// report why it is not (the cached POST error, `FipsErrorState`, ...).
+/// POST is never re-run automatically: after a failure use
+/// [`recover_from_error`] or [`reinitialize`].
+///
+/// Without `std` a caller racing the first POST gets the retriable
+/// `FipsPostInProgress` instead of blocking.
+pub fn ensure_initialized() -> Result<()> {
+    if crate::state::is_operational() {
+        return Ok(());
+    }
+    #[cfg(feature = "std")]
+    {
+        let mut first = FIRST_POST.lock().unwrap_or_else(|e| e.into_inner());
+        let pid = std::process::id();
+        let result = match *first {
+            Some((owner, result)) if owner == pid => result,
+            _ => {
+                let result = run_post();
+                *first = Some((pid, result));
+                result
+            }
+        };
+        drop(first);
+        result.and_then(|()| crate::state::check_operational())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        if FIRST_POST_CLAIMED
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            run_post()
+        } else {
+            crate::state::check_operational()
+        }
+    }
+}
+
+/// Serializes [`reinitialize`] calls
+#[cfg(feature = "std")]
+static REINITIALIZE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Reset the module and re-run POST as one step
+///
+/// Replaces the `reset_fips_state(); run_post()` pair, which lets another
+/// thread observe (or act on) the Uninitialized state in between. Here the
+/// module moves from any state straight to POST, so concurrent callers of
+/// `check_operational` only ever see a retriable `FipsPostInProgress`, and
+/// concurrent `reinitialize` calls queue on an internal lock and run one
+/// after the other.
+///
+/// Returns `FipsPostInProgress` if a POST started by [`run_post`] or
+/// [`recover_from_error`] is still running; otherwise the POST result.
+#[cfg(feature = "std")]
+pub fn reinitialize() -> Result<()> {
+    let _guard = REINITIALIZE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    restart_post_state()?;
+    run_entered_post()
+}
+
+/// Move out of the POST state according to the self-test result
+fn finish_self_tests(result: Result<()>) -> Result<()> {
+    #[cfg(feature = "std")]
+    crate::status::record_post(result);
     match result {
         Ok(()) => {
-            enter_operational_state();
+            enter_operational_state()?;
+            emit(FipsEvent::PostPassed);
             Ok(())
         }
         Err(e) => {
             enter_error_state();
+            emit(FipsEvent::PostFailed(e));
             Err(e)
         }
     }
 }
 
 /// Internal function to run all self-tests
-fn run_all_self_tests() -> Result<()> {
+fn run_all_self_tests(run: TestRunner<'_>) -> Result<()> {
+    emit(FipsEvent::PostStarted);
+
     // 1. Hash function CASTs (SHA3-256, SHA3-512, SHAKE-128, SHAKE-256)
-    run_hash_casts()?;
+    run(SelfTestId::Sha3_256, &mut sha3_256_cast)?;
+    run(SelfTestId::Sha3_512, &mut sha3_512_cast)?;
+    run(SelfTestId::Shake128, &mut shake128_cast)?;
+    run(SelfTestId::Shake256, &mut shake256_cast)?;
     
     // 2. Known Answer Tests (KATs) - FIPS mode only
     #[cfg(all(feature = "ml-kem", feature = "fips_140_3"))]
-    run_kyber_decap_kat()?;
+    run(SelfTestId::MlKemKat, &mut run_kyber_decap_kat)?;
     
     #[cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
-    run_dilithium_verify_kat()?;
+    run(SelfTestId::MlDsaKat, &mut run_dilithium_verify_kat)?;
     
     // 3. Pair-wise Consistency Tests (PCTs)
-    // Only run if std feature is enabled (requires RNG)
-    #[cfg(all(feature = "ml-kem", feature = "std"))]
-    {
-        let kyber_keys = KyberKeys::generate_key_pair();
-        kyber_pct(&kyber_keys)?;
-    }
+    // Only run with an implicit RNG (std without `explicit-rng`)
+    #[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+    run(SelfTestId::MlKemPct, &mut || kyber_pct(&KyberKeys::generate_key_pair()))?;
     
-    #[cfg(all(feature = "ml-dsa", feature = "std"))]
-    {
-        let (dil_pk, dil_sk) = generate_dilithium_keypair();
-        dilithium_pct(&dil_pk, &dil_sk)?;
-    }
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+    run(SelfTestId::MlDsaPct, &mut || mldsa_post_pct(&mut rand::rngs::OsRng))?;
     
     Ok(())
 }
 
+/// POST ML-DSA PCT: key seed, message and hedge all drawn from `rng`
+///
+/// Signing a fresh message on every POST exercises the signing path over
+/// varied input rather than replaying the fixed `PCT_MESSAGE`.
+#[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+fn mldsa_post_pct<R: SeedSource + ?Sized>(rng: &mut R) -> Result<()> {
+    let (dil_pk, dil_sk) = generate_dilithium_keypair_with_rng(rng)?;
+    dilithium_pct_with_rng(&dil_pk, &dil_sk, rng)
+}
+
+/// One self-test executed during [`run_post_with_report`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SelfTestRecord {
+    pub test: SelfTestId,
+    pub kind: SelfTestKind,
+    pub passed: bool,
+    /// Wall-clock time spent in the test, in nanoseconds
+    pub duration_ns: u64,
+}
+
+/// Machine-readable record of one POST run, for CMVP evidence
+///
+/// With the `serde` feature this serializes (e.g. with `serde_json`) to a
+/// self-describing JSON document.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SelfTestReport {
+    /// Crate version (`CARGO_PKG_VERSION`)
+    pub module_version: &'static str,
+    /// Whether the `fips_140_3` feature was compiled in
+    pub fips_mode: bool,
+    /// Tests in execution order; stops at the first failure
+    pub tests: Vec<SelfTestRecord>,
+    /// The error POST failed with, if any
+    pub error: Option<PqcError>,
+}
+
+#[cfg(feature = "std")]
+impl SelfTestReport {
+    fn new() -> Self {
+        Self {
+            module_version: env!("CARGO_PKG_VERSION"),
+            fips_mode: crate::info::is_fips_mode(),
+            tests: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// `true` if POST completed with every test passing
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A failed [`run_post_with_report`]: the error plus the partial report
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostFailure {
+    pub error: PqcError,
+    pub report: SelfTestReport,
+}
+
+#[cfg(feature = "std")]
+impl From<PostFailure> for PqcError {
+    fn from(failure: PostFailure) -> Self {
+        failure.error
+    }
+}
+
+/// [`run_post`] that also records every CAST/KAT/PCT it executed
+///
+/// State transitions and events are identical to [`run_post`]. On failure
+/// the report covers the tests run up to and including the failing one.
+#[cfg(feature = "std")]
+pub fn run_post_with_report() -> core::result::Result<SelfTestReport, PostFailure> {
+    let mut report = SelfTestReport::new();
+    let result = enter_post_state().and_then(|()| {
+        finish_self_tests(run_all_self_tests(&mut |id, test| {
+            let start = Instant::now();
+            let result = test();
+            let elapsed = start.elapsed().as_nanos();
+            report.tests.push(SelfTestRecord {
+                test: id,
+                kind: id.kind(),
+                passed: result.is_ok(),
+                duration_ns: u64::try_from(elapsed).unwrap_or(u64::MAX),
+            });
+            self_test_result(id, result)
+        }))
+    });
+    match result {
+        Ok(()) => Ok(report),
+        Err(error) => {
+            report.error = Some(error);
+            Err(PostFailure { error, report })
+        }
+    }
+}
+
+/// One KAT vector and a fingerprint of its expected outputs
+#[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KatFingerprint {
+    pub name: &'static str,
+    /// See [`crate::kat::KatVector::fingerprint`]
+    pub fingerprint: [u8; 32],
+}
+
+/// Which KAT vectors ran for one algorithm
+///
+/// `vectors` has one entry per built-in vector, in table order; its length
+/// grows as vectors are added.
+#[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KatReport {
+    pub algorithm: &'static str,
+    pub vectors: alloc::vec::Vec<KatFingerprint>,
+}
+
+/// Run the ML-KEM and ML-DSA KATs and return diagnostic summaries
+///
+/// For troubleshooting and audit records only: POST itself reports through
+/// [`crate::events`] and never writes to stdout. Does not change the FIPS
+/// state.
+#[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+pub fn kat_report() -> Result<[KatReport; 2]> {
+    Ok([run_kyber_decap_kat_report()?, run_dilithium_verify_kat_report()?])
+}
+
 /// Run POST and panic on failure (for FIPS strict mode)
 /// 
 /// Use this in applications that require FIPS mode and should not
@@ -98,10 +363,11 @@ pub fn run_post_or_panic() {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::{get_fips_state, FipsState, reset_fips_state};
+    use crate::state::{get_fips_state, lock_state_for_test, reset_fips_state};
 
     #[test]
     fn test_post_success() {
+        let _guard = lock_state_for_test();
         reset_fips_state();
         
         let result = run_post();
@@ -111,6 +377,7 @@ mod tests {
 
     #[test]
     fn test_post_state_transitions() {
+        let _guard = lock_state_for_test();
         reset_fips_state();
         assert_eq!(get_fips_state(), FipsState::Uninitialized);
         
@@ -121,6 +388,7 @@ mod tests {
 
     #[test]
     fn test_post_repeatable() {
+        let _guard = lock_state_for_test();
         // POST should be able to run multiple times
         for _ in 0..5 {
             reset_fips_state();
@@ -132,8 +400,149 @@ mod tests {
     #[cfg(feature = "std")]
     #[test]
     fn test_post_or_panic_success() {
+        let _guard = lock_state_for_test();
         reset_fips_state();
         run_post_or_panic(); // Should not panic
         assert_eq!(get_fips_state(), FipsState::Operational);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_post_rejected_from_error_state() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        enter_error_state();
+
+        assert_eq!(run_post(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::Error);
+        reset_fips_state();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_reinitialize() {
+        let _guard = lock_state_for_test();
+
+        for start in [FipsState::Uninitialized, FipsState::Operational, FipsState::Error] {
+            reset_fips_state();
+            match start {
+                FipsState::Operational => run_post().unwrap(),
+                FipsState::Error => enter_error_state(),
+                _ => {}
+            }
+            assert_eq!(reinitialize(), Ok(()));
+            assert_eq!(get_fips_state(), FipsState::Operational);
+        }
+
+        // Concurrent callers serialize; nobody observes Uninitialized
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    for _ in 0..3 {
+                        assert_eq!(reinitialize(), Ok(()));
+                        assert_ne!(get_fips_state(), FipsState::Uninitialized);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert_eq!(get_fips_state(), FipsState::Operational);
+
+        enter_post_state().unwrap();
+        assert_eq!(reinitialize(), Err(PqcError::FipsPostInProgress));
+        assert_eq!(get_fips_state(), FipsState::POST);
+        reset_fips_state();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_post_with_report() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+
+        let report = run_post_with_report().unwrap();
+        assert!(report.passed());
+        assert_eq!(get_fips_state(), FipsState::Operational);
+        assert_eq!(report.module_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(report.tests[0].test, SelfTestId::Sha3_256);
+        assert!(report.tests.iter().all(|t| t.passed));
+        #[cfg(feature = "fips_140_3")]
+        assert!(report.tests.iter().any(|t| t.kind == SelfTestKind::Kat));
+        #[cfg(all(any(feature = "ml-kem", feature = "ml-dsa"), not(feature = "explicit-rng")))]
+        assert_eq!(report.tests.last().unwrap().kind, SelfTestKind::Pct);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&report).unwrap();
+            assert_eq!(json["tests"][0]["test"], "Sha3_256");
+            assert_eq!(json["tests"][0]["kind"], "Cast");
+            assert_eq!(json["error"], serde_json::Value::Null);
+        }
+
+        // A POST that cannot start still yields an (empty) report
+        enter_error_state();
+        let failure = run_post_with_report().unwrap_err();
+        assert_eq!(failure.error, PqcError::IllegalStateTransition);
+        assert!(failure.report.tests.is_empty());
+        assert_eq!(failure.report.error, Some(PqcError::IllegalStateTransition));
+        reset_fips_state();
+    }
+
+    #[test]
+    #[cfg(all(feature = "fips_140_3", feature = "alloc"))]
+    fn test_kat_report() {
+        let report = kat_report().unwrap();
+        assert_eq!(report[0].algorithm, "ML-KEM-1024");
+        assert_eq!(report[1].algorithm, "ML-DSA-65");
+        assert_eq!(report[0].vectors.len(), crate::kat_kyber::VECTORS.len());
+        assert_eq!(report[1].vectors.len(), crate::kat_dilithium::VECTORS.len());
+        assert_eq!(report[1].vectors[1].name, "sign");
+        assert_ne!(report[0].vectors[0].fingerprint, report[0].vectors[1].fingerprint);
+        assert_eq!(kat_report().unwrap(), report);
+    }
+
+    #[test]
+    fn test_recover_from_error_succeeds() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        enter_error_state();
+
+        assert!(recover_from_error().is_ok());
+        assert_eq!(get_fips_state(), FipsState::Operational);
+    }
+
+    #[test]
+    fn test_recover_from_error_rejected_outside_error_state() {
+        let _guard = lock_state_for_test();
+
+        reset_fips_state();
+        assert_eq!(recover_from_error(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::Uninitialized);
+
+        enter_post_state().unwrap();
+        assert_eq!(recover_from_error(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::POST);
+
+        enter_operational_state().unwrap();
+        assert_eq!(recover_from_error(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::Operational);
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+    fn test_mldsa_post_pct_draws_message_from_rng() {
+        struct Recording(Vec<usize>);
+        impl SeedSource for Recording {
+            fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+                self.0.push(dest.len());
+                rand::rngs::OsRng.fill(dest)
+            }
+        }
+
+        // Key seed, PCT message, signing hedge
+        let mut rng = Recording(Vec::new());
+        mldsa_post_pct(&mut rng).unwrap();
+        assert_eq!(rng.0, [32, 32, 32]);
+    }
+}
@@ -1,35 +1,325 @@
 // src/rng.rs
+use core::sync::atomic::{AtomicUsize, Ordering};
+
 use zeroize::Zeroize;
 
+use crate::error::{PqcError, Result};
+
 #[cfg(feature = "std")]
 use rand::RngCore;
 
-/// Generate 32-byte seed (std only; for encap/sign)
+/// Source of seed material for key generation, encapsulation and signing
+///
+/// Deliberately independent of any `rand_core` version: implement it for
+/// your own entropy source, or wrap a `rand_core` 0.6 / 0.9 RNG in
+/// [`RandCore06`] / [`RandCore09`] (features `rand_core_06` / `rand_core_09`).
+/// With `std`, every `rand` 0.8 `RngCore + CryptoRng` is a `SeedSource`
+/// as it is.
+pub trait SeedSource {
+    /// Fill `dest` entirely with fresh randomness
+    fn fill(&mut self, dest: &mut [u8]) -> Result<()>;
+
+    /// Pull fresh entropy into the source's internal state
+    ///
+    /// Called by [`Reseeding`] after [`request_reseed`] or a detected
+    /// `fork()`. Sources without internal state (e.g. `OsRng`) keep the
+    /// default no-op; DRBGs must override it.
+    fn reseed(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Bumped by [`request_reseed`]; each [`Reseeding`] remembers the last value it saw
+static RESEED_EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+/// Ask every [`Reseeding`] source to reseed before its next output
+///
+/// Also called whenever the module enters the Operational state, so a
+/// completed POST or error recovery never reuses pre-existing DRBG state.
+pub fn request_reseed() {
+    RESEED_EPOCH.fetch_add(1, Ordering::AcqRel);
+}
+
 #[cfg(feature = "std")]
-pub fn generate_seed_32() -> [u8; 32] {
+fn current_pid() -> u32 {
+    std::process::id()
+}
+
+/// Wrapper that reseeds an injected source on request and after `fork()`
+///
+/// Before each `fill`, the inner source is reseeded if [`request_reseed`]
+/// was called since its last reseed or (under `std`) the process id has
+/// changed. If that reseed fails, `fill` returns the error and produces
+/// no output, so a forked child can never replay its parent's stream.
+pub struct Reseeding<R> {
+    inner: R,
+    epoch: usize,
+    #[cfg(feature = "std")]
+    pid: u32,
+}
+
+impl<R: SeedSource> Reseeding<R> {
+    /// Wrap a freshly seeded source
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            epoch: RESEED_EPOCH.load(Ordering::Acquire),
+            #[cfg(feature = "std")]
+            pid: current_pid(),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn reseed_if_stale(&mut self) -> Result<()> {
+        let epoch = RESEED_EPOCH.load(Ordering::Acquire);
+        #[cfg(feature = "std")]
+        let stale = epoch != self.epoch || current_pid() != self.pid;
+        #[cfg(not(feature = "std"))]
+        let stale = epoch != self.epoch;
+        if stale {
+            self.reseed()?;
+        }
+        Ok(())
+    }
+}
+
+impl<R: SeedSource> SeedSource for Reseeding<R> {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+        self.reseed_if_stale()?;
+        self.inner.fill(dest)
+    }
+
+    fn reseed(&mut self) -> Result<()> {
+        // Read the epoch first so a request racing with this reseed is not lost
+        let epoch = RESEED_EPOCH.load(Ordering::Acquire);
+        self.inner.reseed()?;
+        self.epoch = epoch;
+        #[cfg(feature = "std")]
+        {
+            self.pid = current_pid();
+        }
+        Ok(())
+    }
+}
+
+/// Any `rand` 0.8 cryptographic RNG (`OsRng`, `thread_rng()`, `StdRng`, ...)
+///
+/// Lets `*_with_rng` functions take `&mut rand::thread_rng()` directly.
+/// The `CryptoRng` bound keeps non-cryptographic generators out.
+#[cfg(feature = "std")]
+impl<R: RngCore + rand::CryptoRng> SeedSource for R {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+        self.try_fill_bytes(dest).map_err(|_| PqcError::RngFailure)
+    }
+}
+
+/// Adapter for a `rand_core` 0.6 cryptographic RNG
+#[cfg(feature = "rand_core_06")]
+pub struct RandCore06<R>(pub R);
+
+#[cfg(feature = "rand_core_06")]
+impl<R: rand_core_06::RngCore + rand_core_06::CryptoRng> SeedSource for RandCore06<R> {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+        self.0.try_fill_bytes(dest).map_err(|_| PqcError::RngFailure)
+    }
+}
+
+/// Adapter for a `rand_core` 0.9 cryptographic RNG
+#[cfg(feature = "rand_core_09")]
+pub struct RandCore09<R>(pub R);
+
+#[cfg(feature = "rand_core_09")]
+impl<R: rand_core_09::TryCryptoRng> SeedSource for RandCore09<R> {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+        self.0.try_fill_bytes(dest).map_err(|_| PqcError::RngFailure)
+    }
+}
+
+/// Domain separator for every [`ShakeRng`] SHAKE256 call
+const SHAKE_RNG_LABEL: &[u8] = b"pqc-combo ShakeRng v1";
+/// Shortest entropy block [`ShakeRng`] accepts (256 bits)
+pub const SHAKE_RNG_MIN_ENTROPY_BYTES: usize = 32;
+const SHAKE_RNG_STATE_BYTES: usize = 64;
+
+/// SHAKE256 generator for targets with no OS RNG, seeded once by the integrator
+///
+/// ```text
+/// new:        state = SHAKE256(label || 0x00 || entropy)[..64]
+/// fill(dest): state || dest = SHAKE256(label || 0x01 || state)[..64 + len]
+/// reseed_with: state = SHAKE256(label || 0x02 || state || entropy)[..64]
+/// ```
+///
+/// Every `fill` replaces the state, so a later compromise of the state
+/// does not reveal earlier output. The construction adds no entropy: **the
+/// integrator is responsible for the seed**, which must be at least
+/// [`SHAKE_RNG_MIN_ENTROPY_BYTES`] of full-entropy output from a hardware
+/// source and must never be reused across devices or boots. The same seed
+/// gives the same keys and signatures.
+///
+/// There is nothing to pull fresh entropy from on its own, so
+/// [`SeedSource::reseed`] fails with `RngFailure`; a [`Reseeding`] wrapper
+/// therefore stops output rather than continue unrefreshed. Feed new
+/// entropy with [`ShakeRng::reseed_with`] instead.
+pub struct ShakeRng {
+    state: [u8; SHAKE_RNG_STATE_BYTES],
+}
+
+impl ShakeRng {
+    /// Seed from an entropy block; `InvalidSeed` if it is shorter than
+    /// [`SHAKE_RNG_MIN_ENTROPY_BYTES`] or all zero (a stuck register)
+    pub fn new(entropy: &[u8]) -> Result<Self> {
+        check_entropy_block(entropy)?;
+        let mut rng = Self { state: [0u8; SHAKE_RNG_STATE_BYTES] };
+        rng.absorb(0x00, &[entropy]);
+        Ok(rng)
+    }
+
+    /// Mix a further entropy block into the state
+    pub fn reseed_with(&mut self, entropy: &[u8]) -> Result<()> {
+        check_entropy_block(entropy)?;
+        let mut state = self.state;
+        self.absorb(0x02, &[&state, entropy]);
+        state.zeroize();
+        Ok(())
+    }
+
+    /// `state = SHAKE256(label || step || inputs)[..64]`
+    fn absorb(&mut self, step: u8, inputs: &[&[u8]]) {
+        use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+        let mut xof = sha3::Shake256::default();
+        xof.update(SHAKE_RNG_LABEL);
+        xof.update(&[step]);
+        for input in inputs {
+            xof.update(input);
+        }
+        xof.finalize_xof().read(&mut self.state);
+    }
+}
+
+fn check_entropy_block(entropy: &[u8]) -> Result<()> {
+    if entropy.len() < SHAKE_RNG_MIN_ENTROPY_BYTES || entropy.iter().all(|&b| b == 0) {
+        return Err(PqcError::InvalidSeed);
+    }
+    Ok(())
+}
+
+impl SeedSource for ShakeRng {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+        use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+        let mut xof = sha3::Shake256::default();
+        xof.update(SHAKE_RNG_LABEL);
+        xof.update(&[0x01]);
+        xof.update(&self.state);
+        let mut reader = xof.finalize_xof();
+        reader.read(&mut self.state);
+        reader.read(dest);
+        Ok(())
+    }
+
+    fn reseed(&mut self) -> Result<()> {
+        Err(PqcError::RngFailure)
+    }
+}
+
+impl Drop for ShakeRng {
+    fn drop(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+/// Seeds that reached the stuck-output check in `seed_*_from`
+static SEEDS_CHECKED: AtomicUsize = AtomicUsize::new(0);
+/// Seeds rejected by that check
+static STUCK_OUTPUTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Draw a 32-byte seed from `rng`
+///
+/// An all-zero result is treated as a broken source (`RngFailure`).
+pub fn seed_32_from<R: SeedSource + ?Sized>(rng: &mut R) -> Result<[u8; 32]> {
     let mut seed = [0u8; 32];
-    rand::rngs::OsRng.try_fill_bytes(&mut seed).expect("OsRng failed");
-    seed
+    rng.fill(&mut seed)?;
+    check_stuck_output(&seed)?;
+    Ok(seed)
 }
 
-/// Generate 64-byte seed (std only; for keygen)
+/// Draw a 64-byte seed from `rng`
+///
+/// An all-zero result is treated as a broken source (`RngFailure`).
+pub fn seed_64_from<R: SeedSource + ?Sized>(rng: &mut R) -> Result<[u8; 64]> {
+    let mut seed = [0u8; 64];
+    rng.fill(&mut seed)?;
+    check_stuck_output(&seed)?;
+    Ok(seed)
+}
+
+fn check_stuck_output(seed: &[u8]) -> Result<()> {
+    SEEDS_CHECKED.fetch_add(1, Ordering::Relaxed);
+    if seed.iter().all(|&b| b == 0) {
+        STUCK_OUTPUTS.fetch_add(1, Ordering::Relaxed);
+        return Err(PqcError::RngFailure);
+    }
+    Ok(())
+}
+
+/// `(seeds checked, seeds rejected as stuck)` since process start
 #[cfg(feature = "std")]
+pub(crate) fn stuck_output_counts() -> (usize, usize) {
+    (SEEDS_CHECKED.load(Ordering::Relaxed), STUCK_OUTPUTS.load(Ordering::Relaxed))
+}
+
+/// Generate 32-byte seed (std only; for encap/sign)
+#[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+pub fn generate_seed_32() -> [u8; 32] {
+    implicit_seed(|rng| seed_32_from(rng))
+}
+
+/// Generate 64-byte seed (std only; for keygen)
+#[cfg(all(feature = "std", not(feature = "explicit-rng")))]
 pub fn generate_seed_64() -> [u8; 64] {
-    let mut seed = [0u8; 64];
-    rand::rngs::OsRng.try_fill_bytes(&mut seed).expect("OsRng failed");
-    seed
+    implicit_seed(|rng| seed_64_from(rng))
 }
 
-/// Validate 32-byte seed
-pub fn validate_seed_32(seed: &[u8; 32]) {
+/// Seed from the OS RNG, or from a `testing::with_test_rng` source
+#[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+fn implicit_seed<T>(draw: fn(&mut dyn SeedSource) -> Result<T>) -> T {
+    #[cfg(feature = "testing")]
+    if let Some(seed) = crate::testing::draw_installed(draw) {
+        return seed.expect("installed test RNG failed");
+    }
+    draw(&mut rand::rngs::OsRng).expect("OsRng failed")
+}
+
+/// Check a caller-supplied 32-byte seed; all zero is `InvalidSeed`
+pub fn check_seed_32(seed: &[u8; 32]) -> Result<()> {
     if seed.iter().all(|&b| b == 0) {
+        return Err(PqcError::InvalidSeed);
+    }
+    Ok(())
+}
+
+/// Check a caller-supplied 64-byte seed; all zero is `InvalidSeed`
+pub fn check_seed_64(seed: &[u8; 64]) -> Result<()> {
+    if seed.iter().all(|&b| b == 0) {
+        return Err(PqcError::InvalidSeed);
+    }
+    Ok(())
+}
+
+/// Validate 32-byte seed (panics; see [`check_seed_32`])
+pub fn validate_seed_32(seed: &[u8; 32]) {
+    if check_seed_32(seed).is_err() {
         panic!("Zero seed invalid");
     }
 }
 
-/// Validate 64-byte seed
+/// Validate 64-byte seed (panics; see [`check_seed_64`])
 pub fn validate_seed_64(seed: &[u8; 64]) {
-    if seed.iter().all(|&b| b == 0) {
+    if check_seed_64(seed).is_err() {
         panic!("Zero seed invalid");
     }
 }
@@ -50,4 +340,188 @@ impl Drop for SecureSeed32 {
     fn drop(&mut self) {
         self.0.zeroize();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shake_rng_is_deterministic() {
+        let entropy = [0x3c; SHAKE_RNG_MIN_ENTROPY_BYTES];
+        let (mut a, mut b) = (ShakeRng::new(&entropy).unwrap(), ShakeRng::new(&entropy).unwrap());
+        let (mut x, mut y) = ([0u8; 40], [0u8; 40]);
+        a.fill(&mut x).unwrap();
+        b.fill(&mut y).unwrap();
+        assert_eq!(x, y);
+        // Pins the construction: a change here breaks every seeded deployment
+        assert_eq!(x[..8], [0xad, 0x05, 0x33, 0x3d, 0xcd, 0x39, 0x19, 0x47]);
+
+        // The state moves on after every call, and other entropy gives another stream
+        a.fill(&mut y).unwrap();
+        assert_ne!(x, y);
+        ShakeRng::new(&[0x3d; SHAKE_RNG_MIN_ENTROPY_BYTES]).unwrap().fill(&mut y).unwrap();
+        assert_ne!(x, y);
+        b.reseed_with(&[0x3e; 32]).unwrap();
+        a.fill(&mut x).unwrap();
+        b.fill(&mut y).unwrap();
+        assert_ne!(x, y);
+
+        assert!(ShakeRng::new(&[0x3c; SHAKE_RNG_MIN_ENTROPY_BYTES - 1]).is_err());
+        assert!(ShakeRng::new(&[0u8; 64]).is_err());
+        assert_eq!(a.reseed(), Err(PqcError::RngFailure));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+    fn test_shake_rng_drives_with_rng_apis() {
+        let keygen = |entropy: &[u8]| {
+            let mut rng = ShakeRng::new(entropy).unwrap();
+            let keys = crate::KyberKeys::generate_key_pair_with_rng(&mut rng).unwrap();
+            let (pk, _) = crate::generate_dilithium_keypair_with_rng(&mut rng).unwrap();
+            (keys.pk, pk)
+        };
+        let (kem_a, dsa_a) = keygen(&[0x51; 48]);
+        let (kem_b, dsa_b) = keygen(&[0x51; 48]);
+        assert!(kem_a == kem_b && dsa_a == dsa_b);
+    }
+
+    /// Deterministic counter source for tests
+    struct Counter(u8);
+
+    impl SeedSource for Counter {
+        fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+            for b in dest.iter_mut() {
+                self.0 = self.0.wrapping_add(1);
+                *b = self.0;
+            }
+            Ok(())
+        }
+    }
+
+    struct Broken;
+
+    impl SeedSource for Broken {
+        fn fill(&mut self, _dest: &mut [u8]) -> Result<()> {
+            Err(PqcError::RngFailure)
+        }
+    }
+
+    struct Zeros;
+
+    impl SeedSource for Zeros {
+        fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+            dest.fill(0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_seed_from_custom_source() {
+        let seed = seed_32_from(&mut Counter(0)).unwrap();
+        assert_eq!(seed[0], 1);
+        assert_eq!(seed[31], 32);
+        assert_eq!(seed_64_from(&mut Counter(0)).unwrap()[63], 64);
+    }
+
+    #[test]
+    fn test_check_seed() {
+        assert_eq!(check_seed_32(&[0u8; 32]), Err(PqcError::InvalidSeed));
+        assert_eq!(check_seed_64(&[0u8; 64]), Err(PqcError::InvalidSeed));
+        assert!(check_seed_32(&[1u8; 32]).is_ok());
+        let mut seed = [0u8; 64];
+        seed[63] = 1;
+        assert!(check_seed_64(&seed).is_ok());
+    }
+
+    #[test]
+    fn test_seed_source_failures() {
+        assert_eq!(seed_32_from(&mut Broken), Err(PqcError::RngFailure));
+        assert_eq!(seed_64_from(&mut Zeros), Err(PqcError::RngFailure));
+    }
+
+    /// Counter source that records reseeds and can refuse them
+    struct Drbg {
+        counter: Counter,
+        reseeds: usize,
+        healthy: bool,
+    }
+
+    impl SeedSource for Drbg {
+        fn fill(&mut self, dest: &mut [u8]) -> Result<()> {
+            self.counter.fill(dest)
+        }
+
+        fn reseed(&mut self) -> Result<()> {
+            if !self.healthy {
+                return Err(PqcError::RngFailure);
+            }
+            self.reseeds += 1;
+            self.counter.0 = self.counter.0.wrapping_add(0x80);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_request_reseed() {
+        // POST also requests reseeds; keep it from running concurrently
+        let _guard = crate::state::lock_state_for_test();
+        let mut rng = Reseeding::new(Drbg { counter: Counter(0), reseeds: 0, healthy: true });
+        seed_32_from(&mut rng).unwrap();
+        assert_eq!(rng.inner.reseeds, 0);
+
+        request_reseed();
+        seed_32_from(&mut rng).unwrap();
+        seed_32_from(&mut rng).unwrap();
+        assert_eq!(rng.inner.reseeds, 1);
+
+        rng.inner.healthy = false;
+        request_reseed();
+        assert_eq!(seed_32_from(&mut rng), Err(PqcError::RngFailure));
+        // Still stale: no output until a reseed succeeds
+        assert_eq!(seed_32_from(&mut rng), Err(PqcError::RngFailure));
+        rng.inner.healthy = true;
+        assert!(seed_32_from(&mut rng).is_ok());
+        assert_eq!(rng.into_inner().reseeds, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_os_rng_seed_source() {
+        let a = seed_32_from(&mut rand::rngs::OsRng).unwrap();
+        let b = seed_32_from(&mut rand::rngs::OsRng).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    #[cfg(feature = "rand_core_06")]
+    fn test_rand_core_06_adapter() {
+        struct Fixed;
+        impl rand_core_06::RngCore for Fixed {
+            fn next_u32(&mut self) -> u32 { 0x0101_0101 }
+            fn next_u64(&mut self) -> u64 { 0x0101_0101_0101_0101 }
+            fn fill_bytes(&mut self, dest: &mut [u8]) { dest.fill(1) }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> core::result::Result<(), rand_core_06::Error> {
+                dest.fill(1);
+                Ok(())
+            }
+        }
+        impl rand_core_06::CryptoRng for Fixed {}
+
+        assert_eq!(seed_32_from(&mut RandCore06(Fixed)).unwrap(), [1u8; 32]);
+    }
+
+    #[test]
+    #[cfg(feature = "rand_core_09")]
+    fn test_rand_core_09_adapter() {
+        struct Fixed;
+        impl rand_core_09::RngCore for Fixed {
+            fn next_u32(&mut self) -> u32 { 0x0202_0202 }
+            fn next_u64(&mut self) -> u64 { 0x0202_0202_0202_0202 }
+            fn fill_bytes(&mut self, dest: &mut [u8]) { dest.fill(2) }
+        }
+        impl rand_core_09::CryptoRng for Fixed {}
+
+        assert_eq!(seed_32_from(&mut RandCore09(Fixed)).unwrap(), [2u8; 32]);
+    }
+}
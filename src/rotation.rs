@@ -0,0 +1,141 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Key Rotation: Replace a Key Pair and Wipe the Retired Secret
+// ------------------------------------------------------------------------
+//! Rotation takes the old key pair by value, so the retired secret cannot
+//! be used afterwards, and returns a fresh pair that has passed its
+//! pairwise consistency test:
+//!
+//! 1. generate the new pair and run its PCT;
+//! 2. wipe the old secret key;
+//! 3. return the new pair.
+//!
+//! The old secret is wiped even when step 1 fails: once a rotation has
+//! been asked for, the retired key is never handed back. A caller that
+//! must keep serving through a failed rotation should keep an exported
+//! copy, subject to the CSP export policy.
+
+use zeroize::Zeroize;
+
+use crate::error::Result;
+use crate::rng::SeedSource;
+#[cfg(feature = "ml-dsa")]
+use crate::DilithiumKeypair;
+#[cfg(feature = "ml-kem")]
+use crate::KyberKeys;
+
+/// Replace `old` with a fresh PCT-checked ML-KEM-1024 pair (OS randomness)
+#[cfg(all(feature = "ml-kem", feature = "std", not(feature = "explicit-rng")))]
+pub fn rotate_kyber(old: KyberKeys) -> Result<KyberKeys> {
+    retire_kyber(old, KyberKeys::generate_key_pair_with_pct())
+}
+
+/// [`rotate_kyber`] with seed and PCT randomness from `rng`
+#[cfg(feature = "ml-kem")]
+pub fn rotate_kyber_with_rng<R: SeedSource + ?Sized>(old: KyberKeys, rng: &mut R) -> Result<KyberKeys> {
+    retire_kyber(old, KyberKeys::generate_key_pair_with_rng_and_pct(rng))
+}
+
+/// Replace `old` with a fresh PCT-checked ML-DSA-65 pair (OS randomness)
+#[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng")))]
+pub fn rotate_dilithium(old: DilithiumKeypair) -> Result<DilithiumKeypair> {
+    retire_dilithium(old, DilithiumKeypair::generate_with_pct())
+}
+
+/// [`rotate_dilithium`] with seed and PCT randomness from `rng`
+#[cfg(feature = "ml-dsa")]
+pub fn rotate_dilithium_with_rng<R: SeedSource + ?Sized>(
+    old: DilithiumKeypair,
+    rng: &mut R,
+) -> Result<DilithiumKeypair> {
+    retire_dilithium(old, DilithiumKeypair::generate_with_rng_and_pct(rng))
+}
+
+#[cfg(feature = "ml-kem")]
+fn retire_kyber(mut old: KyberKeys, new: Result<KyberKeys>) -> Result<KyberKeys> {
+    old.sk.zeroize();
+    #[cfg(all(test, feature = "std"))]
+    tests::observe_retired(old.sk.as_slice());
+    new
+}
+
+#[cfg(feature = "ml-dsa")]
+fn retire_dilithium(mut old: DilithiumKeypair, new: Result<DilithiumKeypair>) -> Result<DilithiumKeypair> {
+    old.sk.zeroize();
+    #[cfg(all(test, feature = "std"))]
+    tests::observe_retired(old.sk.as_slice());
+    new
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PqcError;
+
+    struct Broken;
+
+    impl SeedSource for Broken {
+        fn fill(&mut self, _dest: &mut [u8]) -> Result<()> {
+            Err(PqcError::RngFailure)
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "std"))]
+    fn test_rotate_kyber() {
+        let old = KyberKeys::generate_key_pair_with_seed([0xc1; 64]);
+        let old_pk = old.pk.clone();
+        let new = rotate_kyber_with_rng(old, &mut rand::rngs::OsRng).unwrap();
+        assert!(new.pk != old_pk);
+        assert!(crate::pct::kyber_pct_with_rng(&new, &mut rand::rngs::OsRng).is_ok());
+
+        let old = KyberKeys::generate_key_pair_with_seed([0xc2; 64]);
+        assert!(matches!(rotate_kyber_with_rng(old, &mut Broken), Err(PqcError::RngFailure)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", feature = "std"))]
+    fn test_rotate_dilithium() {
+        let old = DilithiumKeypair::generate_with_seed([0xc3; 32]);
+        let old_pk = old.pk.clone();
+        let new = rotate_dilithium_with_rng(old, &mut rand::rngs::OsRng).unwrap();
+        assert!(new.pk != old_pk);
+
+        let old = DilithiumKeypair::generate_with_seed([0xc4; 32]);
+        assert!(matches!(rotate_dilithium_with_rng(old, &mut Broken), Err(PqcError::RngFailure)));
+    }
+
+    #[cfg(feature = "std")]
+    std::thread_local! {
+        /// Whether the last secret retired on this thread was all zero
+        static RETIRED_WIPED: core::cell::Cell<Option<bool>> = const { core::cell::Cell::new(None) };
+    }
+
+    /// Called by the retire step with the old secret, just before it is dropped
+    #[cfg(feature = "std")]
+    pub(super) fn observe_retired(sk: &[u8]) {
+        RETIRED_WIPED.with(|wiped| wiped.set(Some(sk.iter().all(|&b| b == 0))));
+    }
+
+    #[cfg(feature = "std")]
+    fn take_retired() -> Option<bool> {
+        RETIRED_WIPED.with(|wiped| wiped.take())
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "std"))]
+    fn test_retired_secret_is_wiped() {
+        let old = KyberKeys::generate_key_pair_with_seed([0xc5; 64]);
+        rotate_kyber_with_rng(old, &mut rand::rngs::OsRng).unwrap();
+        assert_eq!(take_retired(), Some(true));
+
+        // Also when the replacement could not be generated
+        let old = KyberKeys::generate_key_pair_with_seed([0xc6; 64]);
+        assert!(rotate_kyber_with_rng(old, &mut Broken).is_err());
+        assert_eq!(take_retired(), Some(true));
+
+        let old = DilithiumKeypair::generate_with_seed([0xc7; 32]);
+        assert!(rotate_dilithium_with_rng(old, &mut Broken).is_err());
+        assert_eq!(take_retired(), Some(true));
+    }
+}
@@ -0,0 +1,171 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Digital Signature Re-exports and Signing Context
+// ------------------------------------------------------------------------
+//! ML-DSA-65 (FIPS 204) surface, available with the `ml-dsa` feature.
+//!
+//! `use pqc_combo::sig::prelude::*;` brings in exactly the signature types,
+//! functions and constants.
+//!
+//! [`SigningContext`] bundles the FIPS 204 context string with signing
+//! limits for services that sign untrusted requests.
+
+use crate::error::{PqcError, Result};
+use crate::rng::{self, SeedSource};
+use crate::{
+    sign_message_deterministic, sign_message_with_context_and_randomness, DilithiumSecretKey,
+    DilithiumSignature, ML_DSA_MAX_CONTEXT_BYTES, ML_DSA_SIGN_SEED_BYTES,
+};
+
+/// Context string plus limits applied to every signature made through it
+///
+/// `max_message_len` bounds how much input a single one-shot call will hash,
+/// so an untrusted caller cannot make the service sign arbitrarily large
+/// buffers; longer messages return `MessageTooLarge` before any hashing.
+/// The default is unbounded. Inputs that are legitimately large should go
+/// through the streaming `prehash::SigningHasher` (HashML-DSA, `acvp`
+/// feature), which absorbs them chunk by chunk without buffering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SigningContext<'a> {
+    ctx: &'a [u8],
+    max_message_len: Option<usize>,
+}
+
+impl<'a> SigningContext<'a> {
+    /// Empty context string, no message limit
+    pub const fn new() -> Self {
+        Self { ctx: &[], max_message_len: None }
+    }
+
+    /// Use the FIPS 204 context string `ctx` (at most 255 bytes)
+    pub fn with_context(self, ctx: &'a [u8]) -> Result<Self> {
+        if ctx.len() > ML_DSA_MAX_CONTEXT_BYTES {
+            return Err(PqcError::ContextTooLong);
+        }
+        Ok(Self { ctx, ..self })
+    }
+
+    /// Reject messages longer than `max` bytes
+    pub const fn with_max_message_len(self, max: usize) -> Self {
+        Self { max_message_len: Some(max), ..self }
+    }
+
+    /// Configured context string
+    pub fn context(&self) -> &'a [u8] {
+        self.ctx
+    }
+
+    /// Configured message limit, `None` if unbounded
+    pub fn max_message_len(&self) -> Option<usize> {
+        self.max_message_len
+    }
+
+    /// `MessageTooLarge` if `len` exceeds the configured limit
+    pub fn check_message_len(&self, len: usize) -> Result<()> {
+        match self.max_message_len {
+            Some(max) if len > max => Err(PqcError::MessageTooLarge),
+            _ => Ok(()),
+        }
+    }
+
+    /// Hedged signature with OS randomness
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    pub fn sign(&self, sk: &DilithiumSecretKey, msg: &[u8]) -> Result<DilithiumSignature> {
+        self.sign_with_rng(sk, msg, &mut rand::rngs::OsRng)
+    }
+
+    /// Hedged signature with randomness drawn from `rng`
+    pub fn sign_with_rng<R: SeedSource + ?Sized>(
+        &self,
+        sk: &DilithiumSecretKey,
+        msg: &[u8],
+        rng: &mut R,
+    ) -> Result<DilithiumSignature> {
+        self.check_message_len(msg.len())?;
+        let randomness = zeroize::Zeroizing::new(rng::seed_32_from(rng)?);
+        sign_message_with_context_and_randomness(sk, msg, self.ctx, *randomness)
+    }
+
+    /// Hedged signature with caller-supplied randomness
+    pub fn sign_with_randomness(
+        &self,
+        sk: &DilithiumSecretKey,
+        msg: &[u8],
+        randomness: [u8; ML_DSA_SIGN_SEED_BYTES],
+    ) -> Result<DilithiumSignature> {
+        self.check_message_len(msg.len())?;
+        sign_message_with_context_and_randomness(sk, msg, self.ctx, randomness)
+    }
+
+    /// Deterministic (rnd = 0) signature
+    pub fn sign_deterministic(&self, sk: &DilithiumSecretKey, msg: &[u8]) -> Result<DilithiumSignature> {
+        self.check_message_len(msg.len())?;
+        sign_message_deterministic(sk, msg, self.ctx)
+    }
+}
+
+/// Everything needed to generate ML-DSA keys, sign and verify
+pub mod prelude {
+    pub use super::SigningContext;
+    pub use crate::error::{PqcError, Result};
+    pub use crate::rng::SeedSource;
+    pub use crate::traits::{Signer, Verifier};
+    pub use crate::{
+        generate_dilithium_keypair_with_rng, generate_dilithium_keypair_with_rng_and_pct,
+        generate_dilithium_keypair_with_seed, import_dilithium_keypair, sign_message_deterministic,
+        sign_message_with_context_and_randomness, signature_from_bytes,
+        verify_signature_with_context, verify_strict, DilithiumKeypair, DilithiumPublicKey,
+        DilithiumSecretKey, DilithiumSignature, ML_DSA_65_PK_BYTES, ML_DSA_65_SIG_BYTES,
+        ML_DSA_65_SK_BYTES, ML_DSA_KEYGEN_SEED_BYTES, ML_DSA_MAX_CONTEXT_BYTES, ML_DSA_SIGN_SEED_BYTES,
+    };
+    #[cfg(not(feature = "require-context"))]
+    pub use crate::{
+        sign_message_with_randomness, sign_message_with_randomness_mut, sign_message_with_rng,
+        verify_signature, verify_signature_bytes,
+    };
+    #[cfg(not(feature = "explicit-rng"))]
+    pub use crate::{
+        generate_dilithium_keypair, generate_dilithium_keypair_with_pct, sign_message_with_context,
+    };
+    #[cfg(not(any(feature = "explicit-rng", feature = "require-context")))]
+    pub use crate::sign_message;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    #[cfg(not(feature = "require-context"))]
+    fn test_prelude_sign_verify() {
+        let keys = DilithiumKeypair::generate_with_seed([0x66; ML_DSA_KEYGEN_SEED_BYTES]);
+        let sig = sign_message_deterministic(&keys.sk, b"prelude", b"").unwrap();
+        assert!(verify_signature(&keys.pk, b"prelude", &sig));
+        assert_eq!(Verifier::verify(&keys.pk, b"other", &sig), Err(PqcError::VerificationFailure));
+    }
+
+    #[test]
+    fn test_signing_context_message_limit() {
+        let keys = DilithiumKeypair::generate_with_seed([0x6a; ML_DSA_KEYGEN_SEED_BYTES]);
+        let unbounded = SigningContext::new().with_context(b"svc").unwrap();
+        assert_eq!(unbounded.max_message_len(), None);
+        let large = [0x5a; 10 * 1024];
+        assert!(unbounded.sign_with_randomness(&keys.sk, &large, [0x6b; 32]).is_ok());
+
+        let bounded = unbounded.with_max_message_len(1024);
+        let sig = bounded.sign_deterministic(&keys.sk, &large[..1024]).unwrap();
+        assert!(verify_signature_with_context(&keys.pk, &large[..1024], b"svc", &sig));
+        assert!(matches!(
+            bounded.sign_deterministic(&keys.sk, &large[..1025]),
+            Err(PqcError::MessageTooLarge)
+        ));
+        assert!(matches!(
+            bounded.sign_with_randomness(&keys.sk, &large, [0x6b; 32]),
+            Err(PqcError::MessageTooLarge)
+        ));
+        assert_eq!(
+            SigningContext::new().with_context(&[0u8; ML_DSA_MAX_CONTEXT_BYTES + 1]),
+            Err(PqcError::ContextTooLong)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Size Constants Checked Against libcrux
+// ------------------------------------------------------------------------
+//! Compile-time checks that every ML-KEM / ML-DSA size constant in
+//! `lib.rs` matches the encoding length libcrux actually uses.
+//!
+//! Several of these were found by hand (libcrux's ML-DSA-65 signing key is
+//! 4032 bytes and its signature 3309), so a dependency bump that changes
+//! one must fail the build here rather than produce wrongly sized buffers
+//! downstream. The tests below repeat the check on generated values and
+//! cover the context limit, which libcrux does not export.
+
+#[cfg(feature = "ml-kem")]
+mod ml_kem {
+    use libcrux_ml_kem::mlkem1024::{MlKem1024Ciphertext, MlKem1024PrivateKey, MlKem1024PublicKey};
+    use libcrux_ml_kem::{ENCAPS_SEED_SIZE, KEY_GENERATION_SEED_SIZE, SHARED_SECRET_SIZE};
+
+    use crate::{
+        ML_KEM_1024_CT_BYTES, ML_KEM_1024_PK_BYTES, ML_KEM_1024_SK_BYTES, ML_KEM_1024_SS_BYTES,
+        ML_KEM_ENCAP_SEED_BYTES, ML_KEM_KEYGEN_SEED_BYTES,
+    };
+
+    const _: () = assert!(MlKem1024PublicKey::len() == ML_KEM_1024_PK_BYTES);
+    const _: () = assert!(MlKem1024PrivateKey::len() == ML_KEM_1024_SK_BYTES);
+    const _: () = assert!(MlKem1024Ciphertext::len() == ML_KEM_1024_CT_BYTES);
+    const _: () = assert!(SHARED_SECRET_SIZE == ML_KEM_1024_SS_BYTES);
+    const _: () = assert!(KEY_GENERATION_SEED_SIZE == ML_KEM_KEYGEN_SEED_BYTES);
+    const _: () = assert!(ENCAPS_SEED_SIZE == ML_KEM_ENCAP_SEED_BYTES);
+}
+
+#[cfg(feature = "ml-dsa")]
+mod ml_dsa {
+    use libcrux_ml_dsa::ml_dsa_65::{MLDSA65Signature, MLDSA65SigningKey, MLDSA65VerificationKey};
+    use libcrux_ml_dsa::{KEY_GENERATION_RANDOMNESS_SIZE, SIGNING_RANDOMNESS_SIZE};
+
+    use crate::{
+        ML_DSA_65_PK_BYTES, ML_DSA_65_SIG_BYTES, ML_DSA_65_SK_BYTES, ML_DSA_KEYGEN_SEED_BYTES,
+        ML_DSA_SIGN_SEED_BYTES,
+    };
+
+    const _: () = assert!(MLDSA65VerificationKey::len() == ML_DSA_65_PK_BYTES);
+    const _: () = assert!(MLDSA65SigningKey::len() == ML_DSA_65_SK_BYTES);
+    const _: () = assert!(MLDSA65Signature::len() == ML_DSA_65_SIG_BYTES);
+    const _: () = assert!(KEY_GENERATION_RANDOMNESS_SIZE == ML_DSA_KEYGEN_SEED_BYTES);
+    const _: () = assert!(SIGNING_RANDOMNESS_SIZE == ML_DSA_SIGN_SEED_BYTES);
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::*;
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_kem_sizes_match_generated_values() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x41; ML_KEM_KEYGEN_SEED_BYTES]);
+        let (ct, ss) = encapsulate_shared_secret_with_randomness(&keys.pk, [0x42; ML_KEM_ENCAP_SEED_BYTES]);
+        assert_eq!(keys.pk.as_slice().len(), ML_KEM_1024_PK_BYTES);
+        assert_eq!(keys.sk.as_slice().len(), ML_KEM_1024_SK_BYTES);
+        assert_eq!(ct.as_slice().len(), ML_KEM_1024_CT_BYTES);
+        assert_eq!(ss.as_bytes().len(), ML_KEM_1024_SS_BYTES);
+    }
+
+    #[test]
+    #[cfg(feature = "ml-dsa")]
+    fn test_dsa_sizes_match_generated_values() {
+        let (pk, sk) = generate_dilithium_keypair_with_seed([0x43; ML_DSA_KEYGEN_SEED_BYTES]);
+        let sig = sign_message_deterministic(&sk, b"sizes", b"ctx").unwrap();
+        assert_eq!(pk.as_slice().len(), ML_DSA_65_PK_BYTES);
+        assert_eq!(sk.as_slice().len(), ML_DSA_65_SK_BYTES);
+        assert_eq!(sig.as_slice().len(), ML_DSA_65_SIG_BYTES);
+
+        // libcrux itself accepts exactly ML_DSA_MAX_CONTEXT_BYTES of context
+        let ctx = [0x44; ML_DSA_MAX_CONTEXT_BYTES + 1];
+        let rnd = [0x45; ML_DSA_SIGN_SEED_BYTES];
+        assert!(libcrux_ml_dsa::ml_dsa_65::sign(&sk.0, b"sizes", &ctx[..ML_DSA_MAX_CONTEXT_BYTES], rnd).is_ok());
+        assert!(libcrux_ml_dsa::ml_dsa_65::sign(&sk.0, b"sizes", &ctx, rnd).is_err());
+    }
+}
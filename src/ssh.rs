@@ -0,0 +1,162 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// OpenSSH Public Key Format for ML-DSA-65
+// ------------------------------------------------------------------------
+//! `authorized_keys` / `.pub` lines for ML-DSA-65 verification keys:
+//!
+//! ```text
+//! ssh-mldsa-65 base64(string("ssh-mldsa-65") || string(pk)) [comment]
+//! ```
+//!
+//! `string` is the RFC 4251 §5 encoding, a u32 big-endian length followed
+//! by the bytes. The key type name follows draft-sfluhrer-ssh-mldsa;
+//! released OpenSSH has no ML-DSA key type yet, so the name may change
+//! with the final specification. Lines carrying `authorized_keys` options
+//! before the key type are not accepted.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::encoding::to_base64;
+use crate::error::{PqcError, Result};
+use crate::{DilithiumPublicKey, ML_DSA_65_PK_BYTES};
+
+/// SSH public key algorithm name for ML-DSA-65
+pub const SSH_MLDSA65_KEY_TYPE: &str = "ssh-mldsa-65";
+
+/// Size of an RFC 4251 `string` length prefix
+const SSH_LEN_PREFIX_BYTES: usize = 4;
+
+/// Format `pk` as an OpenSSH public key line
+///
+/// An empty `comment` is omitted together with its separating space.
+pub fn to_openssh(pk: &DilithiumPublicKey, comment: &str) -> String {
+    let mut blob = Vec::with_capacity(
+        2 * SSH_LEN_PREFIX_BYTES + SSH_MLDSA65_KEY_TYPE.len() + ML_DSA_65_PK_BYTES,
+    );
+    put_string(&mut blob, SSH_MLDSA65_KEY_TYPE.as_bytes());
+    put_string(&mut blob, pk.as_slice());
+
+    let mut line = String::from(SSH_MLDSA65_KEY_TYPE);
+    line.push(' ');
+    line.push_str(&to_base64(&blob));
+    if !comment.is_empty() {
+        line.push(' ');
+        line.push_str(comment);
+    }
+    line
+}
+
+/// Parse an OpenSSH public key line produced by [`to_openssh`]
+///
+/// Returns `InvalidEncoding` for another key type, malformed Base64, a
+/// length prefix that overruns the blob, a key type inside the blob that
+/// disagrees with the line, or trailing bytes; `InvalidKeyLength` if the
+/// embedded key is not `ML_DSA_65_PK_BYTES` long.
+pub fn from_openssh(line: &str) -> Result<DilithiumPublicKey> {
+    let mut fields = line.split_ascii_whitespace();
+    if fields.next() != Some(SSH_MLDSA65_KEY_TYPE) {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let encoded = fields.next().ok_or(PqcError::InvalidEncoding)?;
+    let blob = STANDARD.decode(encoded).map_err(|_| PqcError::InvalidEncoding)?;
+
+    let (key_type, rest) = take_string(&blob)?;
+    if key_type != SSH_MLDSA65_KEY_TYPE.as_bytes() {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let (key, rest) = take_string(rest)?;
+    if !rest.is_empty() {
+        return Err(PqcError::InvalidEncoding);
+    }
+    DilithiumPublicKey::try_from(key)
+}
+
+fn put_string(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// Split one RFC 4251 `string` off the front of `bytes`
+fn take_string(bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (prefix, rest) = bytes
+        .split_first_chunk::<SSH_LEN_PREFIX_BYTES>()
+        .ok_or(PqcError::InvalidEncoding)?;
+    let len = usize::try_from(u32::from_be_bytes(*prefix)).map_err(|_| PqcError::InvalidEncoding)?;
+    if len > rest.len() {
+        return Err(PqcError::InvalidEncoding);
+    }
+    Ok(rest.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::from_base64;
+    use crate::generate_dilithium_keypair_with_seed;
+
+    fn public_key() -> DilithiumPublicKey {
+        generate_dilithium_keypair_with_seed([0x5c; 32]).0
+    }
+
+    #[test]
+    fn test_openssh_roundtrip() {
+        let pk = public_key();
+        let line = to_openssh(&pk, "admin@build-host");
+
+        // string("ssh-mldsa-65") || uint32(1952) || ...
+        assert!(line.starts_with("ssh-mldsa-65 AAAADHNzaC1tbGRzYS02NQAAB6"));
+        assert!(line.ends_with(" admin@build-host"));
+        assert!(from_openssh(&line).unwrap() == pk);
+
+        let bare = to_openssh(&pk, "");
+        assert_eq!(bare.split(' ').count(), 2);
+        assert!(from_openssh(&bare).unwrap() == pk);
+        assert!(from_openssh(&alloc::format!("{}  spaced comment\n", bare)).unwrap() == pk);
+
+        let encoded = bare.split(' ').nth(1).unwrap();
+        let blob = from_base64(encoded, 2 * 4 + 12 + ML_DSA_65_PK_BYTES).unwrap();
+        assert_eq!(&blob[..4], &[0, 0, 0, 12]);
+        assert_eq!(&blob[4..16], b"ssh-mldsa-65");
+        assert_eq!(&blob[16..20], &(ML_DSA_65_PK_BYTES as u32).to_be_bytes());
+        assert_eq!(&blob[20..], pk.as_slice());
+    }
+
+    #[test]
+    fn test_openssh_rejects_bad_framing() {
+        let pk = public_key();
+        let mut blob = Vec::new();
+        put_string(&mut blob, SSH_MLDSA65_KEY_TYPE.as_bytes());
+        put_string(&mut blob, pk.as_slice());
+        let line = |blob: &[u8]| alloc::format!("{} {}", SSH_MLDSA65_KEY_TYPE, to_base64(blob));
+
+        // Outer type must match
+        let ed = to_openssh(&pk, "").replacen(SSH_MLDSA65_KEY_TYPE, "ssh-ed25519", 1);
+        assert_eq!(from_openssh(&ed).map(|_| ()), Err(PqcError::InvalidEncoding));
+        assert_eq!(from_openssh("ssh-mldsa-65").map(|_| ()), Err(PqcError::InvalidEncoding));
+        assert_eq!(from_openssh("ssh-mldsa-65 !!!").map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        // Key length prefix overruns the blob
+        let truncated = &blob[..blob.len() - 1];
+        assert_eq!(from_openssh(&line(truncated)).map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        // Trailing bytes after the key
+        let mut trailing = blob.clone();
+        trailing.push(0);
+        assert_eq!(from_openssh(&line(&trailing)).map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        // Inner type disagrees with the line
+        let mut renamed = blob.clone();
+        renamed[15] = b'4';
+        assert_eq!(from_openssh(&line(&renamed)).map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        // Well-framed key of the wrong size
+        let mut short = Vec::new();
+        put_string(&mut short, SSH_MLDSA65_KEY_TYPE.as_bytes());
+        put_string(&mut short, &pk.as_slice()[..32]);
+        assert_eq!(from_openssh(&line(&short)).map(|_| ()), Err(PqcError::InvalidKeyLength));
+    }
+}
@@ -4,7 +4,15 @@
 // ------------------------------------------------------------------------
 
 use crate::error::{PqcError, Result};
-use core::sync::atomic::{AtomicU8, Ordering};
+use crate::events::{emit, FipsEvent};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "std")]
+use core::sync::atomic::AtomicUsize;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 /// FIPS 140-3 Module States
 #[repr(u8)]
@@ -28,26 +36,215 @@ impl From<u8> for FipsState {
     }
 }
 
-static FIPS_STATE: AtomicU8 = AtomicU8::new(FipsState::Uninitialized as u8);
+/// State in the low byte; with `std`, the pid of the process that wrote it
+/// in the bits above, so a compare-exchange moves state and owner together
+static FIPS_STATE: AtomicU64 = AtomicU64::new(FipsState::Uninitialized as u64);
+
+const STATE_MASK: u64 = 0xff;
+
+/// Owner bits for a state word written by this process
+fn owner_tag() -> u64 {
+    #[cfg(feature = "std")]
+    let tag = u64::from(std::process::id()) << 8;
+    #[cfg(not(feature = "std"))]
+    let tag = 0;
+    tag
+}
+
+fn state_word(state: FipsState) -> u64 {
+    owner_tag() | state as u64
+}
 
+/// Current state, as seen by this process
+///
+/// With `std`, a POST or Operational state inherited across `fork()` reads
+/// as Uninitialized: the child never ran its own POST, so it must call
+/// [`crate::run_post`] before use. An inherited Error state is kept.
+///
+/// The check costs one `getpid` call per read while POST or Operational.
+/// The pid is deliberately not cached: a cached copy is itself inherited
+/// across `fork()` and would hide the change it is meant to detect.
 pub fn get_fips_state() -> FipsState {
-    FipsState::from(FIPS_STATE.load(Ordering::Acquire))
+    let word = FIPS_STATE.load(Ordering::Acquire);
+    #[cfg(feature = "std")]
+    let word = reset_if_forked(word);
+    FipsState::from((word & STATE_MASK) as u8)
+}
+
+/// Drop a POST/Operational state that another process set up
+#[cfg(feature = "std")]
+fn reset_if_forked(word: u64) -> u64 {
+    let state = FipsState::from((word & STATE_MASK) as u8);
+    let inheritable = state == FipsState::POST || state == FipsState::Operational;
+    if !inheritable || word & !STATE_MASK == owner_tag() {
+        return word;
+    }
+    if FIPS_STATE
+        .compare_exchange(
+            word,
+            state_word(FipsState::Uninitialized),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        )
+        .is_ok()
+    {
+        emit(FipsEvent::StateChanged(FipsState::Uninitialized));
+    }
+    FIPS_STATE.load(Ordering::Acquire)
 }
 
 fn set_fips_state(state: FipsState) {
-    FIPS_STATE.store(state as u8, Ordering::Release);
+    FIPS_STATE.store(state_word(state), Ordering::Release);
+    emit(FipsEvent::StateChanged(state));
 }
 
-pub(crate) fn enter_post_state() {
-    set_fips_state(FipsState::POST);
+/// Atomically move from `from` to `to`; returns `false` if the module was
+/// not in `from`
+///
+/// A state inherited across `fork()` is dropped first, so it never counts
+/// as `from`.
+pub(crate) fn transition_fips_state(from: FipsState, to: FipsState) -> bool {
+    get_fips_state();
+    let mut current = FIPS_STATE.load(Ordering::Acquire);
+    while current & STATE_MASK == from as u64 {
+        match FIPS_STATE.compare_exchange(
+            current,
+            state_word(to),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                emit(FipsEvent::StateChanged(to));
+                return true;
+            }
+            Err(actual) => current = actual,
+        }
+    }
+    false
+}
+
+// Legal state edges:
+//
+//   Uninitialized -> POST -> Operational | Error
+//   Operational   -> POST          (on-demand re-test)
+//   Error         -> POST          (only via `recover_from_error`)
+//   any           -> Error         (self-test or conditional test failure)
+//
+// Anything else is rejected with `PqcError::IllegalStateTransition` and
+// leaves the state unchanged.
+
+/// Start POST from Uninitialized or Operational
+pub(crate) fn enter_post_state() -> Result<()> {
+    if transition_fips_state(FipsState::Uninitialized, FipsState::POST)
+        || transition_fips_state(FipsState::Operational, FipsState::POST)
+    {
+        Ok(())
+    } else {
+        Err(PqcError::IllegalStateTransition)
+    }
 }
 
-pub(crate) fn enter_operational_state() {
-    set_fips_state(FipsState::Operational);
+/// Finish a passing POST
+///
+/// Injected DRBGs are asked to reseed before serving the new session.
+pub(crate) fn enter_operational_state() -> Result<()> {
+    if transition_fips_state(FipsState::POST, FipsState::Operational) {
+        crate::rng::request_reseed();
+        Ok(())
+    } else {
+        Err(PqcError::IllegalStateTransition)
+    }
+}
+
+/// Restart POST from any state except POST itself, in one atomic step
+///
+/// Used by [`crate::preop::reinitialize`]: observers go straight from the
+/// old state to POST and never see Uninitialized.
+#[cfg(feature = "std")]
+pub(crate) fn restart_post_state() -> Result<()> {
+    get_fips_state();
+    let mut current = FIPS_STATE.load(Ordering::Acquire);
+    loop {
+        if current & STATE_MASK == FipsState::POST as u64 {
+            return Err(PqcError::FipsPostInProgress);
+        }
+        match FIPS_STATE.compare_exchange(
+            current,
+            state_word(FipsState::POST),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                emit(FipsEvent::StateChanged(FipsState::POST));
+                return Ok(());
+            }
+            Err(actual) => current = actual,
+        }
+    }
 }
 
 pub(crate) fn enter_error_state() {
     set_fips_state(FipsState::Error);
+    zeroize_on_error();
+}
+
+// === CSP Zeroization Registry ===
+
+/// Handle for a zeroizer registered with [`register_zeroizer`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroizerId(usize);
+
+#[cfg(feature = "std")]
+type Zeroizer = Box<dyn FnMut() + Send>;
+
+#[cfg(feature = "std")]
+static ZEROIZERS: Mutex<Vec<(ZeroizerId, Zeroizer)>> = Mutex::new(Vec::new());
+
+#[cfg(feature = "std")]
+static NEXT_ZEROIZER_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Register a closure that scrubs CSPs when the module enters the Error state
+///
+/// FIPS 140-3 requires CSPs to be zeroized on transition to an error
+/// condition. Types holding secret material register a closure here; every
+/// registered closure is invoked by [`zeroize_on_error`].
+#[cfg(feature = "std")]
+pub fn register_zeroizer<F>(zeroizer: F) -> ZeroizerId
+where
+    F: FnMut() + Send + 'static,
+{
+    let id = ZeroizerId(NEXT_ZEROIZER_ID.fetch_add(1, Ordering::Relaxed));
+    ZEROIZERS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push((id, Box::new(zeroizer)));
+    id
+}
+
+/// Remove a previously registered zeroizer
+///
+/// Returns `true` if the zeroizer was registered.
+#[cfg(feature = "std")]
+pub fn unregister_zeroizer(id: ZeroizerId) -> bool {
+    let mut zeroizers = ZEROIZERS.lock().unwrap_or_else(|e| e.into_inner());
+    let before = zeroizers.len();
+    zeroizers.retain(|(registered, _)| *registered != id);
+    zeroizers.len() != before
+}
+
+/// Invoke every registered zeroizer
+///
+/// Called automatically on entry to the Error state, including when POST
+/// fails. Without the `std` feature there is no registry and this is a no-op.
+pub fn zeroize_on_error() {
+    #[cfg(feature = "std")]
+    {
+        let mut zeroizers = ZEROIZERS.lock().unwrap_or_else(|e| e.into_inner());
+        for (_, zeroize) in zeroizers.iter_mut() {
+            zeroize();
+        }
+    }
 }
 
 pub fn is_operational() -> bool {
@@ -64,8 +261,64 @@ pub fn check_operational() -> Result<()> {
     }
 }
 
+/// Whether an error from [`check_operational`] may clear up on its own
+///
+/// Only `FipsPostInProgress` is retriable: another thread is running POST and
+/// the module will shortly become Operational or Error. `FipsErrorState` and
+/// `FipsNotInitialized` need caller action (`recover_from_error` / `run_post`)
+/// and retrying them is pointless.
+///
+/// Recommended backoff for retriable errors: start around 50 µs and double on
+/// each attempt, capping at 10 ms, until an overall deadline expires.
+/// [`wait_until_operational`] implements this policy.
+pub fn is_retriable(err: &PqcError) -> bool {
+    matches!(err, PqcError::FipsPostInProgress)
+}
+
+/// Block until the module leaves the POST state or `timeout` elapses
+///
+/// Returns `Ok(())` once Operational. Non-retriable states are reported
+/// immediately; if POST is still running at the deadline,
+/// `Err(PqcError::FipsPostInProgress)` is returned.
+#[cfg(feature = "std")]
+pub fn wait_until_operational(timeout: Duration) -> Result<()> {
+    const INITIAL_BACKOFF: Duration = Duration::from_micros(50);
+    const MAX_BACKOFF: Duration = Duration::from_millis(10);
+
+    let deadline = Instant::now() + timeout;
+    let mut backoff = INITIAL_BACKOFF;
+    loop {
+        match check_operational() {
+            Err(e) if is_retriable(&e) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(e);
+                }
+                std::thread::sleep(backoff.min(deadline - now));
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Return to Uninitialized; with `std` this also clears [`crate::status`]
+///
+/// Test-only (`testing` feature): it leaves any state unconditionally,
+/// Error included, so `reset_fips_state(); run_post()` would bypass
+/// [`recover_from_error`](crate::recover_from_error).
+#[cfg(any(test, feature = "testing"))]
 pub fn reset_fips_state() {
     set_fips_state(FipsState::Uninitialized);
+    #[cfg(feature = "std")]
+    crate::status::clear();
+}
+
+/// Serializes unit tests that drive the global FIPS state machine
+#[cfg(test)]
+pub(crate) fn lock_state_for_test() -> std::sync::MutexGuard<'static, ()> {
+    static TEST_STATE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    TEST_STATE_LOCK.lock().unwrap_or_else(|e| e.into_inner())
 }
 
 #[cfg(test)]
@@ -74,6 +327,7 @@ mod tests {
 
     #[test]
     fn test_initial_state() {
+        let _guard = lock_state_for_test();
         reset_fips_state();
         assert_eq!(get_fips_state(), FipsState::Uninitialized);
         assert!(!is_operational());
@@ -81,13 +335,14 @@ mod tests {
 
     #[test]
     fn test_state_transitions() {
+        let _guard = lock_state_for_test();
         reset_fips_state();
         
-        enter_post_state();
+        enter_post_state().unwrap();
         assert_eq!(get_fips_state(), FipsState::POST);
         assert!(!is_operational());
         
-        enter_operational_state();
+        enter_operational_state().unwrap();
         assert_eq!(get_fips_state(), FipsState::Operational);
         assert!(is_operational());
         
@@ -98,20 +353,104 @@ mod tests {
 
     #[test]
     fn test_check_operational() {
+        let _guard = lock_state_for_test();
         reset_fips_state();
         
         assert!(check_operational().is_err());
         assert_eq!(check_operational().unwrap_err(), PqcError::FipsNotInitialized);
         
-        enter_post_state();
+        enter_post_state().unwrap();
         assert!(check_operational().is_err());
         assert_eq!(check_operational().unwrap_err(), PqcError::FipsPostInProgress);
         
-        enter_operational_state();
+        enter_operational_state().unwrap();
         assert!(check_operational().is_ok());
         
         enter_error_state();
         assert!(check_operational().is_err());
         assert_eq!(check_operational().unwrap_err(), PqcError::FipsErrorState);
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_state_runs_zeroizers() {
+        let _guard = lock_state_for_test();
+        use std::sync::{Arc, Mutex};
+
+        let buffer = Arc::new(Mutex::new([0xAAu8; 32]));
+        let registered = Arc::clone(&buffer);
+        let id = register_zeroizer(move || {
+            registered.lock().unwrap().fill(0);
+        });
+
+        enter_error_state();
+        assert_eq!(*buffer.lock().unwrap(), [0u8; 32]);
+
+        assert!(unregister_zeroizer(id));
+        assert!(!unregister_zeroizer(id));
+        reset_fips_state();
+    }
+
+    #[test]
+    fn test_is_retriable() {
+        assert!(is_retriable(&PqcError::FipsPostInProgress));
+        assert!(!is_retriable(&PqcError::FipsErrorState));
+        assert!(!is_retriable(&PqcError::FipsNotInitialized));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_wait_until_operational() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+
+        enter_post_state().unwrap();
+        let finisher = std::thread::spawn(|| {
+            std::thread::sleep(Duration::from_millis(20));
+            enter_operational_state().unwrap();
+        });
+        assert!(wait_until_operational(Duration::from_secs(5)).is_ok());
+        finisher.join().unwrap();
+
+        enter_post_state().unwrap();
+        assert_eq!(
+            wait_until_operational(Duration::from_millis(10)),
+            Err(PqcError::FipsPostInProgress)
+        );
+
+        enter_error_state();
+        assert_eq!(
+            wait_until_operational(Duration::from_secs(5)),
+            Err(PqcError::FipsErrorState)
+        );
+        reset_fips_state();
+    }
+
+    #[test]
+    fn test_illegal_transitions_rejected() {
+        let _guard = lock_state_for_test();
+
+        // Uninitialized cannot skip POST
+        reset_fips_state();
+        assert_eq!(enter_operational_state(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::Uninitialized);
+
+        // POST cannot be re-entered while running
+        enter_post_state().unwrap();
+        assert_eq!(enter_post_state(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::POST);
+
+        // Operational may only go back through POST
+        enter_operational_state().unwrap();
+        assert_eq!(enter_operational_state(), Err(PqcError::IllegalStateTransition));
+        enter_post_state().unwrap();
+        enter_operational_state().unwrap();
+
+        // Error is left only through recover_from_error
+        enter_error_state();
+        assert_eq!(enter_post_state(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(enter_operational_state(), Err(PqcError::IllegalStateTransition));
+        assert_eq!(get_fips_state(), FipsState::Error);
+        reset_fips_state();
+    }
+}
@@ -0,0 +1,147 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Cached Self-Test Status
+// ------------------------------------------------------------------------
+//! Last known outcome of POST and of every individual self-test.
+//!
+//! Every self-test outcome reported through [`crate::events`] and every
+//! completed POST is also stored here with a wall-clock timestamp, so a
+//! monitoring endpoint can answer "last POST: OK at T, SHA3-256 CAST: OK"
+//! from [`self_test_status`] without re-running anything.
+//! `reset_fips_state` (`testing` feature) clears the cache.
+
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::Result;
+use crate::events::SelfTestId;
+
+/// Every self-test, in POST execution order
+const ALL_SELF_TESTS: [SelfTestId; 8] = [
+    SelfTestId::Sha3_256,
+    SelfTestId::Sha3_512,
+    SelfTestId::Shake128,
+    SelfTestId::Shake256,
+    SelfTestId::MlKemKat,
+    SelfTestId::MlDsaKat,
+    SelfTestId::MlKemPct,
+    SelfTestId::MlDsaPct,
+];
+
+/// Result of one run and when it finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TestOutcome {
+    pub result: Result<()>,
+    /// Wall-clock completion time, in milliseconds since the Unix epoch
+    pub unix_time_ms: u64,
+}
+
+impl TestOutcome {
+    fn now(result: Result<()>) -> Self {
+        let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Self { result, unix_time_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX) }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Snapshot returned by [`self_test_status`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SelfTestStatus {
+    /// Last completed POST, `None` if none ran since the last reset
+    pub last_post: Option<TestOutcome>,
+    tests: [(SelfTestId, Option<TestOutcome>); ALL_SELF_TESTS.len()],
+}
+
+impl SelfTestStatus {
+    const EMPTY: Self = {
+        let mut tests = [(SelfTestId::Sha3_256, None); ALL_SELF_TESTS.len()];
+        let mut i = 0;
+        while i < ALL_SELF_TESTS.len() {
+            tests[i].0 = ALL_SELF_TESTS[i];
+            i += 1;
+        }
+        Self { last_post: None, tests }
+    };
+
+    /// Last outcome of `id`, `None` if it has not run since the last reset
+    pub fn test(&self, id: SelfTestId) -> Option<TestOutcome> {
+        self.tests[id as usize].1
+    }
+
+    /// Every self-test with its last outcome, in POST execution order
+    pub fn tests(&self) -> impl Iterator<Item = (SelfTestId, Option<TestOutcome>)> + '_ {
+        self.tests.iter().copied()
+    }
+}
+
+static STATUS: Mutex<SelfTestStatus> = Mutex::new(SelfTestStatus::EMPTY);
+
+/// Cached results of the last POST and of each self-test
+pub fn self_test_status() -> SelfTestStatus {
+    STATUS.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+pub(crate) fn record_test(id: SelfTestId, result: Result<()>) {
+    STATUS.lock().unwrap_or_else(|e| e.into_inner()).tests[id as usize].1 = Some(TestOutcome::now(result));
+}
+
+pub(crate) fn record_post(result: Result<()>) {
+    STATUS.lock().unwrap_or_else(|e| e.into_inner()).last_post = Some(TestOutcome::now(result));
+}
+
+#[cfg(any(test, feature = "testing"))]
+pub(crate) fn clear() {
+    *STATUS.lock().unwrap_or_else(|e| e.into_inner()) = SelfTestStatus::EMPTY;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PqcError;
+    use crate::state::{lock_state_for_test, reset_fips_state};
+
+    #[test]
+    fn test_status_order_matches_ids() {
+        for (index, (id, _)) in SelfTestStatus::EMPTY.tests().enumerate() {
+            assert_eq!(id as usize, index);
+        }
+    }
+
+    #[test]
+    fn test_status_after_post_and_reset() {
+        let _guard = lock_state_for_test();
+        reset_fips_state();
+        assert_eq!(self_test_status(), SelfTestStatus::EMPTY);
+
+        let before = TestOutcome::now(Ok(())).unix_time_ms;
+        crate::run_post().unwrap();
+        let status = self_test_status();
+        let post = status.last_post.unwrap();
+        assert!(post.passed());
+        assert!(post.unix_time_ms >= before);
+        let sha3 = status.test(SelfTestId::Sha3_256).unwrap();
+        assert!(sha3.passed() && sha3.unix_time_ms <= post.unix_time_ms);
+        assert_eq!(
+            status.test(SelfTestId::MlKemPct).is_some(),
+            cfg!(all(feature = "ml-kem", not(feature = "explicit-rng")))
+        );
+
+        // A standalone CAST updates its entry without touching the POST record
+        crate::cast::run_hash_casts().unwrap();
+        let again = self_test_status();
+        assert!(again.test(SelfTestId::Shake256).unwrap().unix_time_ms >= sha3.unix_time_ms);
+        assert_eq!(again.last_post, Some(post));
+
+        crate::events::cast_result(SelfTestId::Shake128, Err(PqcError::CastFailure)).unwrap_err();
+        let failed = self_test_status().test(SelfTestId::Shake128).unwrap();
+        assert_eq!(failed.result, Err(PqcError::CastFailure));
+
+        reset_fips_state();
+        assert_eq!(self_test_status(), SelfTestStatus::EMPTY);
+    }
+}
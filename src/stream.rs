@@ -0,0 +1,394 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Streaming ML-KEM + AES-256-GCM File Encryption
+// ------------------------------------------------------------------------
+//! Encrypt a stream of any length to an ML-KEM-1024 public key without
+//! buffering it in memory.
+//!
+//! ```text
+//! header = aead_id (1) || KEM ciphertext (1568) || nonce prefix (8)
+//! nonce  = prefix || counter: u32 BE
+//! chunk  = AEAD(key, nonce, aad = aead_id || last: u8, plaintext) || tag
+//! ```
+//!
+//! `aead_id` is an [`AeadAlgorithm`] id (0x01 = AES-256-GCM, 0x02 =
+//! ChaCha20-Poly1305 with the `chacha20poly1305` feature); opening
+//! picks the cipher from it and rejects an unknown id with
+//! `UnsupportedAlgorithm` before decapsulating.
+//!
+//! Every chunk except the last carries exactly [`STREAM_CHUNK_BYTES`] of
+//! plaintext; the last carries fewer (possibly zero) and has `last` = 1 in
+//! its AAD. The counter starts at 0 and increases by one per chunk, so a
+//! dropped, duplicated or reordered chunk is decrypted under the wrong nonce,
+//! and a stream cut short either ends without a final-flagged chunk or
+//! presents a full chunk with the wrong flag. All of these fail
+//! authentication.
+//! The AEAD key is [`crate::derive_session_key`] over the encapsulation
+//! with [`STREAM_KEY_INFO`].
+//!
+//! The 32-bit counter caps a stream at 2^32 chunks (256 TiB of plaintext);
+//! a longer stream fails with `MessageTooLarge` instead of reusing a nonce.
+
+use std::io::{ErrorKind, Read, Write};
+use std::vec;
+
+use zeroize::Zeroize;
+
+use crate::aead::AeadAlgorithm;
+use crate::error::{PqcError, Result};
+use crate::kdf::derive_session_key;
+use crate::rng::SeedSource;
+use crate::{
+    decapsulate_shared_secret, try_encapsulate_shared_secret_with_randomness, KyberCiphertext,
+    KyberPublicKey, KyberSecretKey, AES_KEY_BYTES, AES_NONCE_BYTES, ML_KEM_1024_CT_BYTES,
+    ML_KEM_1024_PK_BYTES, ML_KEM_1024_SK_EK_OFFSET,
+};
+
+/// Plaintext bytes per full chunk
+pub const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+/// Random nonce prefix stored in the header
+pub const STREAM_NONCE_PREFIX_BYTES: usize = 8;
+/// Header length: AEAD id, KEM ciphertext and nonce prefix
+pub const STREAM_HEADER_BYTES: usize = 1 + ML_KEM_1024_CT_BYTES + STREAM_NONCE_PREFIX_BYTES;
+/// `info` passed to [`derive_session_key`] for stream keys
+pub const STREAM_KEY_INFO: &[u8] = b"pqc-combo stream v2";
+
+/// Encrypt everything from `reader` to `pk`, writing header and chunks to `writer`
+///
+/// The public key is validated first (`InvalidPublicKey`). Returns the
+/// number of plaintext bytes encrypted.
+#[cfg(not(feature = "explicit-rng"))]
+pub fn seal_reader_to_writer<R: Read, W: Write>(
+    pk: &KyberPublicKey,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64> {
+    seal_reader_to_writer_with_rng(pk, &mut rand::rngs::OsRng, reader, writer)
+}
+
+/// [`seal_reader_to_writer`] with encapsulation randomness and the nonce
+/// prefix drawn from `rng`
+pub fn seal_reader_to_writer_with_rng<S: SeedSource + ?Sized, R: Read, W: Write>(
+    pk: &KyberPublicKey,
+    rng: &mut S,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64> {
+    seal_reader_to_writer_with_aead(pk, AeadAlgorithm::default(), rng, reader, writer)
+}
+
+/// [`seal_reader_to_writer_with_rng`] with a chosen AEAD
+pub fn seal_reader_to_writer_with_aead<S: SeedSource + ?Sized, R: Read, W: Write>(
+    pk: &KyberPublicKey,
+    aead: AeadAlgorithm,
+    rng: &mut S,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64> {
+    let mut randomness = crate::rng::seed_32_from(rng)?;
+    let encapsulated = try_encapsulate_shared_secret_with_randomness(pk, randomness);
+    randomness.zeroize();
+    let (ct, mut ss) = encapsulated?;
+    let mut key = derive_session_key(&ss, pk, &ct, STREAM_KEY_INFO);
+    ss.zeroize();
+    let mut prefix = [0u8; STREAM_NONCE_PREFIX_BYTES];
+    rng.fill(&mut prefix)?;
+
+    let result = writer
+        .write_all(&[aead.id()])
+        .and_then(|()| writer.write_all(ct.as_slice()))
+        .and_then(|()| writer.write_all(&prefix))
+        .map_err(|_| PqcError::IoFailure)
+        .and_then(|()| seal_chunks(aead, &key, &prefix, reader, writer));
+    key.zeroize();
+    result
+}
+
+fn seal_chunks<R: Read, W: Write>(
+    aead: AeadAlgorithm,
+    key: &[u8; AES_KEY_BYTES],
+    prefix: &[u8; STREAM_NONCE_PREFIX_BYTES],
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64> {
+    let mut plaintext = vec![0u8; STREAM_CHUNK_BYTES];
+    let mut sealed = vec![0u8; STREAM_CHUNK_BYTES + aead.tag_len()];
+    let mut total = 0u64;
+    let mut index = 0u32;
+
+    let result = loop {
+        let len = match read_full(reader, &mut plaintext) {
+            Ok(len) => len,
+            Err(e) => break Err(e),
+        };
+        let last = len < STREAM_CHUNK_BYTES;
+        let nonce = chunk_nonce(prefix, index);
+        let aad = [aead.id(), last as u8];
+        let written = match aead.seal_into(key, &nonce, &aad, &plaintext[..len], &mut sealed) {
+            Ok(written) => written,
+            Err(e) => break Err(e),
+        };
+        if writer.write_all(&sealed[..written]).is_err() {
+            break Err(PqcError::IoFailure);
+        }
+        total += len as u64;
+        if last {
+            break writer.flush().map(|()| total).map_err(|_| PqcError::IoFailure);
+        }
+        index = match next_index(index) {
+            Ok(next) => next,
+            Err(e) => break Err(e),
+        };
+    };
+    plaintext.zeroize();
+    result
+}
+
+/// Decrypt a stream produced by [`seal_reader_to_writer`]
+///
+/// Returns the number of plaintext bytes written. Chunks are released to
+/// `writer` as soon as they authenticate, so on any error the output
+/// written so far must be discarded: `AeadOperationFailed` means a
+/// tampered, dropped, duplicated, reordered or truncated chunk (including a
+/// stream that stops on a chunk boundary, before its final chunk),
+/// `InvalidEncoding` a short header, and `UnsupportedAlgorithm` an AEAD id
+/// this build does not know.
+pub fn open_reader_to_writer<R: Read, W: Write>(
+    sk: &KyberSecretKey,
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64> {
+    let mut header = [0u8; STREAM_HEADER_BYTES];
+    if read_full(reader, &mut header)? != STREAM_HEADER_BYTES {
+        return Err(PqcError::InvalidEncoding);
+    }
+    let aead = AeadAlgorithm::from_id(header[0])?;
+    let (ct_bytes, prefix_bytes) = header[1..].split_at(ML_KEM_1024_CT_BYTES);
+    let ct = KyberCiphertext::try_from(ct_bytes)?;
+    let prefix: [u8; STREAM_NONCE_PREFIX_BYTES] =
+        prefix_bytes.try_into().map_err(|_| PqcError::InvalidEncoding)?;
+    let pk = KyberPublicKey::try_from(&sk.as_slice()[ML_KEM_1024_SK_EK_OFFSET..ML_KEM_1024_SK_EK_OFFSET + ML_KEM_1024_PK_BYTES])?;
+
+    let mut ss = decapsulate_shared_secret(sk, &ct);
+    let mut key = derive_session_key(&ss, &pk, &ct, STREAM_KEY_INFO);
+    ss.zeroize();
+    let result = open_chunks(aead, &key, &prefix, reader, writer);
+    key.zeroize();
+    result
+}
+
+fn open_chunks<R: Read, W: Write>(
+    aead: AeadAlgorithm,
+    key: &[u8; AES_KEY_BYTES],
+    prefix: &[u8; STREAM_NONCE_PREFIX_BYTES],
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<u64> {
+    let mut sealed = vec![0u8; STREAM_CHUNK_BYTES + aead.tag_len()];
+    let mut plaintext = vec![0u8; STREAM_CHUNK_BYTES];
+    let mut total = 0u64;
+    let mut index = 0u32;
+
+    let result = loop {
+        let len = match read_full(reader, &mut sealed) {
+            Ok(len) => len,
+            Err(e) => break Err(e),
+        };
+        // Too short to hold a tag: the final chunk was cut or never sent
+        if len < aead.tag_len() {
+            break Err(PqcError::AeadOperationFailed);
+        }
+        let last = len < sealed.len();
+        let nonce = chunk_nonce(prefix, index);
+        let aad = [aead.id(), last as u8];
+        let opened = match aead.open_into(key, &nonce, &aad, &sealed[..len], &mut plaintext) {
+            Ok(opened) => opened,
+            Err(e) => break Err(e),
+        };
+        if writer.write_all(&plaintext[..opened]).is_err() {
+            break Err(PqcError::IoFailure);
+        }
+        total += opened as u64;
+        if last {
+            break writer.flush().map(|()| total).map_err(|_| PqcError::IoFailure);
+        }
+        index = match next_index(index) {
+            Ok(next) => next,
+            Err(e) => break Err(e),
+        };
+    };
+    plaintext.zeroize();
+    result
+}
+
+/// Counter of the chunk after `index`, or `MessageTooLarge` once the
+/// 32-bit counter is exhausted
+fn next_index(index: u32) -> Result<u32> {
+    index.checked_add(1).ok_or(PqcError::MessageTooLarge)
+}
+
+/// `prefix || index: u32 BE`
+fn chunk_nonce(prefix: &[u8; STREAM_NONCE_PREFIX_BYTES], index: u32) -> [u8; AES_NONCE_BYTES] {
+    let mut nonce = [0u8; AES_NONCE_BYTES];
+    nonce[..STREAM_NONCE_PREFIX_BYTES].copy_from_slice(prefix);
+    nonce[STREAM_NONCE_PREFIX_BYTES..].copy_from_slice(&index.to_be_bytes());
+    nonce
+}
+
+/// Fill `buf` from `reader`, stopping early only at end of input
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(_) => return Err(PqcError::IoFailure),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KyberKeys, AES_TAG_BYTES};
+    use std::vec::Vec;
+
+    fn roundtrip(len: usize) {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x31; 64]);
+        let plaintext: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+        let mut sealed = Vec::new();
+        assert_eq!(
+            seal_reader_to_writer_with_rng(&keys.pk, &mut rand::rngs::OsRng, &mut &plaintext[..], &mut sealed),
+            Ok(len as u64)
+        );
+        let chunks = len / STREAM_CHUNK_BYTES + 1;
+        assert_eq!(sealed.len(), STREAM_HEADER_BYTES + len + chunks * AES_TAG_BYTES);
+
+        let mut opened = Vec::new();
+        assert_eq!(open_reader_to_writer(&keys.sk, &mut &sealed[..], &mut opened), Ok(len as u64));
+        assert_eq!(opened, plaintext);
+    }
+
+    #[test]
+    fn test_stream_roundtrip_sizes() {
+        roundtrip(0);
+        roundtrip(1000);
+        roundtrip(STREAM_CHUNK_BYTES);
+        roundtrip(2 * STREAM_CHUNK_BYTES + 17);
+    }
+
+    /// Three full chunks plus a 5-byte final chunk
+    fn sealed_stream() -> (KyberKeys, Vec<u8>) {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x32; 64]);
+        let plaintext = vec![0xabu8; 3 * STREAM_CHUNK_BYTES + 5];
+        let mut sealed = Vec::new();
+        seal_reader_to_writer_with_rng(&keys.pk, &mut rand::rngs::OsRng, &mut &plaintext[..], &mut sealed).unwrap();
+        (keys, sealed)
+    }
+
+    const FULL: usize = STREAM_CHUNK_BYTES + AES_TAG_BYTES;
+
+    fn chunk(sealed: &[u8], i: usize) -> &[u8] {
+        let start = STREAM_HEADER_BYTES + i * FULL;
+        &sealed[start..(start + FULL).min(sealed.len())]
+    }
+
+    fn open(keys: &KyberKeys, bytes: &[u8]) -> Result<u64> {
+        open_reader_to_writer(&keys.sk, &mut &bytes[..], &mut Vec::new())
+    }
+
+    #[test]
+    fn test_stream_rejects_modified_chunk() {
+        let (keys, mut sealed) = sealed_stream();
+        sealed[STREAM_HEADER_BYTES + 10] ^= 0x01;
+        assert_eq!(open(&keys, &sealed), Err(PqcError::AeadOperationFailed));
+
+        let wrong = KyberKeys::generate_key_pair_with_seed([0x33; 64]);
+        let (_, sealed) = sealed_stream();
+        assert_eq!(open(&wrong, &sealed), Err(PqcError::AeadOperationFailed));
+    }
+
+    #[test]
+    fn test_stream_rejects_dropped_duplicated_reordered_chunks() {
+        let (keys, sealed) = sealed_stream();
+        let header = &sealed[..STREAM_HEADER_BYTES];
+        let rebuild = |order: &[usize]| {
+            let mut out = header.to_vec();
+            for &i in order {
+                out.extend_from_slice(chunk(&sealed, i));
+            }
+            out
+        };
+
+        assert_eq!(open(&keys, &rebuild(&[0, 1, 2, 3])), Ok(3 * STREAM_CHUNK_BYTES as u64 + 5));
+        assert_eq!(open(&keys, &rebuild(&[0, 2, 3])), Err(PqcError::AeadOperationFailed));
+        assert_eq!(open(&keys, &rebuild(&[0, 1, 1, 2, 3])), Err(PqcError::AeadOperationFailed));
+        assert_eq!(open(&keys, &rebuild(&[1, 0, 2, 3])), Err(PqcError::AeadOperationFailed));
+        assert_eq!(open(&keys, &rebuild(&[0, 1, 3, 2])), Err(PqcError::AeadOperationFailed));
+    }
+
+    #[test]
+    fn test_stream_rejects_truncation() {
+        let (keys, sealed) = sealed_stream();
+
+        // Final chunk missing: stream ends on a chunk boundary
+        let boundary = STREAM_HEADER_BYTES + 3 * FULL;
+        assert_eq!(open(&keys, &sealed[..boundary]), Err(PqcError::AeadOperationFailed));
+        assert_eq!(open(&keys, &sealed[..boundary + 1]), Err(PqcError::AeadOperationFailed));
+
+        // Cut inside a full chunk: the remainder is read as a final chunk
+        assert_eq!(open(&keys, &sealed[..boundary - 100]), Err(PqcError::AeadOperationFailed));
+
+        // Cut inside the final chunk
+        assert_eq!(open(&keys, &sealed[..sealed.len() - 1]), Err(PqcError::AeadOperationFailed));
+
+        // Cut inside the header
+        assert_eq!(open(&keys, &sealed[..STREAM_HEADER_BYTES - 1]), Err(PqcError::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_stream_header_records_aead() {
+        let (keys, mut sealed) = sealed_stream();
+        assert_eq!(sealed[0], AeadAlgorithm::Aes256Gcm.id());
+
+        let mut explicit = Vec::new();
+        let mut rng = rand::rngs::OsRng;
+        seal_reader_to_writer_with_aead(&keys.pk, AeadAlgorithm::Aes256Gcm, &mut rng, &mut &b"agile"[..], &mut explicit)
+            .unwrap();
+        assert_eq!(open(&keys, &explicit), Ok(5));
+
+        // Unknown ids are refused before decapsulation; known ones are bound to the chunks
+        sealed[0] = 0x7f;
+        assert_eq!(open(&keys, &sealed), Err(PqcError::UnsupportedAlgorithm));
+    }
+
+    #[test]
+    #[cfg(feature = "chacha20poly1305")]
+    fn test_chacha20_poly1305_stream_round_trip() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x34; 64]);
+        let plaintext: Vec<u8> = (0..2 * STREAM_CHUNK_BYTES + 7).map(|i| i as u8).collect();
+        let mut sealed = Vec::new();
+        let mut rng = rand::rngs::OsRng;
+        let aead = AeadAlgorithm::ChaCha20Poly1305;
+        seal_reader_to_writer_with_aead(&keys.pk, aead, &mut rng, &mut &plaintext[..], &mut sealed).unwrap();
+        assert_eq!(sealed[0], crate::aead::AEAD_ID_CHACHA20_POLY1305);
+
+        // The opener picks ChaCha20-Poly1305 from the header
+        let mut opened = Vec::new();
+        assert_eq!(open_reader_to_writer(&keys.sk, &mut &sealed[..], &mut opened), Ok(plaintext.len() as u64));
+        assert_eq!(opened, plaintext);
+
+        sealed[0] = AeadAlgorithm::Aes256Gcm.id();
+        assert_eq!(open(&keys, &sealed), Err(PqcError::AeadOperationFailed));
+    }
+
+    #[test]
+    fn test_chunk_counter_exhaustion() {
+        assert_eq!(next_index(0), Ok(1));
+        assert_eq!(next_index(u32::MAX - 1), Ok(u32::MAX));
+        assert_eq!(next_index(u32::MAX), Err(PqcError::MessageTooLarge));
+    }
+}
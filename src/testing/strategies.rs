@@ -0,0 +1,77 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Proptest Strategies for Keygen, Encapsulation and Signing Seeds
+// ------------------------------------------------------------------------
+//! The seed strategies used by this crate's own property tests, behind the
+//! `proptest-support` feature so downstream crates can drive `pqc_combo`
+//! APIs from `proptest!` without copying them.
+//!
+//! [`valid_seed_64`] feeds `KyberKeys::generate_key_pair_with_seed`,
+//! [`valid_seed_32`] the ML-DSA keygen seed and the encapsulation and
+//! signing randomness. Both skip the all-zero seed, which the seed
+//! validation rejects. [`deterministic_config`] pins the proptest RNG so a
+//! run generates the same cases every time, e.g. for reproducing a CI
+//! failure or comparing two builds.
+
+use proptest::prelude::*;
+use proptest::test_runner::{Config, RngSeed};
+
+/// Seed used by [`deterministic_config`]
+pub const DETERMINISTIC_SEED: u64 = 0x7071_635f_636f_6d62;
+
+/// 64-byte keygen seed (`d || z`), never all zeros
+pub fn valid_seed_64() -> impl Strategy<Value = [u8; 64]> {
+    prop::collection::vec(any::<u8>(), 64..=64)
+        .prop_filter("seed must not be all zeros", |v| v.iter().any(|&b| b != 0))
+        .prop_map(|v| {
+            let mut arr = [0u8; 64];
+            arr.copy_from_slice(&v);
+            arr
+        })
+}
+
+/// 32-byte seed or randomness, never all zeros
+pub fn valid_seed_32() -> impl Strategy<Value = [u8; 32]> {
+    prop::collection::vec(any::<u8>(), 32..=32)
+        .prop_filter("seed must not be all zeros", |v| v.iter().any(|&b| b != 0))
+        .prop_map(|v| {
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(&v);
+            arr
+        })
+}
+
+/// `cases` cases from a fixed RNG seed, with no failure persistence
+///
+/// Every run sees the same inputs in the same order, so a failure
+/// reproduces without a regressions file.
+pub fn deterministic_config(cases: u32) -> Config {
+    Config {
+        cases,
+        rng_seed: RngSeed::Fixed(DETERMINISTIC_SEED),
+        failure_persistence: None,
+        ..Config::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_deterministic_config_repeats_cases() {
+        let draw = || {
+            let mut runner = TestRunner::new(deterministic_config(4));
+            let mut seeds = Vec::new();
+            for _ in 0..4 {
+                seeds.push(valid_seed_64().new_tree(&mut runner).unwrap().current());
+            }
+            seeds
+        };
+        let first = draw();
+        assert_eq!(first, draw());
+        assert!(first.iter().all(|seed| seed.iter().any(|&b| b != 0)));
+    }
+}
@@ -0,0 +1,190 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Algorithm-Agnostic Traits
+// ------------------------------------------------------------------------
+//! Traits that let generic code work over any signature scheme or KEM.
+//!
+//! ML-DSA-65 implements [`Signer`] / [`Verifier`] on its key wrappers and
+//! ML-KEM-1024 implements [`Kem`] on the [`MlKem1024`] marker; other
+//! parameter sets or hybrid schemes can plug in behind the same interface.
+//!
+//! The implementations here consult the runtime whitelist
+//! ([`crate::set_allowed_parameter_sets`]) on every call and return
+//! `ParameterSetNotAllowed` for a refused set.
+
+use crate::error::Result;
+use crate::rng::SeedSource;
+
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+use crate::error::PqcError;
+#[cfg(any(feature = "ml-kem", all(feature = "ml-dsa", not(feature = "require-context"))))]
+use crate::info::{check_parameter_set, ParameterSet};
+#[cfg(feature = "ml-kem")]
+use crate::{KyberCiphertext, KyberPublicKey, KyberSecretKey, SharedSecret};
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+use crate::{verify_signature, DilithiumPublicKey, DilithiumSignature};
+
+/// Produces signatures over byte messages
+pub trait Signer {
+    type Signature;
+
+    /// Sign `msg`, drawing any randomness the scheme needs internally
+    fn sign(&self, msg: &[u8]) -> Result<Self::Signature>;
+}
+
+/// Checks signatures over byte messages
+pub trait Verifier {
+    type Signature;
+
+    /// `Ok(())` for a valid signature, `Err(VerificationFailure)` otherwise
+    fn verify(&self, msg: &[u8], sig: &Self::Signature) -> Result<()>;
+}
+
+/// Key encapsulation mechanism
+///
+/// Object-safe: handshake code can hold a `&dyn Kem<PublicKey = ..., ...>`
+/// and pick the parameter set at runtime.
+pub trait Kem {
+    type PublicKey;
+    type SecretKey;
+    type Ciphertext;
+    type SharedSecret;
+
+    /// Encapsulate to `pk` with randomness drawn from `rng`
+    fn encapsulate_with_rng(
+        &self,
+        pk: &Self::PublicKey,
+        rng: &mut dyn SeedSource,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret)>;
+
+    /// Encapsulate to `pk` with OS randomness
+    #[cfg(all(feature = "std", not(feature = "explicit-rng")))]
+    fn encapsulate(&self, pk: &Self::PublicKey) -> Result<(Self::Ciphertext, Self::SharedSecret)> {
+        self.encapsulate_with_rng(pk, &mut rand::rngs::OsRng)
+    }
+
+    fn decapsulate(&self, sk: &Self::SecretKey, ct: &Self::Ciphertext) -> Result<Self::SharedSecret>;
+}
+
+/// ML-KEM-1024 (FIPS 203) as a [`Kem`]
+#[cfg(feature = "ml-kem")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MlKem1024;
+
+/// Validating encapsulation and decapsulation (FIPS 203 §7.2 / §7.3)
+#[cfg(feature = "ml-kem")]
+impl Kem for MlKem1024 {
+    type PublicKey = KyberPublicKey;
+    type SecretKey = KyberSecretKey;
+    type Ciphertext = KyberCiphertext;
+    type SharedSecret = SharedSecret;
+
+    fn encapsulate_with_rng(
+        &self,
+        pk: &KyberPublicKey,
+        rng: &mut dyn SeedSource,
+    ) -> Result<(KyberCiphertext, SharedSecret)> {
+        check_parameter_set(ParameterSet::MlKem1024)?;
+        let mut randomness = crate::rng::seed_32_from(rng)?;
+        let result = crate::try_encapsulate_shared_secret_with_randomness(pk, randomness);
+        zeroize::Zeroize::zeroize(&mut randomness);
+        result
+    }
+
+    fn decapsulate(&self, sk: &KyberSecretKey, ct: &KyberCiphertext) -> Result<SharedSecret> {
+        check_parameter_set(ParameterSet::MlKem1024)?;
+        crate::try_decapsulate_shared_secret(sk, ct)
+    }
+}
+
+/// Hedged ML-DSA-65 signing with an empty context and OS randomness
+#[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "explicit-rng"), not(feature = "require-context")))]
+impl Signer for crate::DilithiumSecretKey {
+    type Signature = DilithiumSignature;
+
+    fn sign(&self, msg: &[u8]) -> Result<DilithiumSignature> {
+        check_parameter_set(ParameterSet::MlDsa65)?;
+        let mut randomness = crate::rng::seed_32_from(&mut rand::rngs::OsRng)?;
+        let sig = crate::sign_message_with_context_and_randomness(self, msg, &[], randomness);
+        zeroize::Zeroize::zeroize(&mut randomness);
+        sig
+    }
+}
+
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
+impl Verifier for DilithiumPublicKey {
+    type Signature = DilithiumSignature;
+
+    fn verify(&self, msg: &[u8], sig: &DilithiumSignature) -> Result<()> {
+        check_parameter_set(ParameterSet::MlDsa65)?;
+        if verify_signature(self, msg, sig) {
+            Ok(())
+        } else {
+            Err(PqcError::VerificationFailure)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::error::PqcError;
+
+    #[cfg(feature = "ml-kem")]
+    type DynMlKem = dyn Kem<
+        PublicKey = KyberPublicKey,
+        SecretKey = KyberSecretKey,
+        Ciphertext = KyberCiphertext,
+        SharedSecret = SharedSecret,
+    >;
+
+    /// Written once against the trait object, as handshake code would be
+    #[cfg(feature = "ml-kem")]
+    fn kem_round_trip(kem: &DynMlKem, pk: &KyberPublicKey, sk: &KyberSecretKey) -> Result<()> {
+        let (ct, ss) = kem.encapsulate_with_rng(pk, &mut rand::rngs::OsRng)?;
+        if kem.decapsulate(sk, &ct)? == ss {
+            Ok(())
+        } else {
+            Err(PqcError::DecapsulationFailure)
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ml-kem")]
+    fn test_kem_round_trip_per_parameter_set() {
+        // One entry per implemented parameter set
+        let kems: [(&DynMlKem, [u8; 64]); 1] = [(&MlKem1024, [0x62; 64])];
+        for (kem, seed) in kems {
+            let keys = crate::KyberKeys::generate_key_pair_with_seed(seed);
+            assert_eq!(kem_round_trip(kem, &keys.pk, &keys.sk), Ok(()));
+
+            let mut bad_pk = [0xffu8; crate::ML_KEM_1024_PK_BYTES];
+            bad_pk[..32].fill(0);
+            let bad_pk = KyberPublicKey::from(bad_pk);
+            assert!(matches!(
+                kem.encapsulate_with_rng(&bad_pk, &mut rand::rngs::OsRng),
+                Err(PqcError::InvalidPublicKey)
+            ));
+        }
+    }
+
+    #[cfg(all(feature = "ml-dsa", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    fn sign_and_check<S, V>(signer: &S, verifier: &V, msg: &[u8]) -> Result<()>
+    where
+        S: Signer,
+        V: Verifier<Signature = S::Signature>,
+    {
+        let sig = signer.sign(msg)?;
+        verifier.verify(msg, &sig)
+    }
+
+    #[test]
+    #[cfg(all(feature = "ml-dsa", not(feature = "explicit-rng"), not(feature = "require-context")))]
+    fn test_ml_dsa_signer_verifier() {
+        let (pk, sk) = crate::generate_dilithium_keypair_with_seed([0x61; 32]);
+        assert_eq!(sign_and_check(&sk, &pk, b"generic"), Ok(()));
+
+        let sig = Signer::sign(&sk, b"original").unwrap();
+        assert_eq!(Verifier::verify(&pk, b"forged", &sig), Err(PqcError::VerificationFailure));
+    }
+}
@@ -0,0 +1,129 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Length-Prefixed Wire Framing for KEM Handshake Messages
+// ------------------------------------------------------------------------
+//! Framing for the two messages of a KEM handshake:
+//!
+//! ```text
+//! msg_type (1) || alg_id (1) || body_len: u16 BE || body
+//! ```
+//!
+//! `msg_type` is 0x01 for a public key announcement and 0x02 for an
+//! encapsulation, and `alg_id` is 0x01 for ML-KEM-1024. Both bytes are
+//! needed because an ML-KEM-1024 public key and ciphertext are both 1568
+//! bytes long: with only a length and an algorithm id, one could be parsed
+//! as the other. A frame for another parameter set, another message type,
+//! or with a length that disagrees with the input is rejected with
+//! `InvalidEncoding` before the body is looked at.
+
+use alloc::vec::Vec;
+
+use crate::error::{PqcError, Result};
+use crate::{validate_public_key, KyberCiphertext, KyberPublicKey};
+
+/// `msg_type || alg_id || body_len`
+pub const WIRE_HEADER_BYTES: usize = 4;
+
+/// Algorithm id of ML-KEM-1024, as in the key blob formats
+pub const WIRE_ALG_ML_KEM_1024: u8 = 0x01;
+
+const WIRE_MSG_PUBLIC_KEY: u8 = 0x01;
+const WIRE_MSG_ENCAPSULATION: u8 = 0x02;
+
+/// Frame an ML-KEM-1024 public key announcement
+pub fn encode_public_key(pk: &KyberPublicKey) -> Vec<u8> {
+    encode(WIRE_MSG_PUBLIC_KEY, pk.as_slice())
+}
+
+/// Parse a public key announcement from an untrusted peer
+///
+/// Besides the framing checks, the key must pass the FIPS 203 §7.2
+/// modulus check (`InvalidPublicKey`).
+pub fn decode_public_key(frame: &[u8]) -> Result<KyberPublicKey> {
+    let pk = KyberPublicKey::try_from(decode(WIRE_MSG_PUBLIC_KEY, frame)?)?;
+    if !validate_public_key(&pk) {
+        return Err(PqcError::InvalidPublicKey);
+    }
+    Ok(pk)
+}
+
+/// Frame an ML-KEM-1024 ciphertext
+pub fn encode_encapsulation(ct: &KyberCiphertext) -> Vec<u8> {
+    encode(WIRE_MSG_ENCAPSULATION, ct.as_slice())
+}
+
+/// Parse an encapsulation frame
+///
+/// A body of the wrong size is `InvalidCiphertext`.
+pub fn decode_encapsulation(frame: &[u8]) -> Result<KyberCiphertext> {
+    KyberCiphertext::try_from(decode(WIRE_MSG_ENCAPSULATION, frame)?)
+}
+
+fn encode(msg_type: u8, body: &[u8]) -> Vec<u8> {
+    // Bodies are fixed-size ML-KEM-1024 encodings, far below u16::MAX
+    let len = body.len() as u16;
+    let mut out = Vec::with_capacity(WIRE_HEADER_BYTES + body.len());
+    out.push(msg_type);
+    out.push(WIRE_ALG_ML_KEM_1024);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(body);
+    out
+}
+
+/// Check the header and return the body
+fn decode(msg_type: u8, frame: &[u8]) -> Result<&[u8]> {
+    let (header, body) = frame
+        .split_first_chunk::<WIRE_HEADER_BYTES>()
+        .ok_or(PqcError::InvalidEncoding)?;
+    let len = usize::from(u16::from_be_bytes([header[2], header[3]]));
+    if header[0] != msg_type || header[1] != WIRE_ALG_ML_KEM_1024 || len != body.len() {
+        return Err(PqcError::InvalidEncoding);
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{encapsulate_shared_secret_with_randomness, KyberKeys, ML_KEM_1024_CT_BYTES};
+
+    #[test]
+    fn test_handshake_frames_roundtrip() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x91; 64]);
+        let announce = encode_public_key(&keys.pk);
+        assert_eq!(&announce[..WIRE_HEADER_BYTES], &[0x01, 0x01, 0x06, 0x20]);
+        let pk = decode_public_key(&announce).unwrap();
+        assert!(pk == keys.pk);
+
+        let (ct, _) = encapsulate_shared_secret_with_randomness(&pk, [0x92; 32]);
+        let reply = encode_encapsulation(&ct);
+        assert_eq!(reply.len(), WIRE_HEADER_BYTES + ML_KEM_1024_CT_BYTES);
+        assert_eq!(&reply[..2], &[0x02, 0x01]);
+        assert!(decode_encapsulation(&reply).unwrap() == ct);
+    }
+
+    #[test]
+    fn test_mismatched_frames_rejected() {
+        let keys = KyberKeys::generate_key_pair_with_seed([0x93; 64]);
+        let announce = encode_public_key(&keys.pk);
+
+        // Same body size, other message type
+        assert_eq!(decode_encapsulation(&announce).map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        let mut other_alg = announce.clone();
+        other_alg[1] = 0x02;
+        assert_eq!(decode_public_key(&other_alg).map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        let mut trailing = announce.clone();
+        trailing.push(0);
+        assert_eq!(decode_public_key(&trailing).map(|_| ()), Err(PqcError::InvalidEncoding));
+        assert_eq!(decode_public_key(&announce[..100]).map(|_| ()), Err(PqcError::InvalidEncoding));
+        assert_eq!(decode_public_key(&announce[..3]).map(|_| ()), Err(PqcError::InvalidEncoding));
+
+        // Well-framed, wrong body size or content
+        let short = encode(WIRE_MSG_ENCAPSULATION, &[0u8; 32]);
+        assert_eq!(decode_encapsulation(&short).map(|_| ()), Err(PqcError::InvalidCiphertext));
+        let unreduced = encode(WIRE_MSG_PUBLIC_KEY, &[0xff; crate::ML_KEM_1024_PK_BYTES]);
+        assert_eq!(decode_public_key(&unreduced).map(|_| ()), Err(PqcError::InvalidPublicKey));
+    }
+}
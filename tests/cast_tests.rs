@@ -76,7 +76,6 @@ fn test_individual_cast_failures_propagate() {
     match result {
         Ok(()) => {
             // Expected path - all CASTs pass
-            assert!(true);
         }
         Err(PqcError::CastFailure) => {
             panic!("CAST failed - this indicates a serious implementation bug");
@@ -106,6 +106,7 @@ mod nist_dsa_vectors {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_dsa_sign_vector_1() {
         let keygen_seed_hex = "061550234D158C5EC95595FE04EF7A25767F2E24CC2BC479D09D86DC9ABCFDE7";
         let keygen_seed_bytes = hex::decode(keygen_seed_hex).unwrap();
@@ -128,6 +129,7 @@ mod nist_dsa_vectors {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_dsa_determinism() {
         let keygen_seed = [0x42; 32];
         let sign_seed = [0x43; 32];
@@ -148,6 +150,7 @@ mod nist_dsa_vectors {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_dsa_wrong_key() {
         let seed1 = [0x01; 32];
         let seed2 = [0x02; 32];
@@ -163,6 +166,58 @@ mod nist_dsa_vectors {
     }
 }
 
+/// Seed byte order: seeds are passed to FIPS 203 / FIPS 204 as the byte
+/// strings the ACVP `d`, `z` and `seed` hex fields spell, first byte first
+mod seed_byte_order {
+    use super::*;
+
+    /// ACVP ML-DSA keyGen (FIPS204, vsId 42), tgId 2 (ML-DSA-65), tcId 26
+    const ACVP_DSA_SEED: &str = "70CEFB9AED5B68E018B079DA8284B9D5CAD5499ED9C265FF73588005D85C225C";
+    const ACVP_DSA_PK: &[u8] = include_bytes!("vectors/acvp_ml_dsa_65_keygen_tc26_pk.bin");
+
+    /// `d = 00..1f`, `z = 20..3f`; the public key also matches what OpenSSL
+    /// 3.5 derives from this 64-byte seed (`genpkey -pkeyopt hexseed:`)
+    const KEM_D: &str = "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F";
+    const KEM_Z: &str = "202122232425262728292A2B2C2D2E2F303132333435363738393A3B3C3D3E3F";
+    const KEM_PK: &[u8] = include_bytes!("../src/kat_vectors/ml_kem_1024_pk.bin");
+
+    fn kem_seed(d: &[u8], z: &[u8]) -> [u8; ML_KEM_KEYGEN_SEED_BYTES] {
+        [d, z].concat().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_dsa_acvp_keygen_public_key_matches() {
+        let seed: [u8; ML_DSA_KEYGEN_SEED_BYTES] = hex::decode(ACVP_DSA_SEED).unwrap().try_into().unwrap();
+        let (pk, _sk) = generate_dilithium_keypair_with_seed(seed);
+        assert_eq!(pk.as_slice(), ACVP_DSA_PK);
+
+        // A byte-reversed seed is a different key
+        let mut reversed = seed;
+        reversed.reverse();
+        assert_ne!(generate_dilithium_keypair_with_seed(reversed).0.as_slice(), ACVP_DSA_PK);
+    }
+
+    #[test]
+    fn test_kem_keygen_seed_is_d_then_z() {
+        let (d, z) = (hex::decode(KEM_D).unwrap(), hex::decode(KEM_Z).unwrap());
+        let keys = KyberKeys::generate_key_pair_with_seed(kem_seed(&d, &z));
+        assert_eq!(keys.pk.as_slice(), KEM_PK);
+
+        // FIPS 203 dk = dk_pke || ek || H(ek) || z: z is the second half
+        let sk = keys.sk.as_slice();
+        assert_eq!(&sk[ML_KEM_1024_SK_BYTES - 32..], &z[..]);
+        assert_eq!(&sk[1536..1536 + ML_KEM_1024_PK_BYTES], KEM_PK);
+
+        // ek depends on d only: a new z keeps it, a new d or swapped halves do not
+        let other_z = KyberKeys::generate_key_pair_with_seed(kem_seed(&d, &[0x5a; 32]));
+        assert_eq!(other_z.pk.as_slice(), KEM_PK);
+        let other_d = KyberKeys::generate_key_pair_with_seed(kem_seed(&[0x5a; 32], &z));
+        assert_ne!(other_d.pk.as_slice(), KEM_PK);
+        let swapped = KyberKeys::generate_key_pair_with_seed(kem_seed(&z, &d));
+        assert_ne!(swapped.pk.as_slice(), KEM_PK);
+    }
+}
+
 /// Interoperability Tests
 mod interop_tests {
     use super::*;
@@ -171,20 +226,13 @@ mod interop_tests {
     fn test_key_serialization_roundtrip() {
         let keys = KyberKeys::generate_key_pair();
         
-        // Serialize - get owned bytes
-        let pk_slice = keys.pk.as_slice();
-        let sk_slice = keys.sk.as_slice();
-        
-        // Copy to arrays
-        let mut pk_array = [0u8; ML_KEM_1024_PK_BYTES];
-        pk_array.copy_from_slice(pk_slice);
-        
-        let mut sk_array = [0u8; ML_KEM_1024_SK_BYTES];
-        sk_array.copy_from_slice(sk_slice);
+        // Serialize - owned fixed-size arrays (secret key copy is Zeroizing)
+        let pk_array: [u8; ML_KEM_1024_PK_BYTES] = keys.pk.to_array();
+        let sk_array = keys.sk.to_array();
         
         // Deserialize
         let pk_restored = KyberPublicKey::from(pk_array);
-        let sk_restored = KyberSecretKey::from(sk_array);
+        let sk_restored = KyberSecretKey::from(*sk_array);
         
         // Test functionality
         let (ct, ss1) = encapsulate_shared_secret(&pk_restored);
@@ -195,6 +243,7 @@ mod interop_tests {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_signature_serialization_roundtrip() {
         let (pk, sk) = generate_dilithium_keypair();
         let message = b"Test message";
@@ -212,8 +261,13 @@ mod interop_tests {
         // Verify original signature still works
         assert!(verify_signature(&pk, message, &sig));
         
-        // Note: libcrux MLDSASignature doesn't support reconstruction from bytes
-        // In practice, signatures are stored as bytes and passed to verify_signature directly
+        // Stored bytes verify directly, without rebuilding a DilithiumSignature
+        let stored = sig_bytes.to_vec();
+        assert_eq!(verify_signature_bytes(&pk, message, &stored), Ok(()));
+        assert_eq!(
+            verify_signature_bytes(&pk, b"Other message", &stored),
+            Err(PqcError::VerificationFailure)
+        );
         
         println!("✓ Signature properties verified");
     }
@@ -248,10 +302,10 @@ mod interop_tests {
         let dsa_pk_hash = Sha3_256::digest(dsa_pk.as_slice());
         let dsa_sk_hash = Sha3_256::digest(dsa_sk.as_slice());
         
-        println!("KEM PK hash: {}", hex::encode(&kem_pk_hash));
-        println!("KEM SK hash: {}", hex::encode(&kem_sk_hash));
-        println!("DSA PK hash: {}", hex::encode(&dsa_pk_hash));
-        println!("DSA SK hash: {}", hex::encode(&dsa_sk_hash));
+        println!("KEM PK hash: {}", hex::encode(kem_pk_hash));
+        println!("KEM SK hash: {}", hex::encode(kem_sk_hash));
+        println!("DSA PK hash: {}", hex::encode(dsa_pk_hash));
+        println!("DSA SK hash: {}", hex::encode(dsa_sk_hash));
         
         println!("✓ Cross-platform determinism test passed");
     }
@@ -276,6 +330,7 @@ mod fips_compliance {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_fips_204_key_sizes() {
         let (pk, sk) = generate_dilithium_keypair();
         
@@ -294,6 +349,7 @@ mod edge_cases {
     use super::*;
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_empty_message_signature() {
         let (pk, sk) = generate_dilithium_keypair();
         let empty_msg = b"";
@@ -305,6 +361,7 @@ mod edge_cases {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_large_message_signature() {
         let (pk, sk) = generate_dilithium_keypair();
         let large_msg = vec![0x42u8; 10_000];
@@ -330,6 +387,7 @@ mod edge_cases {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn test_minimum_entropy_seeds() {
         let mut min_seed_64 = [0u8; 64];
         min_seed_64[0] = 1;
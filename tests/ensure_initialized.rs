@@ -0,0 +1,35 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Integration test: one-time POST on first use
+// ------------------------------------------------------------------------
+// Own test binary: the one-time initializer is process-global state.
+
+use pqc_combo::{ensure_initialized, get_fips_state, reset_fips_state, FipsEvent, FipsState, PqcError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn test_post_runs_once_on_first_use() {
+    let post_runs = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&post_runs);
+    pqc_combo::set_event_handler(Box::new(move |event| {
+        if event == FipsEvent::PostStarted {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+    }));
+
+    assert_eq!(get_fips_state(), FipsState::Uninitialized);
+    let callers: Vec<_> = (0..8).map(|_| std::thread::spawn(ensure_initialized)).collect();
+    for caller in callers {
+        assert_eq!(caller.join().unwrap(), Ok(()));
+    }
+    assert_eq!(get_fips_state(), FipsState::Operational);
+    assert_eq!(ensure_initialized(), Ok(()));
+    assert_eq!(post_runs.load(Ordering::SeqCst), 1);
+
+    // A reset is not undone behind the caller's back
+    reset_fips_state();
+    assert_eq!(ensure_initialized(), Err(PqcError::FipsNotInitialized));
+    assert_eq!(post_runs.load(Ordering::SeqCst), 1);
+    pqc_combo::clear_event_handler();
+}
@@ -70,7 +70,7 @@ fn test_generate_key_pair_with_pct_returns_valid_keys() {
 }
 
 #[test]
-#[cfg(feature = "ml-dsa")]
+#[cfg(all(feature = "ml-dsa", not(feature = "require-context")))]
 fn test_generate_dilithium_keypair_with_pct_returns_valid_keys() {
     let result = generate_dilithium_keypair_with_pct();
     assert!(result.is_ok(), "Dilithium key generation with PCT should succeed");
@@ -89,13 +89,13 @@ fn test_pct_repeatable_across_multiple_generations() {
     // Verify PCT consistently passes for multiple key generations
     for i in 0..20 {
         let keys = KyberKeys::generate_key_pair_with_pct()
-            .expect(&format!("Kyber PCT failed on iteration {}", i));
+            .unwrap_or_else(|_| panic!("Kyber PCT failed on iteration {}", i));
 
         // Double-check with explicit PCT call
         assert!(kyber_pct(&keys).is_ok(), "Explicit PCT should also pass");
 
         let (pk, sk) = generate_dilithium_keypair_with_pct()
-            .expect(&format!("Dilithium PCT failed on iteration {}", i));
+            .unwrap_or_else(|_| panic!("Dilithium PCT failed on iteration {}", i));
 
         // Double-check with explicit PCT call
         assert!(dilithium_pct(&pk, &sk).is_ok(), "Explicit PCT should also pass");
@@ -103,7 +103,7 @@ fn test_pct_repeatable_across_multiple_generations() {
 }
 
 #[test]
-#[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+#[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
 fn test_pct_integrated_workflow() {
     // Full workflow using PCT-validated keys
 
@@ -131,10 +131,10 @@ fn test_pct_integrated_workflow() {
         let nonce = [0x42u8; 12];
         let plaintext = b"Encrypted with PCT-validated keys";
 
-        let ciphertext = encrypt_aes_gcm(&ss_sender, &nonce, plaintext)
+        let ciphertext = encrypt_aes_gcm(ss_sender.as_bytes(), &nonce, plaintext)
             .expect("AES-GCM encryption should succeed");
 
-        let decrypted = decrypt_aes_gcm(&ss_receiver, &nonce, &ciphertext)
+        let decrypted = decrypt_aes_gcm(ss_receiver.as_bytes(), &nonce, &ciphertext)
             .expect("AES-GCM decryption should succeed");
 
         assert_eq!(plaintext, &decrypted[..], "Decryption should recover original plaintext");
@@ -142,7 +142,7 @@ fn test_pct_integrated_workflow() {
 }
 
 #[test]
-#[cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+#[cfg(all(feature = "ml-kem", feature = "ml-dsa", not(feature = "require-context")))]
 fn test_pct_concurrent_key_generation() {
     use std::thread;
 
@@ -152,10 +152,10 @@ fn test_pct_concurrent_key_generation() {
         handles.push(thread::spawn(move || {
             // Each thread generates keys with PCT
             let kyber_keys = KyberKeys::generate_key_pair_with_pct()
-                .expect(&format!("Thread {} Kyber PCT failed", i));
+                .unwrap_or_else(|_| panic!("Thread {} Kyber PCT failed", i));
 
             let (dil_pk, dil_sk) = generate_dilithium_keypair_with_pct()
-                .expect(&format!("Thread {} Dilithium PCT failed", i));
+                .unwrap_or_else(|_| panic!("Thread {} Dilithium PCT failed", i));
 
             // Verify operations work
             let (ct, ss_a) = encapsulate_shared_secret(&kyber_keys.pk);
@@ -0,0 +1,79 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Integration test: injected DRBG reseeds after fork()
+// ------------------------------------------------------------------------
+#![cfg(unix)]
+
+use pqc_combo::rng::{generate_seed_32, seed_32_from};
+use pqc_combo::{PqcError, Reseeding, SeedSource};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+
+/// Minimal SHAKE-256 DRBG; its whole state is copied into a forked child
+struct ShakeDrbg([u8; 32]);
+
+impl ShakeDrbg {
+    fn squeeze(&mut self, extra: &[u8], out: &mut [u8]) {
+        let mut xof = Shake256::default();
+        xof.update(&self.0);
+        xof.update(extra);
+        let mut reader = xof.finalize_xof();
+        reader.read(out);
+        reader.read(&mut self.0);
+    }
+}
+
+impl SeedSource for ShakeDrbg {
+    fn fill(&mut self, dest: &mut [u8]) -> Result<(), PqcError> {
+        self.squeeze(b"", dest);
+        Ok(())
+    }
+
+    fn reseed(&mut self) -> Result<(), PqcError> {
+        let entropy = generate_seed_32();
+        self.squeeze(&entropy, &mut []);
+        Ok(())
+    }
+}
+
+/// Fork `n` children that each draw one seed from `rng`; collect the seeds
+fn seeds_from_children<R: SeedSource>(rng: &mut R, n: usize) -> Vec<[u8; 32]> {
+    (0..n)
+        .map(|_| {
+            let mut fds = [0; 2];
+            assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+            let pid = unsafe { libc::fork() };
+            assert!(pid >= 0, "fork failed");
+            if pid == 0 {
+                let seed = seed_32_from(rng).unwrap_or([0u8; 32]);
+                unsafe {
+                    libc::write(fds[1], seed.as_ptr().cast(), seed.len());
+                    libc::_exit(0);
+                }
+            }
+            let mut seed = [0u8; 32];
+            unsafe {
+                libc::close(fds[1]);
+                assert_eq!(libc::read(fds[0], seed.as_mut_ptr().cast(), seed.len()), 32);
+                libc::close(fds[0]);
+                libc::waitpid(pid, core::ptr::null_mut(), 0);
+            }
+            seed
+        })
+        .collect()
+}
+
+#[test]
+fn test_forked_children_get_distinct_seeds() {
+    // Without the wrapper both children replay the parent's DRBG state
+    let mut raw = ShakeDrbg([0x42; 32]);
+    let raw_seeds = seeds_from_children(&mut raw, 2);
+    assert_eq!(raw_seeds[0], raw_seeds[1]);
+
+    let mut guarded = Reseeding::new(ShakeDrbg([0x42; 32]));
+    let parent_seed = seed_32_from(&mut guarded).unwrap();
+    let child_seeds = seeds_from_children(&mut guarded, 2);
+    assert_ne!(child_seeds[0], [0u8; 32]);
+    assert_ne!(child_seeds[0], child_seeds[1]);
+    assert!(!child_seeds.contains(&parent_seed));
+}
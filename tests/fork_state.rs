@@ -0,0 +1,117 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Integration test: a fork()ed child does not inherit the POST or
+// Operational state
+// ------------------------------------------------------------------------
+#![cfg(unix)]
+
+use pqc_combo::state::check_operational;
+use pqc_combo::{
+    clear_event_handler, ensure_initialized, get_fips_state, reinitialize, run_post,
+    set_event_handler, FipsEvent, FipsState, PqcError,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// The tests share the process-wide FIPS state
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Run `child` in a forked process and return the bytes it reports
+fn in_child(child: fn() -> [u8; 3]) -> [u8; 3] {
+    let mut fds = [0; 2];
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+    let pid = unsafe { libc::fork() };
+    assert!(pid >= 0, "fork failed");
+    if pid == 0 {
+        let report = child();
+        unsafe {
+            libc::write(fds[1], report.as_ptr().cast(), report.len());
+            libc::_exit(0);
+        }
+    }
+    let mut report = [0u8; 3];
+    unsafe {
+        libc::close(fds[1]);
+        assert_eq!(libc::read(fds[0], report.as_mut_ptr().cast(), report.len()), 3);
+        libc::close(fds[0]);
+        libc::waitpid(pid, core::ptr::null_mut(), 0);
+    }
+    report
+}
+
+#[test]
+fn test_forked_child_must_rerun_post() {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    // First use caches this process's POST result
+    assert_eq!(ensure_initialized(), Ok(()));
+    assert_eq!(check_operational(), Ok(()));
+
+    let report = in_child(|| {
+        let inherited = check_operational() == Err(PqcError::FipsNotInitialized);
+        let state = get_fips_state() == FipsState::Uninitialized;
+        let rerun = run_post().is_ok() && check_operational().is_ok();
+        [inherited as u8, state as u8, rerun as u8]
+    });
+    assert_eq!(report[0], 1, "child saw the parent's Operational state");
+    assert_eq!(report[1], 1, "child state was not reset to Uninitialized");
+    assert_eq!(report[2], 1, "child could not run its own POST");
+
+    // Nor does it trust the parent's cached first-use POST result
+    let report = in_child(|| {
+        let inherited = check_operational() == Err(PqcError::FipsNotInitialized);
+        let started = get_fips_state() == FipsState::Uninitialized;
+        let rerun = ensure_initialized() == Ok(()) && check_operational().is_ok();
+        [inherited as u8, started as u8, rerun as u8]
+    });
+    assert_eq!(report[0], 1, "child saw the parent's Operational state");
+    assert_eq!(report[1], 1, "child state was not reset to Uninitialized");
+    assert_eq!(report[2], 1, "ensure_initialized did not run POST in the child");
+
+    // The parent is unaffected
+    assert_eq!(check_operational(), Ok(()));
+}
+
+#[test]
+fn test_child_forked_during_post_runs_its_own_post() {
+    static FORKED: AtomicBool = AtomicBool::new(false);
+    static REPORTS: Mutex<Vec<[u8; 3]>> = Mutex::new(Vec::new());
+
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+
+    // Fork from inside the parent's POST; the children inherit the POST
+    // state and the (already set) FORKED flag
+    set_event_handler(Box::new(|event| {
+        if event != FipsEvent::PostStarted || FORKED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let after_run_post = in_child(|| {
+            let rerun = run_post() == Ok(());
+            let operational = check_operational() == Ok(());
+            let state = get_fips_state() == FipsState::Operational;
+            [rerun as u8, operational as u8, state as u8]
+        });
+        let after_reinitialize = in_child(|| {
+            let rerun = reinitialize() == Ok(());
+            let operational = check_operational() == Ok(());
+            let state = get_fips_state() == FipsState::Operational;
+            [rerun as u8, operational as u8, state as u8]
+        });
+        let mut reports = REPORTS.lock().unwrap();
+        reports.push(after_run_post);
+        reports.push(after_reinitialize);
+    }));
+    let parent = run_post();
+    clear_event_handler();
+    assert_eq!(parent, Ok(()));
+
+    let reports = REPORTS.lock().unwrap();
+    assert_eq!(reports.len(), 2, "POST did not start in the parent");
+    for (report, first_call) in reports.iter().zip(["run_post", "reinitialize"]) {
+        assert_eq!(report[0], 1, "{first_call} failed in a child forked during POST");
+        assert_eq!(report[1], 1, "child is not operational after {first_call}");
+        assert_eq!(report[2], 1, "child state is not Operational after {first_call}");
+    }
+
+    // The parent is unaffected
+    assert_eq!(check_operational(), Ok(()));
+}
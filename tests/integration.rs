@@ -18,7 +18,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "ml-dsa", feature = "std"))]
+    #[cfg(all(feature = "ml-dsa", feature = "std", not(feature = "require-context")))]
     fn test_ml_dsa_sign_verify() {
         let (pk, sk) = generate_dilithium_keypair();
         let msg = b"hello pqc";
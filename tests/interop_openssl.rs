@@ -0,0 +1,126 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Integration test: ML-DSA-65 interop with the OpenSSL 3.5+ CLI
+// ------------------------------------------------------------------------
+//! Signs with this crate and verifies with `openssl pkeyutl`, and the other
+//! way round, exchanging public keys as DER SubjectPublicKeyInfo. Each test
+//! prints a note and passes if no `openssl` with ML-DSA-65 is on `PATH`.
+
+use pqc_combo::*;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// SPKI prefix for an ML-DSA-65 key (OID 2.16.840.1.101.3.4.3.18,
+/// RFC 9881): SEQUENCE { SEQUENCE { OID }, BIT STRING { 0x00 || pk } }
+const SPKI_PREFIX: [u8; 22] = [
+    0x30, 0x82, 0x07, 0xb2, 0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03,
+    0x12, 0x03, 0x82, 0x07, 0xa1, 0x00,
+];
+
+const MESSAGE: &[u8] = b"pqc-combo <-> OpenSSL ML-DSA-65 interop";
+
+fn spki_der(pk: &DilithiumPublicKey) -> Vec<u8> {
+    [&SPKI_PREFIX[..], pk.as_slice()].concat()
+}
+
+fn public_key_from_spki(der: &[u8]) -> DilithiumPublicKey {
+    assert_eq!(der[..SPKI_PREFIX.len()], SPKI_PREFIX, "unexpected SPKI header");
+    DilithiumPublicKey::try_from(&der[SPKI_PREFIX.len()..]).unwrap()
+}
+
+/// `true` if an `openssl` binary that knows ML-DSA-65 is available
+fn openssl_available() -> bool {
+    let listed = Command::new("openssl")
+        .args(["list", "-signature-algorithms"])
+        .output()
+        .map(|out| out.status.success() && String::from_utf8_lossy(&out.stdout).contains("ML-DSA-65"))
+        .unwrap_or(false);
+    if !listed {
+        eprintln!("skipping: no openssl CLI with ML-DSA-65 support on PATH");
+    }
+    listed
+}
+
+/// Per-test scratch directory, removed on drop
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("pqc-interop-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+
+    fn path(&self, file: &str) -> String {
+        self.0.join(file).to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn openssl(args: &[&str]) -> std::process::Output {
+    Command::new("openssl").args(args).output().expect("failed to run openssl")
+}
+
+#[test]
+fn test_openssl_verifies_our_signature() {
+    if !openssl_available() {
+        return;
+    }
+    let dir = Scratch::new("ours");
+    let (pk, sk) = generate_dilithium_keypair();
+    let sig = sign_message(&sk, MESSAGE);
+
+    std::fs::write(dir.path("pk.der"), spki_der(&pk)).unwrap();
+    std::fs::write(dir.path("msg"), MESSAGE).unwrap();
+    std::fs::write(dir.path("sig"), sig.as_slice()).unwrap();
+
+    let (pk_path, msg_path, sig_path) = (dir.path("pk.der"), dir.path("msg"), dir.path("sig"));
+    let verify = |sig_file: &str| {
+        openssl(&[
+            "pkeyutl", "-verify", "-rawin", "-pubin", "-keyform", "DER", "-inkey", &pk_path,
+            "-in", &msg_path, "-sigfile", sig_file,
+        ])
+    };
+    let out = verify(&sig_path);
+    assert!(out.status.success(), "openssl rejected our signature: {}", String::from_utf8_lossy(&out.stderr));
+
+    let mut tampered = sig.to_array();
+    tampered[0] ^= 0x01;
+    std::fs::write(dir.path("bad_sig"), tampered).unwrap();
+    assert!(!verify(&dir.path("bad_sig")).status.success());
+}
+
+#[test]
+fn test_we_verify_openssl_signature() {
+    if !openssl_available() {
+        return;
+    }
+    let dir = Scratch::new("theirs");
+    let seed = [0x44u8; ML_DSA_KEYGEN_SEED_BYTES];
+    let (key_path, pk_path) = (dir.path("key.pem"), dir.path("pk.der"));
+    let (msg_path, sig_path) = (dir.path("msg"), dir.path("sig"));
+
+    let hexseed = format!("hexseed:{}", hex::encode(seed));
+    let out = openssl(&["genpkey", "-algorithm", "ML-DSA-65", "-pkeyopt", &hexseed, "-out", &key_path]);
+    assert!(out.status.success(), "openssl genpkey failed: {}", String::from_utf8_lossy(&out.stderr));
+    let out = openssl(&["pkey", "-in", &key_path, "-pubout", "-outform", "DER", "-out", &pk_path]);
+    assert!(out.status.success());
+
+    // Same FIPS 204 seed, same key pair
+    let pk = public_key_from_spki(&std::fs::read(&pk_path).unwrap());
+    let (our_pk, _) = generate_dilithium_keypair_with_seed(seed);
+    assert!(pk == our_pk, "key generation from the same seed differs");
+
+    std::fs::write(&msg_path, MESSAGE).unwrap();
+    let out = openssl(&["pkeyutl", "-sign", "-rawin", "-inkey", &key_path, "-in", &msg_path, "-out", &sig_path]);
+    assert!(out.status.success(), "openssl pkeyutl -sign failed: {}", String::from_utf8_lossy(&out.stderr));
+
+    let sig = signature_from_bytes(&std::fs::read(&sig_path).unwrap()).unwrap();
+    assert!(verify_signature(&pk, MESSAGE, &sig));
+    assert!(!verify_signature(&pk, b"different message", &sig));
+}
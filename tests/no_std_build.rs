@@ -0,0 +1,34 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Integration test: the core API links and runs without std
+// ------------------------------------------------------------------------
+//! Builds and runs the `#![no_std]` binary in `no_std_check/` against this
+//! crate with `default-features = false, features = ["ml-kem"]`.
+//!
+//! The binary is a separate crate because an integration test of this
+//! package is always linked with its dev-dependencies, and those turn
+//! `std` back on through feature unification. The nested build uses its
+//! own target directory and runs `--locked --offline` against the committed
+//! `no_std_check/Cargo.lock`, so it never touches the network or rewrites
+//! the lockfile; the outer build has already fetched every dependency.
+#![cfg(unix)]
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_no_std_binary_builds_and_runs() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("no_std_check/Cargo.toml");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_std_check");
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let status = Command::new(cargo)
+        .args(["run", "--quiet", "--locked", "--offline", "--manifest-path"])
+        .arg(&manifest)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .status()
+        .expect("failed to spawn cargo");
+
+    // 101 is a build failure; 1..=3 name the failed smoke check
+    assert!(status.success(), "no_std smoke binary failed: {}", status);
+}
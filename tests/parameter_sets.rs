@@ -0,0 +1,38 @@
+//! Runtime parameter-set whitelist, in its own process because the
+//! whitelist is global
+//!
+//! Uses `Kem::encapsulate` and `Signer::sign`, which `explicit-rng` and
+//! `require-context` remove.
+#![cfg(not(any(feature = "explicit-rng", feature = "require-context")))]
+
+use pqc_combo::traits::{Kem, MlKem1024, Signer, Verifier};
+use pqc_combo::*;
+
+#[test]
+fn test_whitelist_gates_trait_implementations() {
+    let kem = KyberKeys::generate_key_pair_with_seed([0x71; 64]);
+    let dsa = DilithiumKeypair::generate_with_seed([0x72; 32]);
+    assert!(allowed_parameter_sets().eq(ParameterSet::ALL));
+
+    let (ct, ss) = MlKem1024.encapsulate(&kem.pk).unwrap();
+    let sig = dsa.sk.sign(b"whitelist").unwrap();
+
+    // Level 5 only: ML-KEM-1024 stays usable, ML-DSA-65 is refused
+    set_allowed_parameter_sets(&[ParameterSet::MlKem1024]);
+    assert!(is_parameter_set_allowed(ParameterSet::MlKem1024));
+    assert!(!is_parameter_set_allowed(ParameterSet::MlDsa65));
+    assert!(allowed_parameter_sets().eq([ParameterSet::MlKem1024]));
+    assert_eq!(MlKem1024.decapsulate(&kem.sk, &ct).unwrap(), ss);
+    assert_eq!(dsa.sk.sign(b"whitelist").map(|_| ()), Err(PqcError::ParameterSetNotAllowed));
+    assert_eq!(dsa.pk.verify(b"whitelist", &sig), Err(PqcError::ParameterSetNotAllowed));
+
+    // The typed free functions are not governed by the whitelist
+    assert!(verify_signature(&dsa.pk, b"whitelist", &sig));
+
+    set_allowed_parameter_sets(&[]);
+    assert!(matches!(MlKem1024.encapsulate(&kem.pk), Err(PqcError::ParameterSetNotAllowed)));
+    assert!(matches!(MlKem1024.decapsulate(&kem.sk, &ct), Err(PqcError::ParameterSetNotAllowed)));
+
+    set_allowed_parameter_sets(&ParameterSet::ALL);
+    assert_eq!(dsa.pk.verify(b"whitelist", &sig), Ok(()));
+}
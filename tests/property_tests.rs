@@ -1,31 +1,8 @@
 use proptest::prelude::*;
 use pqc_combo::*;
 
-// Strategy for generating valid 64-byte seeds
-fn valid_seed_64() -> impl Strategy<Value = [u8; 64]> {
-    prop::collection::vec(any::<u8>(), 64..=64)
-        .prop_filter("seed must not be all zeros", |v| {
-            v.iter().any(|&b| b != 0)
-        })
-        .prop_map(|v| {
-            let mut arr = [0u8; 64];
-            arr.copy_from_slice(&v);
-            arr
-        })
-}
-
-// Strategy for generating valid 32-byte seeds
-fn valid_seed_32() -> impl Strategy<Value = [u8; 32]> {
-    prop::collection::vec(any::<u8>(), 32..=32)
-        .prop_filter("seed must not be all zeros", |v| {
-            v.iter().any(|&b| b != 0)
-        })
-        .prop_map(|v| {
-            let mut arr = [0u8; 32];
-            arr.copy_from_slice(&v);
-            arr
-        })
-}
+// The same strategies downstream crates get from `testing::strategies`
+use pqc_combo::testing::strategies::{valid_seed_32, valid_seed_64};
 
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(100))]
@@ -98,6 +75,7 @@ proptest! {
     // ======== ML-DSA Properties ========
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn prop_dilithium_roundtrip(
         seed in valid_seed_32(),
         msg in prop::collection::vec(any::<u8>(), 0..1000)
@@ -108,6 +86,7 @@ proptest! {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn prop_dilithium_deterministic(
         keygen_seed in valid_seed_32(),
         sign_seed in valid_seed_32(),
@@ -126,6 +105,7 @@ proptest! {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn prop_dilithium_tamper_detection(
         seed in valid_seed_32(),
         msg in prop::collection::vec(any::<u8>(), 1..1000),
@@ -143,6 +123,7 @@ proptest! {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn prop_dilithium_wrong_key(
         seed1 in valid_seed_32(),
         seed2 in valid_seed_32(),
@@ -165,6 +146,7 @@ proptest! {
     }
 
     #[test]
+    #[cfg(not(feature = "require-context"))]
     fn prop_dilithium_sig_size(
         seed in valid_seed_32(),
         msg in prop::collection::vec(any::<u8>(), 0..1000)
@@ -189,7 +171,7 @@ proptest! {
     }
 }
 
-#[cfg(feature = "aes-gcm")]
+#[cfg(all(feature = "aes-gcm", feature = "alloc"))]
 mod aes_properties {
     use super::*;
 
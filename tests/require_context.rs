@@ -0,0 +1,45 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.0.7
+// Integration tests for the require-context feature
+// ------------------------------------------------------------------------
+// Run with: cargo test --features require-context --test require_context
+// (plain `cargo test --features require-context` also builds: the tests
+// elsewhere that sign with an empty context are compiled out)
+
+use pqc_combo::sig::SigningContext;
+use pqc_combo::*;
+
+#[test]
+fn test_empty_context_rejected() {
+    let (pk, sk) = generate_dilithium_keypair_with_seed([0x31; 32]);
+
+    assert_eq!(
+        sign_message_with_context_and_randomness(&sk, b"msg", b"", [0x32; 32]).map(|_| ()),
+        Err(PqcError::EmptyContext)
+    );
+    assert_eq!(sign_message_deterministic(&sk, b"msg", b"").map(|_| ()), Err(PqcError::EmptyContext));
+    assert_eq!(sign_message_with_context(&sk, b"msg", b"").map(|_| ()), Err(PqcError::EmptyContext));
+    assert_eq!(
+        SigningContext::new().sign_deterministic(&sk, b"msg").map(|_| ()),
+        Err(PqcError::EmptyContext)
+    );
+    #[cfg(feature = "acvp")]
+    assert_eq!(
+        prehash::sign_message_prehashed_with_randomness(&sk, b"msg", b"", [0x32; 32]).map(|_| ()),
+        Err(PqcError::EmptyContext)
+    );
+
+    // A non-empty context still signs, and an empty one never verifies
+    let sig = sign_message_deterministic(&sk, b"msg", b"svc").unwrap();
+    assert!(verify_signature_with_context(&pk, b"msg", b"svc", &sig));
+    assert!(!verify_signature_with_context(&pk, b"msg", b"", &sig));
+}
+
+#[test]
+fn test_self_tests_unaffected() {
+    // The PCT and POST sign with an empty context internally
+    assert!(generate_dilithium_keypair_with_pct().is_ok());
+    let keys = DilithiumKeypair::generate_with_seed([0x33; 32]);
+    assert!(pct::dilithium_pct_deterministic(&keys.pk, &keys.sk).is_ok());
+    assert_eq!(preop::run_post(), Ok(()));
+}